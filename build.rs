@@ -0,0 +1,173 @@
+//! Generates the tiling type tables of `src/data.rs` from the data files of Craig S. Kaplan's
+//! Tactile library in `data/tactile`.
+//!
+//! `tiling_arraydecl.inc` declares the coefficient arrays as C arrays, and `tiling_typedata.inc`
+//! holds one initialiser per tiling type, from IH00 to IH93, referring to the arrays by name.
+//! Invalid tiling types have null arrays. Replace both files with upstream's to pick up its
+//! fixes; the generated tables are written to `$OUT_DIR/tiling_data.rs`.
+//!
+//! The floating point arrays, which make up most of the data, are packed into one byte stream:
+//! every byte is either an index into a palette of the distinct non-zero values, or a run of
+//! zeros. `src/data.rs` unpacks them on first use.
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+
+const ARRAYS: &str = "data/tactile/tiling_arraydecl.inc";
+const TYPES: &str = "data/tactile/tiling_typedata.inc";
+
+/// Number of fields of an initialiser in `tiling_typedata.inc`.
+const FIELDS: usize = 12;
+
+/// Bytes of the packed stream from this one up stand for runs of 1 to `256 - ZERO_RUN` zeros;
+/// those below are palette indices.
+const ZERO_RUN: u8 = 240;
+
+fn main() {
+    println!("cargo::rerun-if-changed={ARRAYS}");
+    println!("cargo::rerun-if-changed={TYPES}");
+
+    let arrays = read(ARRAYS);
+    let types = read(TYPES);
+    let mut out = String::from("// Generated by build.rs from the files in data/tactile. Don't edit.\n\n");
+
+    let mut coefficients: Vec<f32> = vec![];
+
+    // Writing into a `String` can't fail.
+    for declaration in arrays.split(';') {
+        let Some((head, body)) = declaration.split_once('=') else {
+            continue;
+        };
+        let name = head
+            .split('[')
+            .next()
+            .and_then(|head| head.split_whitespace().last())
+            .unwrap_or_else(|| panic!("{ARRAYS}: can't find the name of the array in {head:?}"));
+        let ty = rust_type(name);
+        let values: Vec<String> = body
+            .trim()
+            .trim_start_matches('{')
+            .trim_end_matches('}')
+            .split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| rust_value(name, ty, value))
+            .collect();
+        if ty == "f32" {
+            let start = coefficients.len();
+            coefficients.extend(values.iter().map(|value| value.parse::<f32>().expect("validated by rust_value")));
+            let _ = writeln!(out, "const {name}: Packed = Packed {{ start: {start}, count: {} }};", values.len());
+        } else {
+            let _ = writeln!(out, "pub(crate) static {name}: &[{ty}] = &[{}];", values.join(", "));
+        }
+    }
+
+    let (palette, packed) = pack(&coefficients);
+    let _ = writeln!(out, "\nconst ZERO_RUN: u8 = {ZERO_RUN};");
+    let _ = writeln!(out, "const COEFFICIENT_COUNT: usize = {};", coefficients.len());
+    let palette: Vec<String> = palette.iter().map(|bits| format!("{bits:#010x}")).collect();
+    let _ = writeln!(out, "static PALETTE: [u32; {}] = [{}];", palette.len(), palette.join(", "));
+    let packed: Vec<String> = packed.iter().map(u8::to_string).collect();
+    let _ = writeln!(out, "static PACKED_COEFFICIENTS: [u8; {}] = [{}];", packed.len(), packed.join(", "));
+
+    let initialisers: Vec<&str> = types.split('{').skip(1).map(|entry| entry.split('}').next().unwrap_or_default()).collect();
+    let _ = writeln!(out, "\nstatic PACKED_TYPES: [PackedTilingType; {}] = [", initialisers.len());
+    for (i, entry) in initialisers.iter().enumerate() {
+        let fields: Vec<&str> = entry.split(',').map(str::trim).filter(|field| !field.is_empty()).collect();
+        assert_eq!(fields.len(), FIELDS, "{TYPES}: IH{i:02} has {} fields instead of {FIELDS}", fields.len());
+        if matches!(fields[4], "nullptr" | "NULL" | "0") {
+            let _ = writeln!(out, "    // IH{i:02} is undefined\n    ttd_null(),");
+        } else {
+            let _ = writeln!(out, "    // IH{i:02}\n    ttd({}),", fields.join(", "));
+        }
+    }
+    let _ = writeln!(out, "];");
+
+    let path = Path::new(&env::var("OUT_DIR").expect("cargo sets OUT_DIR")).join("tiling_data.rs");
+    fs::write(&path, out).unwrap_or_else(|err| panic!("can't write {}: {err}", path.display()));
+}
+
+/// Pack the coefficients into a palette of the bit patterns of the distinct non-zero values, and
+/// a stream of palette indices and zero runs, see [`ZERO_RUN`].
+fn pack(coefficients: &[f32]) -> (Vec<u32>, Vec<u8>) {
+    let mut palette: Vec<u32> = vec![];
+    let mut packed = vec![];
+    let mut zeros = 0;
+    for &value in coefficients {
+        // Only positive zero is packed as a run, so negative zeros survive.
+        if value.to_bits() == 0 {
+            zeros += 1;
+            continue;
+        }
+        push_zeros(&mut packed, std::mem::take(&mut zeros));
+        let index = palette.iter().position(|&bits| bits == value.to_bits()).unwrap_or_else(|| {
+            palette.push(value.to_bits());
+            palette.len() - 1
+        });
+        assert!(index < ZERO_RUN as usize, "more than {ZERO_RUN} distinct coefficients, raise ZERO_RUN in build.rs");
+        packed.push(index as u8);
+    }
+    push_zeros(&mut packed, zeros);
+    (palette, packed)
+}
+
+/// Append a run of `zeros` zeros, split into bytes of at most `256 - ZERO_RUN` zeros each.
+fn push_zeros(packed: &mut Vec<u8>, mut zeros: usize) {
+    while zeros > 0 {
+        let run = zeros.min(256 - ZERO_RUN as usize);
+        packed.push(ZERO_RUN + (run - 1) as u8);
+        zeros -= run;
+    }
+}
+
+/// Read a data file with its comments removed.
+fn read(path: &str) -> String {
+    let text = fs::read_to_string(path).unwrap_or_else(|err| panic!("can't read {path}: {err}"));
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text.as_str();
+    while let Some(start) = rest.find("//").into_iter().chain(rest.find("/*")).min() {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = if rest.starts_with("//") { rest.find('\n').unwrap_or(rest.len()) } else { rest.find("*/").map_or(rest.len(), |end| end + 2) };
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// The element type of an array, from the prefix of its name.
+fn rust_type(name: &str) -> &'static str {
+    [
+        ("edge_shapes_array", "EdgeShape"),
+        ("edge_orientations_array", "bool"),
+        ("edge_shape_ids_array", "usize"),
+        ("default_params_array", "f32"),
+        ("tiling_vertex_coefficients_array", "f32"),
+        ("translation_coefficients_array", "f32"),
+        ("aspect_coefficients_array", "f32"),
+        ("colouring_array", "u8"),
+    ]
+    .into_iter()
+    .find(|(prefix, _)| name.starts_with(prefix))
+    .map(|(_, ty)| ty)
+    .unwrap_or_else(|| panic!("{ARRAYS}: unknown array {name}"))
+}
+
+/// A value of an array as a Rust literal of type `ty`.
+fn rust_value(name: &str, ty: &str, value: &str) -> String {
+    let valid = match ty {
+        "EdgeShape" => matches!(value, "J" | "U" | "S" | "I"),
+        "bool" => matches!(value, "true" | "false"),
+        "usize" | "u8" => value.parse::<u8>().is_ok(),
+        _ => value.parse::<f64>().is_ok(),
+    };
+    assert!(valid, "{ARRAYS}: {value:?} in {name} is not a valid {ty}");
+
+    // C allows integer literals in floating point arrays, Rust doesn't.
+    if ty == "f32" && !value.contains(['.', 'e', 'E']) {
+        format!("{value}.0")
+    } else {
+        value.to_string()
+    }
+}
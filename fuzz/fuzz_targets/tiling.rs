@@ -0,0 +1,175 @@
+//! Feed arbitrary tiling types, parameters, regions and edge shapes through the tiling core, which
+//! must neither panic nor let NaN or infinity into its results.
+//!
+//! The input bytes are read as numbers, zeros once they run out. Parameters, edge points and
+//! regions are taken in a range where the prototiles are still sensible, or as raw floats. Raw
+//! parameters and edge points must be refused by the fallible API exactly when they aren't
+//! finite, and give finite results when they're moderate. Only sensible tilings are filled: huge
+//! parameters make tiles so large that filling anything takes forever. Raw regions must fill with
+//! nothing rather than with endless tiles if they aren't finite.
+#![no_main]
+
+use egui_isohedral::{tiling::IsohedralTiling, try_get_tiling_type, vec2, TilingError, Vec2, TILING_TYPES};
+use libfuzzer_sys::fuzz_target;
+
+
+/// Tiles taken from a fill, so large regions don't make the fuzzer time out.
+const MAX_TILES: usize = 4096;
+
+/// Interior points an edge shape is given at most.
+const MAX_EDGE_POINTS: u8 = 6;
+
+/// Largest raw parameter or edge coordinate whose results are checked to be finite.
+const MODERATE: f32 = 1e3;
+
+/// The fuzzer's bytes, read from the front.
+struct Input<'a>(&'a [u8]);
+
+impl Input<'_> {
+    fn bytes<const N: usize>(&mut self) -> [u8; N] {
+        let mut out = [0; N];
+        let n = N.min(self.0.len());
+        out[..n].copy_from_slice(&self.0[..n]);
+        self.0 = &self.0[n..];
+        out
+    }
+
+    fn byte(&mut self) -> u8 {
+        self.bytes::<1>()[0]
+    }
+
+    /// Any float, NaN and the infinities more often than their share of the bit patterns.
+    fn raw(&mut self) -> f32 {
+        match self.byte() % 8 {
+            0 => f32::NAN,
+            1 => f32::INFINITY,
+            2 => f32::NEG_INFINITY,
+            _ => f32::from_le_bytes(self.bytes()),
+        }
+    }
+
+    /// A float from `-range` to `range`.
+    fn within(&mut self, range: f32) -> f32 {
+        (u16::from_le_bytes(self.bytes()) as f32 / u16::MAX as f32 * 2. - 1.) * range
+    }
+}
+
+fn finite(points: impl IntoIterator<Item = Vec2>) -> bool {
+    points.into_iter().all(|p| p.x.is_finite() && p.y.is_finite())
+}
+
+fn moderate(points: impl IntoIterator<Item = Vec2>) -> bool {
+    points.into_iter().all(|p| p.x.abs() <= MODERATE && p.y.abs() <= MODERATE)
+}
+
+fuzz_target!(|data: &[u8]| {
+    let mut input = Input(data);
+
+    // Some indices past the last tiling type, which must be refused.
+    let index = input.byte() as usize % (TILING_TYPES.len() + 8);
+    let ihtype = match try_get_tiling_type(index) {
+        Ok(ihtype) => ihtype,
+        Err(err) => {
+            assert_eq!(err, TilingError::InvalidTilingType(index));
+            return;
+        }
+    };
+    let num_params = IsohedralTiling::new(ihtype).num_params();
+
+    let raw_params = input.byte() % 4 == 0;
+    let params: Vec<f32> = (0..num_params).map(|_| if raw_params { input.raw() } else { input.within(2.) }).collect();
+    let first_bad = params.iter().position(|p| !p.is_finite()).map(TilingError::NonFiniteParameter);
+
+    // `try_set_parameters` refuses the same parameters as the builder, and leaves the tiling alone.
+    let mut set = IsohedralTiling::new(ihtype);
+    let before = set.vertices().to_vec();
+    let mut all = [0.; 6];
+    all[..num_params].copy_from_slice(&params);
+    match set.try_set_parameters(&all) {
+        Ok(()) => assert_eq!(first_bad, None, "{params:?}"),
+        Err(err) => {
+            assert_eq!(Some(err), first_bad, "{params:?}");
+            assert_eq!(set.vertices(), &before[..], "refused parameters changed the tiling");
+        }
+    }
+    let tiling = match IsohedralTiling::builder().tiling_type(ihtype).parameters(&params).build() {
+        Ok(tiling) => tiling,
+        Err(err) => {
+            assert_eq!(Some(err), first_bad, "{params:?}");
+            return;
+        }
+    };
+    assert_eq!(set.vertices(), tiling.vertices());
+    let sane = params.iter().all(|p| p.abs() <= MODERATE);
+    if sane {
+        assert!(finite(tiling.vertices().iter().copied()), "{params:?}");
+        assert!(finite([*tiling.t1(), *tiling.t2()]), "{params:?}");
+    }
+
+    let segments = input.byte() as usize % 16 + 1;
+    let raw_edges = input.byte() % 4 == 0;
+    let mut edges = vec![];
+    for _ in 0..tiling.num_edge_shapes() {
+        let interior = input.byte() % (MAX_EDGE_POINTS + 1);
+        let mut edge = vec![vec2(0., 0.)];
+        for _ in 0..interior {
+            edge.push(if raw_edges { vec2(input.raw(), input.raw()) } else { vec2(input.within(2.), input.within(2.)) });
+        }
+        edge.push(vec2(1., 0.));
+        edges.push(edge);
+    }
+
+    // Too few or too many edges must be refused rather than panic.
+    match input.byte() % 16 {
+        0 => {
+            edges.pop();
+        }
+        1 => edges.push(vec![vec2(0., 0.), vec2(1., 0.)]),
+        _ => {}
+    }
+    if edges.len() != tiling.num_edge_shapes() {
+        let err = TilingError::WrongEdgeCount { expected: tiling.num_edge_shapes(), got: edges.len() };
+        assert_eq!(tiling.try_outline(&edges), Err(err));
+        assert_eq!(tiling.try_sample_edges(&edges, segments), Err(err));
+        return;
+    }
+
+    let first_bad = edges.iter().position(|edge| !finite(edge.iter().copied())).map(TilingError::NonFiniteEdgePoint);
+    let sampled = match tiling.try_sample_edges(&edges, segments) {
+        Ok(sampled) => sampled,
+        Err(err) => {
+            assert_eq!(Some(err), first_bad, "{edges:?}");
+            assert_eq!(tiling.try_outline(&edges), Err(err));
+            return;
+        }
+    };
+    assert_eq!(first_bad, None, "non-finite edge points accepted: {edges:?}");
+    let bounded = sane && edges.iter().all(|edge| moderate(edge.iter().copied()));
+    let outline = tiling.try_outline(&sampled);
+    if bounded {
+        assert!(sampled.iter().all(|edge| finite(edge.iter().copied())), "{params:?} {edges:?}");
+        let outline = outline.as_deref().expect("finite samples refused");
+        assert!(finite(outline.iter().copied()), "{params:?} {edges:?}");
+    }
+    if raw_params || !bounded {
+        return;
+    }
+    let _ = tiling.check_edges(&sampled);
+
+    let raw = input.byte() % 4 == 0;
+    let region: [f32; 4] = std::array::from_fn(|_| if raw { input.raw() } else { input.within(50.) });
+    let tiles: Vec<_> = tiling.fill_region(region[0], region[1], region[2], region[3]).into_iter().take(MAX_TILES).collect();
+    assert!(tiles.windows(2).all(|pair| pair[0].sort_key() < pair[1].sort_key()), "tiles out of order: {params:?} {region:?}");
+    if region.iter().any(|x| !x.is_finite()) {
+        assert!(tiles.is_empty(), "tiles in a region that isn't finite: {region:?}");
+    }
+    for tile in &tiles {
+        assert!(tile.aspect() < tiling.num_aspects());
+        let _ = tile.colour(&tiling);
+        let t = tile.transform();
+        // Raw regions can be so far out that the tiles there are beyond floating point.
+        if !raw {
+            assert!(finite([t.matrix2.x_axis, t.matrix2.y_axis, t.translation]), "{params:?} {region:?}");
+        }
+    }
+});
@@ -0,0 +1,59 @@
+/* C interface to the egui_isohedral tiling core, see src/ffi.rs.
+ *
+ * Build the library with
+ *     cargo rustc --lib --release --features ffi --crate-type cdylib
+ * (or --crate-type staticlib) and link against it.
+ *
+ * Functions returning int return 0 on success and -1 if an argument is invalid.
+ */
+#ifndef EGUI_ISOHEDRAL_H
+#define EGUI_ISOHEDRAL_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* An isohedral tiling, created by ih_tiling_new and released by ih_tiling_free. */
+typedef struct IhTiling IhTiling;
+
+/* A tile written by ih_tiling_fill. The matrix [a b c; d e f] maps a prototile point (x, y) to
+ * (ax + by + c, dx + ey + f). */
+typedef struct IhTile {
+    int32_t t1;
+    int32_t t2;
+    uint32_t aspect;
+    uint32_t colour;
+    float matrix[6];
+} IhTile;
+
+/* A tiling of the index-th valid tiling type, 0 to 80, or NULL if there is no such type. */
+IhTiling *ih_tiling_new(size_t index);
+void ih_tiling_free(IhTiling *tiling);
+/* Switch to the index-th valid tiling type, with its default parameters. */
+int ih_tiling_reset(IhTiling *tiling, size_t index);
+/* The IHnn number of the tiling type. */
+uint32_t ih_tiling_type(const IhTiling *tiling);
+
+size_t ih_tiling_num_params(const IhTiling *tiling);
+/* params holds ih_tiling_num_params floats. */
+int ih_tiling_get_params(const IhTiling *tiling, float *params);
+/* count must be ih_tiling_num_params, and the values finite. */
+int ih_tiling_set_params(IhTiling *tiling, const float *params, size_t count);
+
+size_t ih_tiling_num_vertices(const IhTiling *tiling);
+/* xy holds 2 * ih_tiling_num_vertices floats, written as x, y pairs. */
+int ih_tiling_vertices(const IhTiling *tiling, float *xy);
+
+/* Write up to capacity of the tiles covering the rectangle to tiles, and return how many there
+ * are; call again with a larger buffer if that is more than capacity. A NULL buffer just counts
+ * them. */
+size_t ih_tiling_fill(const IhTiling *tiling, float xmin, float ymin, float xmax, float ymax, IhTile *tiles, size_t capacity);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -1,29 +1,131 @@
 // #![allow(dead_code)]
 
-use crate::{data::get_tiling_type, interface, tiling::IsohedralTiling, utils::{Vec2, vec2}};
+use crate::{console::Console, cache::{ContrastCache, Gap, OutlineCache, TileCache}, edge_editor::{EdgeEditor, NOISE_POINTS}, history::History, interface::{self, DrawBuffers, DualOverlay, EdgeArrows, MorphAnimation, TileLabels}, layers::Layer, link, measure::Measure, recent::RecentFiles, recorder::Recorder, reference::Reference, slideshow::{Fading, Slideshow}, thumbnails::Thumbnails, tile_painter::{Rendering, TilePainter}, timeline::Timeline, watch::Watch};
+use egui_isohedral::{colouring::MAX_COLOURS, edges, export::{figure::Figure, pdf::PrintLayout, PhysicalScale}, get_tiling_type, hatch::Pattern, iterators::Tile, mask::Mask, palette::{Deficiency, Palette}, tiling::IsohedralTiling, view::ViewState, Vec2, vec2};
 use eframe::egui;
-use egui_colors::{utils, Colorix};
+use egui_colors::{utils, Colorix, Theme};
+use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
 
 
 #[derive(Default)]
 pub struct App {
     pub colorix: Colorix,
+    /// Custom themes offered in the themes menu after the built-in ones.
+    pub saved_themes: Vec<Theme>,
     pub params: [f32; 6],
     pub tile_type_num: usize,
     pub tiling: IsohedralTiling,
+    pub colours: usize,
     pub edges_shapes: Vec<Vec<Vec2>>,
     pub set_params: bool,
+    /// The parameter or edge point the arrow keys nudge.
+    pub selection: Option<Selection>,
+    /// The seed every random action draws from, so a random design can be rolled again.
+    pub seed: u64,
+    pub view: ViewState,
+    /// Whether the view has been zoomed to the starting density, which needs the window's size.
+    pub view_fitted: bool,
+    pub tile_cache: TileCache,
+    pub outline_cache: OutlineCache,
+    pub contrast_cache: ContrastCache,
+    pub draw_buffers: DrawBuffers,
+    pub tile_painter: Box<dyn TilePainter>,
+    pub rendering: Rendering,
+    pub gap: Gap,
+    /// Fill pattern of each colour class.
+    pub patterns: [Pattern; MAX_COLOURS],
+    /// Draw just the tile outlines, black on white, on screen and in the exports.
+    pub colouring_book: bool,
+    /// Bright colours as different as possible where classes meet, with heavy black outlines.
+    pub high_contrast: bool,
+    /// Colours for the colour classes instead of the theme's.
+    pub palette: Option<Palette>,
+    /// Main colours of a dropped image, offered until they are applied or dismissed.
+    pub extracted: Vec<egui::Color32>,
+    /// Colours taken from an image: the background, then the colour classes.
+    pub image_colours: Option<Vec<egui::Color32>>,
+    /// The colour vision deficiency previewed on the canvas.
+    pub simulation: Option<Deficiency>,
+    /// Width of the outlines in colouring book mode, in screen points.
+    pub line_weight: f32,
+    pub screen_segments: usize,
+    /// Samples per pixel of the window's multisample anti-aliasing, `0` if off.
+    pub multisampling: u16,
+    pub export_segments: usize,
+    /// Decimals shown in the boxes for exact values next to the sliders.
+    pub decimals: usize,
+    pub status: String,
+    pub folder: String,
+    pub open_path: String,
+    /// A share code pasted into the Files section.
+    pub share_code: String,
+    pub recent: RecentFiles,
+    pub thumbnails: Thumbnails,
+    pub tikz_width: String,
+    /// Label the prototile template with its edge lengths and corner angles.
+    pub dimensions: bool,
+    /// What the publication figure shows.
+    pub figure: Figure,
+    pub tile_labels: TileLabels,
+    pub aspect_frames: bool,
+    pub edge_arrows: EdgeArrows,
+    pub dual: DualOverlay,
+    pub dual_stroke: egui::Stroke,
+    /// A pinned earlier state of the tiling, drawn see-through over it for comparison.
+    pub ghost: Option<Fading>,
+    /// Tilings drawn over the design, bottom first.
+    pub layers: Vec<Layer>,
+    /// The last PNG image opened, to show under the tiling.
+    pub reference: Option<Reference>,
+    /// The silhouette the tiling is cut to, in world space.
+    pub mask: Option<Mask>,
+    /// The text the tiling is masked to by "Mask to text".
+    #[cfg(feature = "glyphs")]
+    pub mask_text: String,
+    /// The tile the view's context menu was opened on.
+    pub menu_tile: Option<Tile>,
+    pub edge_editor: EdgeEditor,
+    pub console: Console,
+    pub timeline: Timeline,
+    pub history: History,
+    pub measure: Measure,
+    pub recorder: Recorder,
+    /// The configuration file followed with `--watch`.
+    pub watch: Option<Watch>,
+    pub physical_units: bool,
+    pub physical: PhysicalScale,
+    /// Cut the tiles of the exports along the border of the region, see [`ExportOptions::clip`].
+    ///
+    /// [`ExportOptions::clip`]: egui_isohedral::export::ExportOptions::clip
+    pub clip_exports: bool,
+    pub print_layout: PrintLayout,
+    pub wallpaper_mode: bool,
+    pub wallpaper_preset: usize,
+    pub morph_target: usize,
+    pub morph_duration: f32,
+    pub morph: Option<MorphAnimation>,
+    pub slideshow: Slideshow,
+    pub screensaver: bool,
+    #[cfg(feature = "set-wallpaper")]
+    pub set_wallpaper: bool,
+    #[cfg(feature = "remote")]
+    pub remote: Option<crate::remote::RemoteServer>,
 }
 
 impl eframe::App for App {
+    /// egui only runs a frame on input, or when one is asked for: whatever moves on its own (the
+    /// colour animator, a morph, the slideshow and its fades, the screensaver and remote commands)
+    /// asks for the frames it needs, so the app idles when nothing moves.
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         self.colorix.set_animator(ctx);
+        #[cfg(feature = "remote")]
+        crate::remote::poll(self, ctx);
         interface::draw_interface(self, ctx);
     }
 }
 
 impl App {
-    fn new(ctx: &mut egui::Context) -> Self {
+    fn new(ctx: &mut egui::Context, screensaver: bool, multisampling: u16) -> Self {
         ctx.set_theme(egui::Theme::Light);
         let colorix = Colorix::global(ctx, utils::SEVENTIES).animated().set_time(2.);
         let tile_type_num = 0;
@@ -31,42 +133,227 @@ impl App {
 
         let mut app = App {
             colorix,
+            saved_themes: vec![],
             params: [0.; 6],
             tile_type_num,
             tiling,
+            colours: 3,
             edges_shapes: vec![],
             set_params: false,
+            selection: None,
+            seed: rand::random(),
+            view: ViewState::default(),
+            view_fitted: false,
+            tile_cache: TileCache::default(),
+            outline_cache: OutlineCache::default(),
+            contrast_cache: ContrastCache::default(),
+            draw_buffers: DrawBuffers::default(),
+            tile_painter: Rendering::default().painter(),
+            rendering: Rendering::default(),
+            gap: Gap::default(),
+            patterns: [Pattern::Solid; MAX_COLOURS],
+            colouring_book: false,
+            high_contrast: false,
+            palette: None,
+            extracted: vec![],
+            image_colours: None,
+            simulation: None,
+            line_weight: 2.,
+            screen_segments: edges::SCREEN_SEGMENTS,
+            multisampling,
+            export_segments: edges::EXPORT_SEGMENTS,
+            decimals: 3,
+            status: String::new(),
+            folder: String::new(),
+            open_path: String::new(),
+            share_code: String::new(),
+            recent: RecentFiles::load(),
+            thumbnails: Thumbnails::default(),
+            tikz_width: String::from("\\linewidth"),
+            dimensions: true,
+            figure: Figure::default(),
+            tile_labels: TileLabels::Off,
+            aspect_frames: false,
+            edge_arrows: EdgeArrows::Off,
+            dual: DualOverlay::Off,
+            dual_stroke: egui::Stroke::new(1.5, egui::Color32::from_rgb(200, 40, 40)),
+            ghost: None,
+            layers: vec![],
+            reference: None,
+            mask: None,
+            #[cfg(feature = "glyphs")]
+            mask_text: String::from("TILE"),
+            menu_tile: None,
+            edge_editor: EdgeEditor::default(),
+            console: Console::default(),
+            timeline: Timeline::default(),
+            history: History::default(),
+            measure: Measure::default(),
+            recorder: Recorder::default(),
+            watch: Watch::from_args(),
+            physical_units: false,
+            clip_exports: false,
+            physical: PhysicalScale::default(),
+            print_layout: PrintLayout::default(),
+            wallpaper_mode: false,
+            wallpaper_preset: 0,
+            morph_target: 0,
+            morph_duration: 2.,
+            morph: None,
+            slideshow: Slideshow::default(),
+            screensaver,
+            #[cfg(feature = "set-wallpaper")]
+            set_wallpaper: false,
+            #[cfg(feature = "remote")]
+            remote: None,
         };
         app.set_default_edges();
         app.set_default_params();
+        if let Some(link) = link::from_args()
+            && let Err(err) = link::open(&mut app, ctx, &link)
+        {
+            app.status = format!("Couldn't open the link: {err}");
+        }
+        if screensaver {
+            app.slideshow.start();
+        }
+        #[cfg(feature = "remote")]
+        {
+            let address = crate::remote::address_from_args();
+            match crate::remote::RemoteServer::start(ctx, &address, crate::remote::origins_from_args()) {
+                Ok(server) => app.remote = Some(server),
+                Err(err) => app.status = format!("Remote control unavailable on {address}: {err}"),
+            }
+        }
 
         app
     }
 
+    /// Switch to the `n`-th valid tiling type, with straight edges and its default parameters.
+    pub fn set_tiling_type(&mut self, n: usize) {
+        self.tile_type_num = n;
+        self.selection = None;
+        self.tiling = IsohedralTiling::new(get_tiling_type(n));
+        self.set_default_edges();
+        self.set_default_params();
+        self.apply_colouring();
+    }
     pub fn set_default_edges(&mut self) {
         self.edges_shapes.clear();
         for _ in 0..self.tiling.num_edge_shapes() {
-            let mut edge = vec![];
-            edge.push(vec2(0.0, 0.0));
-            edge.push(vec2(1.0, 0.0));
-            self.edges_shapes.push(edge);
+            self.edges_shapes.push(vec![vec2(0.0, 0.0), vec2(1.0, 0.0)]);
+        }
+    }
+    /// Replace every edge shape with a random noise edge, respecting its symmetry.
+    pub fn set_random_edges(&mut self) {
+        let mut rng = self.rng(Roll::Edges);
+        let tiling = &self.tiling;
+        self.edges_shapes = (0..tiling.num_edge_shapes())
+            .map(|i| {
+                let noise = edges::Noise { seed: rng.next_u64(), ..edges::Noise::default() };
+                edges::noise_edge(&noise, tiling.edge_shape(i), NOISE_POINTS)
+            })
+            .collect();
+    }
+    /// Random parameters for the current tiling type.
+    pub fn set_random_params(&mut self) {
+        let mut rng = self.rng(Roll::Params);
+        for p in self.params.iter_mut().take(self.tiling.num_params()) {
+            *p = rng.gen_range(0.0..=1.);
+        }
+        self.tiling.set_parameters(&self.params);
+    }
+    /// One of the built-in themes, picked by the seed.
+    pub fn set_random_theme(&mut self, ctx: &egui::Context) {
+        let theme = self.rng(Roll::Theme).gen_range(0..utils::THEMES.len());
+        self.colorix.update_theme(ctx, utils::THEMES[theme]);
+    }
+    /// Random parameters, edges and theme, all from the current seed.
+    pub fn randomize(&mut self, ctx: &egui::Context) {
+        self.set_random_params();
+        self.set_random_edges();
+        self.set_random_theme(ctx);
+    }
+    /// The numbers the current seed gives for `roll`.
+    pub fn rng(&self, roll: Roll) -> StdRng {
+        seeded(self.seed, roll)
+    }
+    /// Move on to the next seed, ahead of a new roll.
+    pub fn next_seed(&mut self) {
+        self.seed = next_seed(self.seed);
+    }
+    /// Colour the tiling with the chosen number of colours, keeping its colouring if it has no
+    /// perfect colouring with that many.
+    pub fn apply_colouring(&mut self) {
+        if let Err(err) = self.tiling.set_colouring(self.colours) {
+            self.status = err.to_string();
         }
     }
+    /// Keep a copy of the tiling as it is now as the ghost, drawn at `opacity` over the tiling.
+    pub fn pin_ghost(&mut self, opacity: f32) {
+        self.ghost = Some(Fading { tiling: self.copy_tiling(), edges: self.edges_shapes.clone(), opacity });
+    }
+    /// A tiling of the same type, parameters and colouring as the app's.
+    pub fn copy_tiling(&self) -> IsohedralTiling {
+        let mut tiling = IsohedralTiling::new(self.tiling.tiling_type());
+        tiling.set_parameters(&self.params);
+        // The colouring is valid, the tiling has it.
+        let _ = tiling.set_colouring(self.colours);
+        tiling
+    }
     pub fn set_default_params(&mut self) {
-        self.params = self.tiling.parameters  
+        self.tiling.parameters(&mut self.params)
     }
     pub fn _set_params(&mut self, i: usize) {
-        self.tiling.parameters[i] = self.params[i]
+        let mut params = [0.; 6];
+        self.tiling.parameters(&mut params);
+        params[i] = self.params[i];
+        self.tiling.set_parameters(&params)
     }
 }
 
-pub fn init() -> Result<(), eframe::Error> {
+pub fn init(screensaver: bool, multisampling: u16) -> Result<(), eframe::Error> {
+    let mut options = eframe::NativeOptions { multisampling, ..Default::default() };
+    if screensaver {
+        options.viewport = options.viewport.with_fullscreen(true);
+    }
 
     eframe::run_native(
         "egui Isohedral",
-        eframe::NativeOptions::default(),
-        Box::new(|cc| {
-            Ok(Box::new(App::new(&mut cc.egui_ctx.clone())))
+        options,
+        Box::new(move |cc| {
+            Ok(Box::new(App::new(&mut cc.egui_ctx.clone(), screensaver, multisampling)))
         }),
     )
-}
\ No newline at end of file
+}
+
+/// What the arrow keys nudge: Left and Right move a parameter down and up, and all four move an
+/// edge point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selection {
+    /// A parameter of the tiling.
+    Param(usize),
+    /// A control point of an edge shape, in the edge editor.
+    EdgePoint { edge: usize, point: usize },
+}
+
+/// What a seed is rolled for. Each gets its own numbers from a seed, so rolling the edges again
+/// doesn't change the parameters that seed gives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Roll {
+    Params = 1,
+    Edges,
+    Theme,
+    /// The tiling type the slideshow moves on to.
+    TilingType,
+}
+
+/// The random numbers `seed` gives for `roll`.
+pub fn seeded(seed: u64, roll: Roll) -> StdRng {
+    StdRng::seed_from_u64(seed ^ (roll as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15))
+}
+
+/// The seed after `seed`. Seeds follow each other, so a run of rolls from one seed repeats too.
+pub fn next_seed(seed: u64) -> u64 {
+    StdRng::seed_from_u64(seed).next_u64()
+}
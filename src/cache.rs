@@ -0,0 +1,289 @@
+use std::collections::BTreeMap;
+
+use eframe::egui;
+
+use egui_isohedral::colouring::{Colouring, MAX_COLOURS};
+use egui_isohedral::iterators::Tile;
+use egui_isohedral::offset::{self, OffsetOptions};
+use egui_isohedral::palette;
+use egui_isohedral::tessellate::LineJoin;
+use egui_isohedral::tiling::{EdgeProblem, IsohedralTiling, TilingType};
+use egui_isohedral::{centroid, triangulate, Affine2, Vec2};
+
+
+/// Lattice coordinates of a tile: `(t1, t2, aspect)`.
+pub type TileKey = (isize, isize, usize);
+
+/// Persistent fill of the visible region, keyed by lattice coordinates.
+///
+/// When only the viewport moves, just the newly exposed strips are filled and the tiles that
+/// scrolled out are dropped, instead of refilling the whole region every frame.
+///
+/// The tiles are also laid out flat in an arena, with their colour classes, which is rebuilt only
+/// when the tiles or the colouring change; while neither the tiling nor the view changes, a frame
+/// just reads the arena.
+#[derive(Debug, Default)]
+pub struct TileCache {
+    tiles: BTreeMap<TileKey, Affine2>,
+    region: Option<egui::Rect>,
+    revision: u64,
+    arena: Vec<Tile>,
+    classes: Vec<usize>,
+    /// The colouring `classes` were looked up in.
+    colouring: Colouring,
+    /// Whether `tiles` changed since the arena was last rebuilt.
+    stale: bool,
+}
+
+impl TileCache {
+    /// Bring the cache up to date for the given tiling and world-space region.
+    pub fn update(&mut self, tiling: &IsohedralTiling, region: egui::Rect) {
+        if self.revision != tiling.revision() {
+            self.revision = tiling.revision();
+            self.clear();
+        }
+
+        match self.region {
+            Some(old) if old == region => {}
+            Some(old) if old.intersects(region) => {
+                // Pad the strips by a lattice cell: tiles straddling a strip border may only be
+                // produced by the fill algorithm when their whole cell is inside the region.
+                let margin = tiling.t1().length() + tiling.t2().length();
+                for strip in exposed_strips(old, region) {
+                    self.fill(tiling, strip.expand(margin));
+                }
+                self.drop_outside(region.expand(margin));
+            }
+            _ => {
+                self.tiles.clear();
+                self.fill(tiling, region);
+            }
+        }
+        self.region = Some(region);
+
+        if self.stale || self.colouring != *tiling.colouring() {
+            self.stale = false;
+            self.colouring = tiling.colouring().clone();
+            self.arena.clear();
+            self.arena.extend(self.tiles.keys().map(|&(t1, t2, aspect)| tiling.tile(t1, t2, aspect)));
+            self.classes.clear();
+            self.classes.extend(self.arena.iter().map(|tile| tile.colour(tiling)));
+        }
+    }
+
+    /// Forget all cached tiles, forcing a full refill on the next update.
+    pub fn clear(&mut self) {
+        self.tiles.clear();
+        self.region = None;
+        self.stale = true;
+    }
+
+    /// Iterate over the cached tiles in lattice order.
+    pub fn tiles(&self) -> impl Iterator<Item = (&TileKey, &Affine2)> {
+        self.tiles.iter()
+    }
+
+    /// The cached tiles in lattice order, as of the last [`update`].
+    ///
+    /// [`update`]: TileCache::update
+    pub fn arena(&self) -> &[Tile] {
+        &self.arena
+    }
+
+    /// The colour class of each tile of the [`arena`], see [`IsohedralTiling::colour`].
+    ///
+    /// [`arena`]: TileCache::arena
+    pub fn classes(&self) -> &[usize] {
+        &self.classes
+    }
+
+    fn fill(&mut self, tiling: &IsohedralTiling, r: egui::Rect) {
+        self.stale = true;
+        for tile in tiling.fill_region(r.min.x, r.min.y, r.max.x, r.max.y) {
+            self.tiles
+                .entry((tile.t1(), tile.t2(), tile.aspect()))
+                .or_insert(tile.transform());
+        }
+    }
+
+    /// Drop every tile whose origin lies outside `region`.
+    fn drop_outside(&mut self, region: egui::Rect) {
+        self.stale = true;
+        self.tiles.retain(|_, t| region.contains(egui::pos2(t.translation.x, t.translation.y)));
+    }
+}
+
+/// Prototile outline in prototile coordinates.
+///
+/// Each edge-shape class is flattened once into a point buffer and the outline is rebuilt only
+/// when the tiling or the edge shapes change, so per tile only the tile transform has to be
+/// applied to the cached points. The outline's triangulation is cached as well: affine maps keep
+/// it valid for every tile, including the concave ones.
+///
+/// With a [`Gap`] between the tiles, the outline they are drawn with is shrunk or inset.
+#[derive(Debug, Default)]
+pub struct OutlineCache {
+    edges: Vec<Vec<Vec2>>,
+    segments: usize,
+    gap: Gap,
+    sampled: Vec<Vec<Vec2>>,
+    outline: Vec<Vec2>,
+    drawn: Vec<Vec2>,
+    triangles: Vec<u32>,
+    centroid: Vec2,
+    problems: Vec<(usize, EdgeProblem)>,
+    revision: u64,
+}
+
+impl OutlineCache {
+    /// Bring the cached outline up to date for the given tiling and edge shapes, sampling each
+    /// edge with about `segments` segments, and leaving `gap` between the drawn tiles.
+    pub fn update(&mut self, tiling: &IsohedralTiling, edges: &[Vec<Vec2>], segments: usize, gap: Gap) {
+        let changed = self.revision != tiling.revision() || self.edges != edges || self.segments != segments;
+        if changed {
+            self.revision = tiling.revision();
+            self.edges = edges.to_vec();
+            self.segments = segments;
+
+            self.sampled = tiling.sample_edges(edges, segments);
+            self.outline = tiling.outline(&self.sampled);
+            self.centroid = centroid(&self.outline);
+            self.problems = tiling.check_edges(&self.sampled);
+        }
+        if changed || self.gap != gap {
+            self.gap = gap;
+            self.drawn = gap.apply(&self.outline, self.centroid);
+            self.triangles = triangulate(&self.drawn);
+        }
+    }
+
+    /// The outline of the prototile, ready to be transformed by each tile's transform.
+    pub fn outline(&self) -> &[Vec2] {
+        &self.outline
+    }
+
+    /// The outline the tiles are drawn with: [`outline`] with the gap taken off. It may be empty
+    /// if the gap leaves nothing.
+    ///
+    /// [`outline`]: OutlineCache::outline
+    pub fn drawn(&self) -> &[Vec2] {
+        &self.drawn
+    }
+
+    /// Triangle indices into [`drawn`], filling the drawn prototile.
+    ///
+    /// [`drawn`]: OutlineCache::drawn
+    pub fn triangles(&self) -> &[u32] {
+        &self.triangles
+    }
+
+    /// The centroid of the prototile.
+    pub fn centroid(&self) -> Vec2 {
+        self.centroid
+    }
+
+    /// Edge shapes that make neighbouring tiles overlap, as found by
+    /// [`IsohedralTiling::check_edges`] on the sampled edges.
+    pub fn problems(&self) -> &[(usize, EdgeProblem)] {
+        &self.problems
+    }
+
+    /// Each edge shape class sampled into points, in the edge frame.
+    pub fn sampled(&self) -> &[Vec<Vec2>] {
+        &self.sampled
+    }
+}
+
+/// The high-contrast colours of the colour classes, kept until the tiling type or its colouring
+/// changes: finding them compares every arrangement of the colours.
+#[derive(Debug, Default)]
+pub struct ContrastCache {
+    key: Option<(TilingType, Colouring)>,
+    colours: [egui::Color32; MAX_COLOURS],
+}
+
+impl ContrastCache {
+    /// Bring the colours up to date for the given tiling.
+    pub fn update(&mut self, tiling: &IsohedralTiling) {
+        let key = (tiling.tiling_type(), tiling.colouring().clone());
+        if self.key.as_ref() == Some(&key) {
+            return;
+        }
+        let colours = palette::high_contrast(tiling.num_colours(), &key.1.adjacent_colours(tiling));
+        for (colour, [r, g, b, a]) in self.colours.iter_mut().zip(colours) {
+            *colour = egui::Color32::from_rgba_unmultiplied(r, g, b, a);
+        }
+        self.key = Some(key);
+    }
+
+    /// The colour of each colour class.
+    pub fn colours(&self) -> [egui::Color32; MAX_COLOURS] {
+        self.colours
+    }
+}
+
+/// Space left between neighbouring tiles, like the grout of a mosaic.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gap {
+    /// Scale every tile toward its centroid by a fraction of its size.
+    Shrink(f32),
+    /// Move every tile's outline inward by a distance in world units, so the gaps are equally wide
+    /// whatever the tile's shape.
+    Inset(f32, LineJoin),
+}
+
+impl Default for Gap {
+    fn default() -> Self {
+        Gap::Shrink(0.)
+    }
+}
+
+impl Gap {
+    /// Whether the tiles are drawn apart, no longer sharing their edges.
+    pub fn is_open(self) -> bool {
+        match self {
+            Gap::Shrink(amount) | Gap::Inset(amount, _) => amount > 0.,
+        }
+    }
+
+    /// The join at the corners of tiles drawn apart.
+    pub fn join(self) -> LineJoin {
+        match self {
+            Gap::Shrink(_) => LineJoin::Miter,
+            Gap::Inset(_, join) => join,
+        }
+    }
+
+    /// The outline a tile with the prototile `outline` and `centroid` is drawn with.
+    ///
+    /// Tile transforms are rigid, so taking the gap off the prototile is the same as taking it off
+    /// every transformed tile.
+    pub fn apply(self, outline: &[Vec2], centroid: Vec2) -> Vec<Vec2> {
+        match self {
+            _ if !self.is_open() => outline.to_vec(),
+            Gap::Shrink(fraction) => outline.iter().map(|&p| centroid + (p - centroid) * (1. - fraction)).collect(),
+            Gap::Inset(distance, join) => offset::inset(outline, distance, &OffsetOptions { join, ..OffsetOptions::default() }),
+        }
+    }
+}
+
+/// The parts of `new` not covered by `old`, as at most four non-overlapping rectangles.
+fn exposed_strips(old: egui::Rect, new: egui::Rect) -> Vec<egui::Rect> {
+    let mut strips = vec![];
+    let inner = old.intersect(new);
+
+    if new.min.y < inner.min.y {
+        strips.push(egui::Rect::from_x_y_ranges(new.x_range(), new.min.y..=inner.min.y));
+    }
+    if new.max.y > inner.max.y {
+        strips.push(egui::Rect::from_x_y_ranges(new.x_range(), inner.max.y..=new.max.y));
+    }
+    if new.min.x < inner.min.x {
+        strips.push(egui::Rect::from_x_y_ranges(new.min.x..=inner.min.x, inner.y_range()));
+    }
+    if new.max.x > inner.max.x {
+        strips.push(egui::Rect::from_x_y_ranges(inner.max.x..=new.max.x, inner.y_range()));
+    }
+
+    strips
+}
@@ -0,0 +1,99 @@
+//! Copying the tiling's numbers to the clipboard as CSV or JSON, for pasting into notebooks and
+//! shaders.
+//!
+//! The actions are in the context menu of the view. Transforms are written as the six numbers
+//! `a b c d e f` of the matrix `[a b c; d e f]` mapping `(x, y)` to `(ax + by + c, dx + ey + f)`,
+//! as in the Inspector section.
+use eframe::egui;
+use egui_isohedral::{iterators::Tile, Affine2, Vec2};
+
+use crate::app::App;
+
+
+/// A text format the numbers are copied in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+impl Format {
+    const ALL: [Format; 2] = [Format::Csv, Format::Json];
+
+    fn name(self) -> &'static str {
+        match self {
+            Format::Csv => "CSV",
+            Format::Json => "JSON",
+        }
+    }
+}
+
+/// The contents of the view's context menu. `tile` is the tile the menu was opened on.
+pub fn menu(app: &mut App, ui: &mut egui::Ui, tile: Option<Tile>) {
+    for format in Format::ALL {
+        if ui.button(format!("Copy prototile vertices as {}", format.name())).clicked() {
+            copy(app, ui, vertices(app.tiling.vertices(), format), "the prototile vertices");
+        }
+    }
+    // The cached tiles are those drawn, including the margin around the view.
+    for format in Format::ALL {
+        if ui.button(format!("Copy visible transforms as {}", format.name())).clicked() {
+            let tiles: Vec<(isize, isize, usize, Affine2)> = app.tile_cache.tiles().map(|(&(t1, t2, aspect), &transform)| (t1, t2, aspect, transform)).collect();
+            copy(app, ui, transforms(&tiles, format), &format!("{} transforms", tiles.len()));
+        }
+    }
+    if let Some(tile) = tile {
+        ui.separator();
+        for format in Format::ALL {
+            if ui.button(format!("Copy this tile's matrix as {}", format.name())).clicked() {
+                let text = transforms(&[(tile.t1(), tile.t2(), tile.aspect(), tile.transform())], format);
+                copy(app, ui, text, &format!("the matrix of tile {}, {} #{}", tile.t1(), tile.t2(), tile.aspect()));
+            }
+        }
+    }
+}
+
+fn copy(app: &mut App, ui: &mut egui::Ui, text: String, what: &str) {
+    ui.ctx().copy_text(text);
+    app.status = format!("copied {what} to the clipboard");
+    ui.close_menu();
+}
+
+/// The six numbers of the matrix of `transform`, see the [module docs](self).
+fn coefficients(transform: &Affine2) -> [f32; 6] {
+    let (m, d) = (transform.matrix2, transform.translation);
+    [m.x_axis.x, m.y_axis.x, d.x, m.x_axis.y, m.y_axis.y, d.y]
+}
+
+fn vertices(points: &[Vec2], format: Format) -> String {
+    match format {
+        Format::Csv => std::iter::once(String::from("x,y")).chain(points.iter().map(|p| format!("{},{}", p.x, p.y))).collect::<Vec<_>>().join("\n") + "\n",
+        Format::Json => {
+            let points: Vec<String> = points.iter().map(|p| format!("[{}, {}]", p.x, p.y)).collect();
+            format!("[{}]\n", points.join(", "))
+        }
+    }
+}
+
+/// Tiles given by their lattice position, aspect and transform.
+fn transforms(tiles: &[(isize, isize, usize, Affine2)], format: Format) -> String {
+    match format {
+        Format::Csv => {
+            let rows = tiles.iter().map(|(t1, t2, aspect, transform)| {
+                let [a, b, c, d, e, f] = coefficients(transform);
+                format!("{t1},{t2},{aspect},{a},{b},{c},{d},{e},{f}")
+            });
+            std::iter::once(String::from("t1,t2,aspect,a,b,c,d,e,f")).chain(rows).collect::<Vec<_>>().join("\n") + "\n"
+        }
+        Format::Json => {
+            let objects: Vec<String> = tiles
+                .iter()
+                .map(|(t1, t2, aspect, transform)| {
+                    let [a, b, c, d, e, f] = coefficients(transform);
+                    format!(r#"  {{"t1": {t1}, "t2": {t2}, "aspect": {aspect}, "matrix": [{a}, {b}, {c}, {d}, {e}, {f}]}}"#)
+                })
+                .collect();
+            format!("[\n{}\n]\n", objects.join(",\n"))
+        }
+    }
+}
@@ -0,0 +1,508 @@
+//! Perfect colourings of isohedral tilings with any small number of colours.
+//!
+//! A colouring is perfect when every symmetry of the tiling permutes the colours: two tiles of the
+//! same colour are mapped onto two tiles of the same colour. The symmetries considered are those
+//! of the tiling type, generated by the translations and the aspects; a prototile with extra
+//! symmetry of its own may have more.
+//!
+//! Every such colouring has the form used by Tactile's built-in 3-colouring: each aspect has a
+//! base colour, and a translation along `t1` or `t2` applies a fixed permutation to the colours.
+//! [`perfect_colourings`] searches these exhaustively for a given number of colours.
+use std::collections::HashSet;
+
+use crate::data::TilingTypeData;
+use crate::tiling::{EdgeShape, IsohedralTiling};
+use crate::utils::{Affine2, Mat2, Vec2, M_ORIENTS};
+
+
+/// The largest number of colours [`perfect_colourings`] searches.
+pub const MAX_COLOURS: usize = 8;
+
+/// The order of the largest commutative group of permutations of `n` colours, for `n` up to
+/// [`MAX_COLOURS`].
+const LARGEST_COMMUTATIVE: [usize; MAX_COLOURS + 1] = [1, 1, 2, 3, 4, 6, 9, 12, 18];
+
+/// Largest distance between two transforms, relative to the lattice, for them to be considered
+/// the same.
+const TOLERANCE: f32 = 1e-3;
+
+/// A colouring of the tiles of a tiling: a base colour per aspect and the permutations of the
+/// colours that the two translations apply.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Colouring {
+    base: Vec<usize>,
+    t1: Vec<usize>,
+    t2: Vec<usize>,
+    periods: (usize, usize),
+}
+
+impl Colouring {
+    /// A colouring from its base colours, one per aspect, and the permutations of the colours
+    /// applied by a translation along `t1` and along `t2`.
+    ///
+    /// Returns `None` unless both permutations are permutations of the same colours, they commute
+    /// and the base colours are among them.
+    pub fn new(base: Vec<usize>, t1: Vec<usize>, t2: Vec<usize>) -> Option<Self> {
+        let colours = t1.len();
+        let valid = t2.len() == colours
+            && is_permutation(&t1)
+            && is_permutation(&t2)
+            && (0..colours).all(|c| t1[t2[c]] == t2[t1[c]])
+            && base.iter().all(|&c| c < colours);
+        valid.then(|| {
+            let periods = (order(&t1), order(&t2));
+            Self { base, t1, t2, periods }
+        })
+    }
+
+    /// Tactile's 3-colouring of a tiling type, from its data.
+    pub(crate) fn from_data(ttd: &TilingTypeData) -> Self {
+        let colouring = ttd.colouring;
+        let colours = |range: std::ops::Range<usize>| colouring[range].iter().map(|&c| c as usize).collect();
+        Self::new(colours(0..ttd.num_aspects), colours(12..15), colours(15..18)).unwrap_or_default()
+    }
+
+    /// The number of colours, some of which may be unused.
+    pub fn num_colours(&self) -> usize {
+        self.t1.len()
+    }
+
+    /// The colour of the tile with the given lattice coordinates and aspect.
+    pub fn colour(&self, t1: isize, t2: isize, aspect: usize) -> usize {
+        let mut colour = self.base[aspect];
+        for _ in 0..t1.rem_euclid(self.periods.0.max(1) as isize) {
+            colour = self.t1[colour];
+        }
+        for _ in 0..t2.rem_euclid(self.periods.1.max(1) as isize) {
+            colour = self.t2[colour];
+        }
+        colour
+    }
+
+    /// The colour of each aspect in the tile at the origin of the lattice.
+    pub fn base_colours(&self) -> &[usize] {
+        &self.base
+    }
+
+    /// How a translation along `t1` permutes the colours: a tile of colour `c` is moved onto one
+    /// of colour `permutation[c]`.
+    pub fn permutation_t1(&self) -> &[usize] {
+        &self.t1
+    }
+
+    /// How a translation along `t2` permutes the colours, see [`permutation_t1`].
+    ///
+    /// [`permutation_t1`]: Colouring::permutation_t1
+    pub fn permutation_t2(&self) -> &[usize] {
+        &self.t2
+    }
+
+    /// The smallest number of translations along `t1` and along `t2` after which the colours
+    /// repeat.
+    pub fn periods(&self) -> (usize, usize) {
+        self.periods
+    }
+
+    /// Whether no two tiles of the same colour share an edge in `tiling`.
+    pub fn is_proper(&self, tiling: &IsohedralTiling) -> bool {
+        let (p1, p2) = (self.periods.0.max(1) as isize, self.periods.1.max(1) as isize);
+        neighbours(tiling).iter().all(|&(aspect, other, (d1, d2))| {
+            (0..p1).all(|t1| (0..p2).all(|t2| self.colour(t1, t2, aspect) != self.colour(t1 + d1, t2 + d2, other)))
+        })
+    }
+
+    /// The pairs of different colours, smaller first, that some two tiles sharing an edge in
+    /// `tiling` have.
+    pub fn adjacent_colours(&self, tiling: &IsohedralTiling) -> Vec<(usize, usize)> {
+        let (p1, p2) = (self.periods.0.max(1) as isize, self.periods.1.max(1) as isize);
+        let mut pairs = HashSet::new();
+        for (aspect, other, (d1, d2)) in neighbours(tiling) {
+            for t1 in 0..p1 {
+                for t2 in 0..p2 {
+                    let (a, b) = (self.colour(t1, t2, aspect), self.colour(t1 + d1, t2 + d2, other));
+                    if a != b {
+                        pairs.insert((a.min(b), a.max(b)));
+                    }
+                }
+            }
+        }
+        let mut pairs: Vec<_> = pairs.into_iter().collect();
+        pairs.sort_unstable();
+        pairs
+    }
+
+    /// The same colouring with the colours renumbered in the order they first appear, so that
+    /// colourings differing only by the names of their colours compare equal.
+    fn canonical(&self) -> Self {
+        let mut names = vec![usize::MAX; self.num_colours()];
+        let mut next = 0;
+        for t1 in 0..self.periods.0 as isize {
+            for t2 in 0..self.periods.1 as isize {
+                for aspect in 0..self.base.len() {
+                    let colour = self.colour(t1, t2, aspect);
+                    if names[colour] == usize::MAX {
+                        names[colour] = next;
+                        next += 1;
+                    }
+                }
+            }
+        }
+        for name in names.iter_mut().filter(|name| **name == usize::MAX) {
+            *name = next;
+            next += 1;
+        }
+        let rename = |permutation: &[usize]| {
+            let mut renamed = vec![0; permutation.len()];
+            for (c, &d) in permutation.iter().enumerate() {
+                renamed[names[c]] = names[d];
+            }
+            renamed
+        };
+        Self {
+            base: self.base.iter().map(|&c| names[c]).collect(),
+            t1: rename(&self.t1),
+            t2: rename(&self.t2),
+            periods: self.periods,
+        }
+    }
+}
+
+/// Every perfect colouring of `tiling` using all of `colours` colours, up to renaming the colours.
+///
+/// Proper colourings, where neighbouring tiles differ, come first, then those with the shortest
+/// periods. Nothing is found for more than [`MAX_COLOURS`] colours, or if the tiling type's data
+/// is inconsistent.
+pub fn perfect_colourings(tiling: &IsohedralTiling, colours: usize) -> Vec<Colouring> {
+    let Some(symmetries) = Symmetries::new(tiling) else {
+        return vec![];
+    };
+    if colours == 0 || colours > MAX_COLOURS {
+        return vec![];
+    }
+
+    // The translations that leave every colour in place form a sublattice, invariant under the
+    // symmetries. The others permute the colours as a commutative group, whose order is the
+    // sublattice's index, so only sublattices of small index need to be tried.
+    let mut found: Vec<Colouring> = vec![];
+    for sublattice in Sublattice::invariant(&symmetries, LARGEST_COMMUTATIVE[colours]) {
+        let action = Action::new(&symmetries, sublattice);
+        for blocks in action.block_systems(colours) {
+            let colouring = action.colouring(&blocks, colours).canonical();
+            if !found.contains(&colouring) {
+                found.push(colouring);
+            }
+        }
+    }
+
+    let mut ranked: Vec<(bool, usize, Colouring)> =
+        found.into_iter().map(|c| (!c.is_proper(tiling), c.periods.0 * c.periods.1, c)).collect();
+    ranked.sort_by_key(|&(improper, cells, _)| (improper, cells));
+    ranked.into_iter().map(|(_, _, c)| c).collect()
+}
+
+/// The symmetry group of a tiling type, as its action on the tiles.
+struct Symmetries {
+    /// `maps[j][i]` is where the symmetry taking aspect 0 onto aspect `j` moves aspect `i` of the
+    /// tile at the origin: an aspect and a lattice translation.
+    maps: Vec<Vec<(usize, (isize, isize))>>,
+    /// The images of the two translation vectors under the linear part of each symmetry, in
+    /// lattice coordinates.
+    linear: Vec<[(isize, isize); 2]>,
+}
+
+impl Symmetries {
+    /// The symmetries of `tiling`: for every aspect `j` and every symmetry `s` of the prototile,
+    /// the isometry `aspect j * s * aspect 0⁻¹`, if it maps every tile onto a tile. At least one of
+    /// them does for each aspect, unless the data is inconsistent.
+    fn new(tiling: &IsohedralTiling) -> Option<Self> {
+        let n = tiling.num_aspects();
+        let aspects: Vec<_> = (0..n).map(|i| *tiling.aspect_transform(i)).collect();
+        let stabiliser = stabiliser(tiling);
+        let lattice = |v: Vec2| {
+            let c = tiling.lattice_coords(v);
+            let (x, y) = (c.x.round(), c.y.round());
+            ((c.x - x).abs() < TOLERANCE && (c.y - y).abs() < TOLERANCE).then_some((x as isize, y as isize))
+        };
+        let identity = |m: Mat2| (m.x_axis - Vec2::X).length() < TOLERANCE && (m.y_axis - Vec2::Y).length() < TOLERANCE;
+        // The tile that `moved` places the prototile onto, up to a symmetry of the prototile.
+        let tile = |moved: Affine2| {
+            (0..n).find_map(|a| {
+                stabiliser.iter().find_map(|&s| {
+                    let offset = moved * (aspects[a] * s).inverse();
+                    if identity(offset.matrix2) { lattice(offset.translation).map(|u| (a, u)) } else { None }
+                })
+            })
+        };
+
+        let mut maps = vec![];
+        let mut linear = vec![];
+        for j in 0..n {
+            let before = maps.len();
+            for &s in &stabiliser {
+                let symmetry = aspects[j] * s * aspects[0].inverse();
+                let Some(row) = (0..n).map(|i| tile(symmetry * aspects[i])).collect::<Option<Vec<_>>>() else {
+                    continue;
+                };
+                maps.push(row);
+                linear.push([lattice(symmetry.matrix2 * *tiling.t1())?, lattice(symmetry.matrix2 * *tiling.t2())?]);
+            }
+            if maps.len() == before {
+                return None;
+            }
+        }
+        Some(Self { maps, linear })
+    }
+}
+
+/// The isometries mapping the prototile onto itself, edge shapes included: every edge must land
+/// on an edge of the same shape, in a direction that the shape's symmetry allows.
+fn stabiliser(tiling: &IsohedralTiling) -> Vec<Affine2> {
+    let edges: Vec<(Affine2, usize, EdgeShape)> = tiling.shapes().map(|e| (e.transform(), e.id(), e.shape())).collect();
+    let scale = tiling.t1().length().max(tiling.t2().length());
+    let close = |a: Affine2, b: Affine2| {
+        (a.matrix2.x_axis - b.matrix2.x_axis).length() < TOLERANCE
+            && (a.matrix2.y_axis - b.matrix2.y_axis).length() < TOLERANCE
+            && (a.translation - b.translation).length() < TOLERANCE * scale
+    };
+    // What each edge shape may be transformed by in the unit frame and still look the same.
+    let allowed = |shape: EdgeShape| match shape {
+        EdgeShape::J => &M_ORIENTS[..1],
+        EdgeShape::U => &[M_ORIENTS[0], M_ORIENTS[2]][..],
+        EdgeShape::S => &M_ORIENTS[..2],
+        EdgeShape::I => &M_ORIENTS[..],
+    };
+    let first = edges[0];
+    let mut found: Vec<Affine2> = vec![];
+    for &(target, _, shape) in edges.iter().filter(|e| e.1 == first.1) {
+        for &orient in allowed(shape) {
+            let s = target * orient * first.0.inverse();
+            let maps_edges = edges.iter().all(|&(e, id, shape)| {
+                edges.iter().any(|&(f, other, _)| other == id && allowed(shape).iter().any(|&m| close(s * e, f * m)))
+            });
+            if maps_edges && !found.iter().any(|&t| close(s, t)) {
+                found.push(s);
+            }
+        }
+    }
+    found
+}
+
+/// A sublattice of the lattice of translations, spanned by `(a, 0)` and `(b, d)` in lattice
+/// coordinates with `0 <= b < a`. Every sublattice has exactly one such basis.
+#[derive(Debug, Clone, Copy)]
+struct Sublattice {
+    a: isize,
+    b: isize,
+    d: isize,
+}
+
+impl Sublattice {
+    /// Every sublattice of index at most `index` that the linear parts of the symmetries map onto
+    /// itself.
+    fn invariant(symmetries: &Symmetries, index: usize) -> Vec<Self> {
+        let index = index as isize;
+        (1..=index)
+            .flat_map(|a| (1..=index / a).flat_map(move |d| (0..a).map(move |b| Self { a, b, d })))
+            .filter(|lattice| {
+                symmetries.linear.iter().all(|&[(x1, y1), (x2, y2)]| {
+                    let image = |(x, y): (isize, isize)| (x * x1 + y * x2, x * y1 + y * y2);
+                    lattice.contains(image((lattice.a, 0))) && lattice.contains(image((lattice.b, lattice.d)))
+                })
+            })
+            .collect()
+    }
+
+    fn index(&self) -> usize {
+        (self.a * self.d) as usize
+    }
+
+    fn contains(&self, (x, y): (isize, isize)) -> bool {
+        y % self.d == 0 && (x - y / self.d * self.b) % self.a == 0
+    }
+
+    /// The number of the coset of `(t1, t2)`, less than [`index`].
+    ///
+    /// [`index`]: Sublattice::index
+    fn coset(&self, t1: isize, t2: isize) -> usize {
+        let (j, r2) = (t2.div_euclid(self.d), t2.rem_euclid(self.d));
+        ((t1 - j * self.b).rem_euclid(self.a) + self.a * r2) as usize
+    }
+}
+
+/// The action of the symmetries on the tiles modulo the translations of a sublattice, numbered
+/// `aspect + aspects * coset`.
+///
+/// A perfect colouring repeating on the sublattice is a partition of these tiles into blocks of
+/// one colour that every symmetry maps onto blocks: a block system of the action.
+struct Action {
+    aspects: usize,
+    sublattice: Sublattice,
+    /// The permutation of the tiles by each symmetry, the two translations first.
+    generators: Vec<Vec<usize>>,
+}
+
+impl Action {
+    fn new(symmetries: &Symmetries, sublattice: Sublattice) -> Self {
+        let aspects = symmetries.maps.first().map_or(0, Vec::len);
+        let index = |aspect: usize, t1: isize, t2: isize| aspect + aspects * sublattice.coset(t1, t2);
+        // One representative of every tile, in the order of their numbers.
+        let tiles = || {
+            (0..sublattice.d).flat_map(move |t2| {
+                (0..sublattice.a).flat_map(move |t1| (0..aspects).map(move |aspect| (aspect, t1, t2)))
+            })
+        };
+
+        let mut generators: Vec<Vec<usize>> = vec![
+            tiles().map(|(aspect, t1, t2)| index(aspect, t1 + 1, t2)).collect(),
+            tiles().map(|(aspect, t1, t2)| index(aspect, t1, t2 + 1)).collect(),
+        ];
+        for (map, &[(a, b), (c, d)]) in symmetries.maps.iter().zip(&symmetries.linear) {
+            let generator: Vec<usize> = tiles()
+                .map(|(aspect, t1, t2)| {
+                    let (to, (u1, u2)) = map[aspect];
+                    index(to, a * t1 + c * t2 + u1, b * t1 + d * t2 + u2)
+                })
+                .collect();
+            if !generators.contains(&generator) {
+                generators.push(generator);
+            }
+        }
+        Self { aspects, sublattice, generators }
+    }
+
+    /// Every block system with exactly `count` blocks, as the block of each tile.
+    fn block_systems(&self, count: usize) -> Vec<Vec<usize>> {
+        let tiles = self.aspects * self.sublattice.index();
+        if !tiles.is_multiple_of(count) {
+            return vec![];
+        }
+        let singletons: Vec<usize> = (0..tiles).collect();
+        // Every block system is a join of the smallest ones putting tile 0 with one other tile.
+        // The blocks of a finer system split those of a coarser one evenly, so only systems with
+        // a multiple of `count` blocks can lead to one with `count` blocks.
+        let mut smallest: Vec<Vec<usize>> = vec![];
+        for tile in 1..tiles {
+            let blocks = self.join(&singletons, tile);
+            if block_count(&blocks).is_multiple_of(count) {
+                smallest.push(blocks);
+            }
+        }
+
+        let mut systems: Vec<Vec<usize>> = if count == tiles { vec![singletons] } else { vec![] };
+        let mut seen: HashSet<Vec<usize>> = systems.iter().cloned().collect();
+        for blocks in smallest {
+            if seen.insert(blocks.clone()) {
+                systems.push(blocks);
+            }
+        }
+        let mut next = 0;
+        while next < systems.len() {
+            let blocks = systems[next].clone();
+            next += 1;
+            if block_count(&blocks) == count {
+                continue;
+            }
+            // Joining depends only on the block the other tile is in.
+            for tile in (1..tiles).filter(|&tile| blocks[tile] == tile) {
+                let joined = self.join(&blocks, tile);
+                if block_count(&joined).is_multiple_of(count) && seen.insert(joined.clone()) {
+                    systems.push(joined);
+                }
+            }
+        }
+        systems.retain(|blocks| block_count(blocks) == count);
+        systems
+    }
+
+    /// The smallest block system coarser than `blocks` with tile 0 and `tile` in one block, as
+    /// the first tile of the block of every tile.
+    fn join(&self, blocks: &[usize], tile: usize) -> Vec<usize> {
+        let mut parent: Vec<usize> = blocks.to_vec();
+        fn root(parent: &mut [usize], mut tile: usize) -> usize {
+            while parent[tile] != tile {
+                parent[tile] = parent[parent[tile]];
+                tile = parent[tile];
+            }
+            tile
+        }
+        let mut pending = vec![(0, tile)];
+        while let Some((a, b)) = pending.pop() {
+            let (ra, rb) = (root(&mut parent, a), root(&mut parent, b));
+            if ra == rb {
+                continue;
+            }
+            parent[ra.max(rb)] = ra.min(rb);
+            pending.extend(self.generators.iter().map(|g| (g[a], g[b])));
+        }
+        (0..parent.len()).map(|t| root(&mut parent, t)).collect()
+    }
+
+    /// The colouring giving each block of `blocks` its own colour.
+    fn colouring(&self, blocks: &[usize], count: usize) -> Colouring {
+        let mut colours = vec![usize::MAX; blocks.len()];
+        let mut next = 0;
+        for &block in blocks {
+            if colours[block] == usize::MAX {
+                colours[block] = next;
+                next += 1;
+            }
+        }
+        let colour = |tile: usize| colours[blocks[tile]];
+        let mut t1 = vec![0; count];
+        let mut t2 = vec![0; count];
+        for tile in 0..blocks.len() {
+            t1[colour(tile)] = colour(self.generators[0][tile]);
+            t2[colour(tile)] = colour(self.generators[1][tile]);
+        }
+        let base = (0..self.aspects).map(colour).collect();
+        let periods = (order(&t1), order(&t2));
+        Colouring { base, t1, t2, periods }
+    }
+}
+
+/// The number of blocks of a block system numbered as by [`Action::join`].
+fn block_count(blocks: &[usize]) -> usize {
+    blocks.iter().enumerate().filter(|&(tile, &block)| tile == block).count()
+}
+
+/// The neighbours of each aspect of the tile at the origin: for every edge, the aspect and the
+/// lattice offset of the tile on its other side.
+fn neighbours(tiling: &IsohedralTiling) -> Vec<(usize, usize, (isize, isize))> {
+    let n = tiling.num_vertices();
+    let scale = tiling.t1().length().max(tiling.t2().length());
+    let close = |a: Vec2, b: Vec2| (a - b).length() < TOLERANCE * scale;
+    let edges = |t1: isize, t2: isize, aspect: usize| -> Vec<(Vec2, Vec2)> {
+        let t = tiling.tile(t1, t2, aspect).transform();
+        (0..n).map(|i| (t.transform_point2(*tiling.vertex(i)), t.transform_point2(*tiling.vertex((i + 1) % n)))).collect()
+    };
+    let mut found = vec![];
+    for aspect in 0..tiling.num_aspects() {
+        for (a, b) in edges(0, 0, aspect) {
+            let other = (-2..=2)
+                .flat_map(|t1| (-2..=2).flat_map(move |t2| (0..tiling.num_aspects()).map(move |other| (t1, t2, other))))
+                .filter(|&key| key != (0, 0, aspect))
+                .find(|&(t1, t2, other)| edges(t1, t2, other).into_iter().any(|(p, q)| close(p, b) && close(q, a) || close(p, a) && close(q, b)));
+            if let Some((t1, t2, other)) = other {
+                found.push((aspect, other, (t1, t2)));
+            }
+        }
+    }
+    found
+}
+
+fn is_permutation(permutation: &[usize]) -> bool {
+    let mut seen = vec![false; permutation.len()];
+    permutation.iter().all(|&c| c < seen.len() && !std::mem::replace(&mut seen[c], true))
+}
+
+/// The number of times `permutation` has to be applied to get the identity.
+fn order(permutation: &[usize]) -> usize {
+    let mut current = permutation.to_vec();
+    let mut order = 1;
+    while current.iter().enumerate().any(|(c, &d)| c != d) {
+        current = current.iter().map(|&c| permutation[c]).collect();
+        order += 1;
+    }
+    order
+}
+
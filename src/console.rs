@@ -0,0 +1,203 @@
+//! A console window for driving the app with typed commands, for power users and debugging.
+//!
+//! Each line is a command name followed by its arguments, separated by spaces; `help` lists the
+//! commands. The output of every command is printed below it.
+use eframe::egui;
+use egui_isohedral::{colouring::MAX_COLOURS, TILING_TYPES};
+
+use crate::app::App;
+use crate::link;
+use crate::interface::{run_export, ExportKind};
+
+
+/// Number of lines of output the console keeps.
+const MAX_LINES: usize = 500;
+
+const HELP: &str = "\
+help                 list the commands
+type [n | IHnn]      show the tiling type, or switch to the n-th valid type or to IHnn
+params [v0 v1 ...]   show the parameters, or set all of them
+param <i> <value>    set parameter i
+colours [k]          show the number of colours, or colour the tiling with k
+edges default|random replace every edge shape
+link [fragment]      show the link fragment of the tiling, or open one
+stats                describe the tiling and the view
+verify               check the tiling type's data for inconsistencies
+export <kind>        save an export, one of: {kinds}
+clear                clear the output";
+
+/// State of the console window.
+#[derive(Default)]
+pub struct Console {
+    pub open: bool,
+    input: String,
+    output: Vec<String>,
+    history: Vec<String>,
+    /// Position in `history` while recalling earlier commands with the arrow keys.
+    recalled: Option<usize>,
+}
+
+/// Show the console window, if it is open.
+pub fn show(app: &mut App, ctx: &egui::Context) {
+    let mut open = app.console.open;
+    let mut command = None;
+    egui::Window::new("Console").open(&mut open).default_width(480.).show(ctx, |ui| {
+        let console = &mut app.console;
+        egui::ScrollArea::vertical().max_height(240.).stick_to_bottom(true).auto_shrink([false, true]).show(ui, |ui| {
+            for line in &console.output {
+                ui.label(egui::RichText::new(line).monospace());
+            }
+        });
+        let response = ui.add(egui::TextEdit::singleline(&mut console.input).font(egui::TextStyle::Monospace).hint_text("help").desired_width(f32::INFINITY));
+        if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+            command = Some(std::mem::take(&mut console.input));
+            response.request_focus();
+        } else if response.has_focus() {
+            recall(console, ui);
+        }
+    });
+    app.console.open = open;
+
+    if let Some(command) = command.filter(|command| !command.trim().is_empty()) {
+        let output = (command.trim() != "clear").then(|| execute(app, ctx, &command).unwrap_or_else(|err| format!("error: {err}")));
+        let console = &mut app.console;
+        match output {
+            Some(output) => {
+                console.output.push(format!("> {command}"));
+                console.output.extend(output.lines().map(String::from));
+                let excess = console.output.len().saturating_sub(MAX_LINES);
+                console.output.drain(..excess);
+            }
+            None => console.output.clear(),
+        }
+        console.history.push(command);
+        console.recalled = None;
+    }
+}
+
+/// Step through earlier commands with the up and down arrow keys.
+fn recall(console: &mut Console, ui: &egui::Ui) {
+    let (up, down) = ui.input(|i| (i.key_pressed(egui::Key::ArrowUp), i.key_pressed(egui::Key::ArrowDown)));
+    if console.history.is_empty() || !(up || down) {
+        return;
+    }
+    let last = console.history.len() - 1;
+    console.recalled = match (console.recalled, up) {
+        (None, true) => Some(last),
+        (Some(i), true) => Some(i.saturating_sub(1)),
+        (Some(i), false) if i < last => Some(i + 1),
+        _ => None,
+    };
+    console.input = console.recalled.map(|i| console.history[i].clone()).unwrap_or_default();
+}
+
+/// Run one command and return its output. Exports of the view are taken of the screen of `ctx`,
+/// which links apply their theme to.
+pub fn execute(app: &mut App, ctx: &egui::Context, command: &str) -> Result<String, String> {
+    let words: Vec<&str> = command.split_whitespace().collect();
+    match words[..] {
+        ["help"] => {
+            let kinds: Vec<&str> = ExportKind::ALL.iter().map(|kind| kind.name()).collect();
+            Ok(HELP.replace("{kinds}", &kinds.join(", ")))
+        }
+        ["type"] => Ok(format!("{} (type {} of {})", app.tiling.tiling_type(), app.tile_type_num, TILING_TYPES.len())),
+        ["type", name] => tiling_type(name).map(|n| {
+            app.set_tiling_type(n);
+            format!("switched to {}", app.tiling.tiling_type())
+        }),
+        ["params"] => Ok(params(app)),
+        ["param", i, value] => set_param(app, i, value).map(|()| params(app)),
+        ["params", ref values @ ..] => set_params(app, values).map(|()| params(app)),
+        ["colours"] => Ok(format!("{} colours", app.tiling.num_colours())),
+        ["colours", k] => match k.parse::<usize>() {
+            Ok(k) if (1..=MAX_COLOURS).contains(&k) => app.tiling.set_colouring(k).map_err(|err| err.to_string()).map(|()| {
+                app.colours = k;
+                format!("{k} colours")
+            }),
+            _ => Err(format!("the number of colours must be from 1 to {MAX_COLOURS}")),
+        },
+        ["edges", "default"] => {
+            app.set_default_edges();
+            Ok(String::from("straight edges"))
+        }
+        ["edges", "random"] => {
+            app.next_seed();
+            app.set_random_edges();
+            Ok(format!("random edges from seed {}", app.seed))
+        }
+        ["link"] => Ok(format!("#{}", link::fragment(app))),
+        ["link", fragment] => link::open(app, ctx, fragment).map(|()| format!("switched to {}", app.tiling.tiling_type())),
+        ["stats"] => Ok(stats(app)),
+        ["verify"] => {
+            let inconsistencies = app.tiling.verify();
+            if inconsistencies.is_empty() {
+                Ok(String::from("no inconsistencies"))
+            } else {
+                Ok(inconsistencies.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n"))
+            }
+        }
+        ["export", name] => match ExportKind::ALL.into_iter().find(|kind| kind.name() == name) {
+            Some(kind) => {
+                run_export(app, ctx.screen_rect(), kind);
+                Ok(app.status.clone())
+            }
+            None => Err(format!("unknown export {name:?}, see help")),
+        },
+        _ => Err(format!("unknown command {command:?}, see help")),
+    }
+}
+
+/// The index of a valid tiling type, given as an index or as `IHnn`.
+fn tiling_type(name: &str) -> Result<usize, String> {
+    let number = |text: &str| text.parse::<usize>().map_err(|_| format!("{name:?} is not a tiling type"));
+    match name.strip_prefix("IH").or_else(|| name.strip_prefix("ih")) {
+        Some(ih) => {
+            let ih = number(ih)?;
+            TILING_TYPES.iter().position(|&t| t == ih).ok_or_else(|| format!("IH{ih:02} is not a valid tiling type"))
+        }
+        None => number(name).and_then(|n| if n < TILING_TYPES.len() { Ok(n) } else { Err(format!("there are only {} tiling types", TILING_TYPES.len())) }),
+    }
+}
+
+fn params(app: &App) -> String {
+    let count = app.tiling.num_params();
+    if count == 0 {
+        return format!("{} has no parameters", app.tiling.tiling_type());
+    }
+    app.params[..count].iter().enumerate().map(|(i, v)| format!("v{i} = {v}")).collect::<Vec<_>>().join("\n")
+}
+
+fn set_param(app: &mut App, i: &str, value: &str) -> Result<(), String> {
+    let count = app.tiling.num_params();
+    let i = i.parse::<usize>().ok().filter(|&i| i < count).ok_or_else(|| format!("{} has {count} parameters", app.tiling.tiling_type()))?;
+    let value = value.parse::<f32>().ok().filter(|v| v.is_finite()).ok_or_else(|| format!("{value:?} is not a number"))?;
+    app.params[i] = value;
+    app.tiling.set_parameters(&app.params);
+    Ok(())
+}
+
+fn set_params(app: &mut App, values: &[&str]) -> Result<(), String> {
+    let count = app.tiling.num_params();
+    if values.len() != count {
+        return Err(format!("{} has {count} parameters", app.tiling.tiling_type()));
+    }
+    let values = values.iter().map(|value| value.parse::<f32>().ok().filter(|v| v.is_finite()).ok_or_else(|| format!("{value:?} is not a number"))).collect::<Result<Vec<f32>, String>>()?;
+    app.params[..count].copy_from_slice(&values);
+    app.tiling.set_parameters(&app.params);
+    Ok(())
+}
+
+fn stats(app: &App) -> String {
+    let tiling = &app.tiling;
+    let (p1, p2) = tiling.colour_periods();
+    [
+        format!("type        {}", tiling.tiling_type()),
+        format!("vertices    {}", tiling.num_vertices()),
+        format!("edge shapes {}", (0..tiling.num_edge_shapes()).map(|i| format!("{:?}", tiling.edge_shape(i))).collect::<Vec<_>>().join(" ")),
+        format!("aspects     {}", tiling.num_aspects()),
+        format!("colours     {}, repeating every {p1} x {p2} translational units", tiling.num_colours()),
+        format!("visible     {} tiles", app.tile_cache.tiles().count()),
+        format!("edge problems {}", app.outline_cache.problems().len()),
+    ]
+    .join("\n")
+}
@@ -0,0 +1,81 @@
+//! Setting the desktop wallpaper and printing, enabled by the `set-wallpaper` and `print`
+//! features.
+//!
+//! This shells out to the tools the desktop already ships with rather than linking platform APIs.
+use std::{io, path::Path, process::Command};
+
+
+/// Make the image at `path` the desktop wallpaper.
+#[cfg(feature = "set-wallpaper")]
+pub fn set_wallpaper(path: &Path) -> io::Result<()> {
+    let path = path.canonicalize()?;
+    let status = command(&path)?.status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!("wallpaper command failed with {status}")))
+    }
+}
+
+#[cfg(all(feature = "set-wallpaper", target_os = "macos"))]
+fn command(path: &Path) -> io::Result<Command> {
+    let mut command = Command::new("osascript");
+    command.args([
+        "-e",
+        &format!(r#"tell application "System Events" to tell every desktop to set picture to "{}""#, path.display()),
+    ]);
+    Ok(command)
+}
+
+/// GNOME and its derivatives; other desktops keep their own settings.
+#[cfg(all(feature = "set-wallpaper", unix, not(target_os = "macos")))]
+fn command(path: &Path) -> io::Result<Command> {
+    let uri = format!("file://{}", path.display());
+    let mut command = Command::new("sh");
+    command.args([
+        "-c",
+        r#"gsettings set org.gnome.desktop.background picture-uri "$0" && gsettings set org.gnome.desktop.background picture-uri-dark "$0""#,
+        &uri,
+    ]);
+    Ok(command)
+}
+
+#[cfg(all(feature = "set-wallpaper", not(unix)))]
+fn command(_path: &Path) -> io::Result<Command> {
+    Err(io::Error::new(io::ErrorKind::Unsupported, "setting the wallpaper isn't supported on this platform"))
+}
+
+/// Send the document at `path` to the default printer.
+#[cfg(feature = "print")]
+pub fn print(path: &Path) -> io::Result<()> {
+    let path = path.canonicalize()?;
+    let status = print_command(&path)?.status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!("print command failed with {status}")))
+    }
+}
+
+/// CUPS, on Linux and macOS alike.
+#[cfg(all(feature = "print", unix))]
+fn print_command(path: &Path) -> io::Result<Command> {
+    let mut command = Command::new("lp");
+    command.arg(path);
+    Ok(command)
+}
+
+/// The print verb of the application registered for PDF files.
+#[cfg(all(feature = "print", windows))]
+fn print_command(path: &Path) -> io::Result<Command> {
+    let mut command = Command::new("powershell");
+    // Single quotes are doubled inside a single-quoted PowerShell string.
+    let path = path.display().to_string().replace('\'', "''");
+    command.args(["-NoProfile", "-Command", &format!("Start-Process -FilePath '{path}' -Verb Print")]);
+    Ok(command)
+}
+
+#[cfg(all(feature = "print", not(any(unix, windows))))]
+fn print_command(_path: &Path) -> io::Result<Command> {
+    Err(io::Error::new(io::ErrorKind::Unsupported, "printing isn't supported on this platform"))
+}
@@ -0,0 +1,493 @@
+//! A window for editing the edge shapes of the prototile.
+//!
+//! Every edge runs from `(0, 0)` to `(1, 0)` in its own frame. U and S edges are kept symmetric
+//! by moving, adding and removing their control points in pairs. While the editor is open, the
+//! edges of the tile in the middle of the view are labelled with their edge shape class. With the
+//! onion skin on, a dragged edge is shown over its geometry from before the drag, in the editor
+//! and on that tile, and the editor links the dragged point to the partner moving with it.
+use eframe::egui;
+#[cfg(feature = "glyphs")]
+use egui_isohedral::glyph::{self, GlyphFit};
+use egui_isohedral::{edges, tiling::{EdgeProblem, EdgeShape}, vec2, Vec2};
+use rand::RngCore;
+
+use crate::{app::{self, App, Roll, Selection}, interface, project};
+
+
+/// Distance in screen points within which a control point can be grabbed, and within which
+/// dragged points snap to symmetry positions.
+const GRAB_RADIUS: f32 = 8.;
+
+/// Number of segments used to preview the selected edge.
+const PREVIEW_SEGMENTS: usize = 64;
+
+/// Number of control points of a noise edge.
+pub const NOISE_POINTS: usize = 32;
+
+/// Number of control points of an edge made from a glyph.
+#[cfg(feature = "glyphs")]
+const GLYPH_POINTS: usize = 96;
+
+/// State of the edge editor window.
+pub struct EdgeEditor {
+    pub open: bool,
+    selected: usize,
+    dragging: Option<usize>,
+    onion_skin: bool,
+    /// The selected edge as it was when the drag started, while dragging with the onion skin on.
+    before: Option<Vec<Vec2>>,
+    snap_grid: bool,
+    grid_divisions: u32,
+    snap_symmetry: bool,
+    scale_factor: f32,
+    smooth_iterations: usize,
+    simplify_tolerance: f32,
+    noise: edges::Noise,
+    #[cfg(feature = "glyphs")]
+    font_path: String,
+    #[cfg(feature = "glyphs")]
+    glyph: String,
+    #[cfg(feature = "glyphs")]
+    glyph_fit: GlyphFit,
+    #[cfg(feature = "glyphs")]
+    glyph_status: String,
+}
+
+impl Default for EdgeEditor {
+    fn default() -> Self {
+        Self {
+            open: false,
+            selected: 0,
+            dragging: None,
+            onion_skin: true,
+            before: None,
+            snap_grid: false,
+            grid_divisions: 8,
+            snap_symmetry: true,
+            scale_factor: 0.8,
+            smooth_iterations: 1,
+            simplify_tolerance: 0.005,
+            noise: edges::Noise::default(),
+            #[cfg(feature = "glyphs")]
+            font_path: String::new(),
+            #[cfg(feature = "glyphs")]
+            glyph: String::from("A"),
+            #[cfg(feature = "glyphs")]
+            glyph_fit: GlyphFit::TopContour,
+            #[cfg(feature = "glyphs")]
+            glyph_status: String::new(),
+        }
+    }
+}
+
+impl EdgeEditor {
+    /// The selected edge from before the drag running, if the onion skin shows it.
+    fn onion(&self) -> Option<&[Vec2]> {
+        self.before.as_deref().filter(|_| self.dragging.is_some())
+    }
+
+    /// Snap a point given in the edge's frame. Symmetry positions within `tolerance` take
+    /// precedence over the grid.
+    fn snap(&self, mut p: Vec2, shape: EdgeShape, tolerance: f32) -> Vec2 {
+        let (mut snapped_x, mut snapped_y) = (false, false);
+        if self.snap_symmetry {
+            let midpoint = vec2(0.5, 0.);
+            if (p - midpoint).length() < tolerance {
+                return midpoint;
+            }
+            if shape == EdgeShape::U && (p.x - 0.5).abs() < tolerance {
+                p.x = 0.5;
+                snapped_x = true;
+            }
+            if p.y.abs() < tolerance {
+                p.y = 0.;
+                snapped_y = true;
+            }
+        }
+        if self.snap_grid {
+            let step = 1. / self.grid_divisions as f32;
+            if !snapped_x {
+                p.x = (p.x / step).round() * step;
+            }
+            if !snapped_y {
+                p.y = (p.y / step).round() * step;
+            }
+        }
+        p
+    }
+}
+
+/// Replace the edge selected in the editor, projected onto its edge shape's constraint, and
+/// return its index.
+pub fn replace_selected(app: &mut App, edge: &[Vec2]) -> Option<usize> {
+    let selected = app.edge_editor.selected;
+    let target = app.edges_shapes.get_mut(selected)?;
+    *target = edges::project(edge, app.tiling.edge_shape(selected));
+    Some(selected)
+}
+
+/// Use the font at `path` for glyph edges, and open the editor to pick a glyph.
+#[cfg(feature = "glyphs")]
+pub fn set_font(app: &mut App, path: &str) {
+    app.edge_editor.font_path = path.to_string();
+    app.edge_editor.open = true;
+}
+
+/// The path of the font used for glyph edges, empty if none was picked.
+#[cfg(feature = "glyphs")]
+pub fn font_path(app: &App) -> &str {
+    &app.edge_editor.font_path
+}
+
+/// Show the edge editor window, if it is open.
+pub fn show(app: &mut App, ctx: &egui::Context) {
+    let mut open = app.edge_editor.open;
+    let save = egui::Window::new("Edge editor").open(&mut open).resizable(false).show(ctx, |ui| edit(app, ui));
+    app.edge_editor.open = open;
+
+    if save.and_then(|response| response.inner) == Some(true) {
+        let selected = app.edge_editor.selected;
+        if let Some(edge) = app.edges_shapes.get(selected) {
+            let json = project::edge_json(edge);
+            interface::save_export(app, &format!("isohedral_edge_{selected}.json"), json.as_bytes());
+        }
+    }
+}
+
+/// The editor's contents. Returns whether the selected edge should be saved to a file.
+fn edit(app: &mut App, ui: &mut egui::Ui) -> bool {
+    let App { edge_editor: editor, edges_shapes, tiling, outline_cache, seed, selection, .. } = app;
+    let count = edges_shapes.len();
+    editor.selected = editor.selected.min(count.saturating_sub(1));
+    let problems = outline_cache.problems();
+
+    ui.horizontal(|ui| {
+        ui.label("edge");
+        for i in 0..count {
+            let warning = if problems.iter().any(|&(id, _)| id == i) { " ⚠" } else { "" };
+            ui.selectable_value(&mut editor.selected, i, format!("{i} ({:?}){warning}", tiling.edge_shape(i)));
+        }
+    });
+    for &(_, problem) in problems.iter().filter(|&&(id, _)| id == editor.selected) {
+        ui.colored_label(ui.visuals().error_fg_color, match problem {
+            EdgeProblem::Overlap => "⚠ This edge makes neighbouring tiles overlap.",
+            EdgeProblem::Asymmetric => "⚠ This edge isn't symmetric, so it doesn't fit its neighbour.",
+        });
+    }
+    ui.horizontal(|ui| {
+        ui.checkbox(&mut editor.snap_grid, "snap to grid");
+        ui.add_enabled(editor.snap_grid, egui::DragValue::new(&mut editor.grid_divisions).range(2..=64).prefix("1/"));
+        ui.checkbox(&mut editor.snap_symmetry, "snap to symmetry");
+        ui.checkbox(&mut editor.onion_skin, "onion skin").on_hover_text("While dragging, show the edge from before the drag and link the point to its partner");
+    });
+    if count == 0 {
+        return false;
+    }
+
+    let shape = tiling.edge_shape(editor.selected);
+    let (response, painter) = ui.allocate_painter(egui::vec2(360., 220.), egui::Sense::click_and_drag());
+    let rect = response.rect;
+    let scale = rect.width() / 1.2;
+    let origin = rect.left_center() + egui::vec2(0.1 * scale, 0.);
+    let to_screen = |p: Vec2| origin + egui::vec2(p.x, p.y) * scale;
+    let to_edge = |pos: egui::Pos2| {
+        let v = (pos - origin) / scale;
+        vec2(v.x, v.y)
+    };
+    let tolerance = GRAB_RADIUS / scale;
+
+    let visuals = ui.visuals().clone();
+    let guide = egui::Stroke::new(1., visuals.weak_text_color());
+    painter.rect_filled(rect, 4., visuals.extreme_bg_color);
+    if editor.snap_grid {
+        let step = scale / editor.grid_divisions as f32;
+        let faint = egui::Stroke::new(0.5, visuals.faint_bg_color);
+        let (columns, rows) = ((rect.width() / step) as i32 + 1, (rect.height() / step / 2.) as i32 + 1);
+        for k in -columns..=columns {
+            let x = origin.x + k as f32 * step;
+            if rect.x_range().contains(x) {
+                painter.vline(x, rect.y_range(), faint);
+            }
+        }
+        for k in -rows..=rows {
+            painter.hline(rect.x_range(), origin.y + k as f32 * step, faint);
+        }
+    }
+    painter.line_segment([to_screen(Vec2::ZERO), to_screen(Vec2::X)], guide);
+    match shape {
+        EdgeShape::U => {
+            let x = to_screen(vec2(0.5, 0.)).x;
+            painter.extend(egui::Shape::dashed_line(&[egui::pos2(x, rect.top()), egui::pos2(x, rect.bottom())], guide, 6., 4.));
+        }
+        EdgeShape::S => {
+            painter.circle_stroke(to_screen(vec2(0.5, 0.)), 4., guide);
+        }
+        EdgeShape::J | EdgeShape::I => {}
+    }
+
+    let edge = &mut edges_shapes[editor.selected];
+    // The drag holds a point index across frames, and the edge may have been replaced since it
+    // started, by a loaded project, a finished morph or a remote command. Drop a drag whose
+    // point is gone.
+    if editor.dragging.is_some_and(|i| i == 0 || i + 1 >= edge.len())
+        || editor.before.as_ref().is_some_and(|before| before.len() != edge.len())
+    {
+        editor.dragging = None;
+        editor.before = None;
+    }
+    if shape != EdgeShape::I {
+        let hovered = response.hover_pos().and_then(|pos| nearest_point(edge, to_edge(pos), tolerance));
+        if hovered.is_some() || editor.dragging.is_some() {
+            ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
+        }
+        if response.drag_started() {
+            editor.dragging = hovered;
+            editor.before = editor.onion_skin.then(|| edge.clone());
+        }
+        if let Some(point) = hovered.filter(|&i| i > 0 && i + 1 < edge.len())
+            && (response.drag_started() || response.clicked())
+        {
+            *selection = Some(Selection::EdgePoint { edge: editor.selected, point });
+        }
+        if let (Some(i), Some(pos), true) = (editor.dragging, response.interact_pointer_pos(), response.dragged()) {
+            move_point(edge, shape, i, editor.snap(to_edge(pos), shape, tolerance));
+        }
+        if response.drag_stopped() {
+            editor.dragging = None;
+            editor.before = None;
+        }
+        if let (true, Some(pos)) = (response.double_clicked(), response.interact_pointer_pos()) {
+            insert_point(edge, shape, editor.snap(to_edge(pos), shape, tolerance));
+        }
+        if let (true, Some(i)) = (response.secondary_clicked(), hovered) {
+            remove_point(edge, shape, i);
+            *selection = None;
+        }
+    }
+
+    if let (Some(before), Some(i)) = (editor.onion(), editor.dragging) {
+        let faint = egui::Stroke::new(1.5, visuals.weak_text_color().gamma_multiply(0.6));
+        let curve: Vec<egui::Pos2> = edges::sample(before, PREVIEW_SEGMENTS).into_iter().map(to_screen).collect();
+        painter.add(egui::Shape::line(curve, faint));
+        // The partner follows the point across the axis of a U edge or about the centre of an S edge.
+        let j = edge.len() - 1 - i;
+        if edges::partner(shape, edge[i]).is_some() && i != j && j < before.len() {
+            painter.extend(egui::Shape::dashed_line(&[to_screen(edge[i]), to_screen(edge[j])], faint, 4., 3.));
+            painter.line_segment([to_screen(before[j]), to_screen(edge[j])], faint);
+            painter.circle_stroke(to_screen(edge[j]), 7., egui::Stroke::new(1.5, visuals.selection.bg_fill));
+        }
+    }
+    let curve: Vec<egui::Pos2> = edges::sample(edge, PREVIEW_SEGMENTS).into_iter().map(to_screen).collect();
+    painter.add(egui::Shape::line(curve, egui::Stroke::new(2., visuals.strong_text_color())));
+    for (i, &p) in edge.iter().enumerate() {
+        let fixed = i == 0 || i + 1 == edge.len();
+        let colour = if fixed { visuals.weak_text_color() } else { visuals.selection.bg_fill };
+        painter.circle_filled(to_screen(p), 4., colour);
+        if *selection == Some(Selection::EdgePoint { edge: editor.selected, point: i }) {
+            painter.circle_stroke(to_screen(p), 7., egui::Stroke::new(1.5, visuals.strong_text_color()));
+        }
+    }
+
+    ui.add_enabled_ui(shape != EdgeShape::I, |ui| {
+        ui.horizontal(|ui| {
+            if ui.button("Flip").on_hover_text("Reflect the edge across its chord").clicked() {
+                edges::flip(edge);
+            }
+            if ui.button("Rotate 180°").on_hover_text("Turn the edge about the middle of its chord").clicked() {
+                edges::rotate_half_turn(edge);
+            }
+            if ui.button("Scale").on_hover_text("Scale the distance from the chord").clicked() {
+                edges::scale_toward_chord(edge, editor.scale_factor);
+            }
+            ui.add(egui::DragValue::new(&mut editor.scale_factor).range(0.0..=4.).speed(0.01).prefix("x "));
+        });
+        ui.horizontal(|ui| {
+            if ui.button("Smooth").on_hover_text("Cut the corners of the control polygon").clicked() {
+                *edge = edges::smooth(edge, editor.smooth_iterations);
+            }
+            ui.add(egui::DragValue::new(&mut editor.smooth_iterations).range(1..=4).suffix(" times"));
+            ui.label(format!("{} points", edge.len()));
+        });
+        ui.horizontal(|ui| {
+            if ui.button("Simplify").on_hover_text("Drop points closer than the tolerance to the simplified edge").clicked() {
+                *edge = edges::simplify(edge, shape, editor.simplify_tolerance);
+            }
+            ui.add(egui::Slider::new(&mut editor.simplify_tolerance, 0.0005..=0.05).logarithmic(true).text("tolerance"));
+        });
+        ui.horizontal(|ui| {
+            if ui.button("Noise").on_hover_text("Replace the edge with a wobbly, hand-drawn looking one").clicked() {
+                *edge = edges::noise_edge(&editor.noise, shape, NOISE_POINTS);
+            }
+            let noise = &mut editor.noise;
+            ui.add(egui::DragValue::new(&mut noise.amplitude).range(0.0..=0.5).speed(0.005).prefix("amplitude "));
+            ui.add(egui::DragValue::new(&mut noise.frequency).range(0.5..=12.).speed(0.05).prefix("frequency "));
+            ui.add(egui::DragValue::new(&mut noise.octaves).range(1..=6).suffix(" octaves"));
+            ui.add(egui::DragValue::new(&mut noise.seed).prefix("seed "));
+            if ui.button("🎲").on_hover_text("New seed").clicked() {
+                *seed = app::next_seed(*seed);
+                noise.seed = app::seeded(*seed, Roll::Edges).next_u64();
+                *edge = edges::noise_edge(noise, shape, NOISE_POINTS);
+            }
+        });
+    });
+    #[cfg(feature = "glyphs")]
+    if shape != EdgeShape::I {
+        glyph_row(editor, edge, shape, ui);
+    }
+    ui.horizontal(|ui| {
+        ui.label("copy to");
+        let from = editor.selected;
+        for to in (0..count).filter(|&to| to != from) {
+            if ui.button(format!("{to} ({:?})", tiling.edge_shape(to))).clicked() {
+                let _ = tiling.copy_edge_shape(edges_shapes, from, to);
+            }
+        }
+        if count > 1 && ui.button("all").clicked() {
+            for to in (0..count).filter(|&to| to != from) {
+                let _ = tiling.copy_edge_shape(edges_shapes, from, to);
+            }
+        }
+    });
+    ui.label(match shape {
+        EdgeShape::I => "I edges must stay straight.",
+        _ => "Drag points to move them, double-click to add one, right-click to remove one.",
+    });
+    ui.button("Save edge").on_hover_text("Open the file again to apply it to the selected edge").clicked()
+}
+
+/// Controls for replacing the edge with a glyph from a font file.
+#[cfg(feature = "glyphs")]
+fn glyph_row(editor: &mut EdgeEditor, edge: &mut Vec<Vec2>, shape: EdgeShape, ui: &mut egui::Ui) {
+    ui.horizontal(|ui| {
+        ui.add(egui::TextEdit::singleline(&mut editor.font_path).hint_text("path to a .ttf or .otf font").desired_width(160.));
+        ui.add(egui::TextEdit::singleline(&mut editor.glyph).char_limit(1).desired_width(20.));
+        ui.selectable_value(&mut editor.glyph_fit, GlyphFit::TopContour, "top");
+        ui.selectable_value(&mut editor.glyph_fit, GlyphFit::Outline, "outline");
+        if ui.button("Use glyph").clicked() {
+            let result = std::fs::read(&editor.font_path).map_err(|err| err.to_string()).and_then(|font| {
+                let c = editor.glyph.chars().next().unwrap_or(' ');
+                glyph::glyph_edge(&font, c, editor.glyph_fit, GLYPH_POINTS).map_err(|err| err.to_string())
+            });
+            match result {
+                Ok(glyph) => {
+                    *edge = edges::project(&glyph, shape);
+                    editor.glyph_status.clear();
+                }
+                Err(err) => editor.glyph_status = err,
+            }
+        }
+    });
+    if !editor.glyph_status.is_empty() {
+        ui.label(&editor.glyph_status);
+    }
+}
+
+/// The interior control point nearest to `p`, if it is within `tolerance`.
+fn nearest_point(edge: &[Vec2], p: Vec2, tolerance: f32) -> Option<usize> {
+    (1..edge.len().saturating_sub(1))
+        .map(|i| (i, (edge[i] - p).length()))
+        .filter(|&(_, d)| d < tolerance)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, _)| i)
+}
+
+/// Move control point `point` of edge shape `edge` by `delta`, keeping the edge's symmetry. The
+/// ends of an edge stay where they are.
+pub fn nudge_point(app: &mut App, edge: usize, point: usize, delta: Vec2) {
+    if edge < app.tiling.num_edge_shapes()
+        && let Some(points) = app.edges_shapes.get_mut(edge)
+        && point > 0
+        && point + 1 < points.len()
+    {
+        let p = points[point] + delta;
+        move_point(points, app.tiling.edge_shape(edge), point, p);
+    } else {
+        app.selection = None;
+    }
+}
+
+/// Move point `i` to `p`, and its partner along with it so the edge keeps its symmetry. A point
+/// that is its own partner is kept on the symmetry axis or centre. An `i` past the end of the
+/// edge is ignored.
+fn move_point(edge: &mut [Vec2], shape: EdgeShape, i: usize, p: Vec2) {
+    let Some(j) = edge.len().checked_sub(i + 1) else {
+        return;
+    };
+    match edges::partner(shape, p) {
+        Some(q) if i == j => edge[i] = (p + q) * 0.5,
+        Some(q) => {
+            edge[i] = p;
+            edge[j] = q;
+        }
+        None => edge[i] = p,
+    }
+}
+
+/// Insert `p` into the segment nearest to it, together with its partner if the shape has one.
+fn insert_point(edge: &mut Vec<Vec2>, shape: EdgeShape, p: Vec2) {
+    let n = edge.len();
+    let s = nearest_segment(edge, p);
+    match edges::partner(shape, p) {
+        None => edge.insert(s + 1, p),
+        Some(q) => {
+            // Insert into the first half first, so the pair ends up at mirrored indices.
+            let (s, p, q) = if s > (n - 2) / 2 { (n - 2 - s, q, p) } else { (s, p, q) };
+            edge.insert(s + 1, p);
+            edge.insert(n - s, q);
+        }
+    }
+}
+
+/// Remove point `i`, together with its partner if the shape has one.
+fn remove_point(edge: &mut Vec<Vec2>, shape: EdgeShape, i: usize) {
+    let j = edge.len() - 1 - i;
+    if edges::partner(shape, edge[i]).is_some() && i != j {
+        edge.remove(i.max(j));
+        edge.remove(i.min(j));
+    } else {
+        edge.remove(i);
+    }
+}
+
+/// The index of the segment of `edge` nearest to `p`.
+fn nearest_segment(edge: &[Vec2], p: Vec2) -> usize {
+    let distance = |a: Vec2, b: Vec2| {
+        let d = b - a;
+        let t = ((p - a).dot(d) / d.dot(d).max(f32::EPSILON)).clamp(0., 1.);
+        (a + d * t - p).length()
+    };
+    (0..edge.len() - 1)
+        .min_by(|&i, &j| distance(edge[i], edge[i + 1]).total_cmp(&distance(edge[j], edge[j + 1])))
+        .unwrap_or(0)
+}
+
+/// Label every edge of the tile nearest the middle of the view with its edge shape class and
+/// shape, and pick out the edges of the class being edited, which all share its geometry, with
+/// their geometry from before the drag running under the onion skin.
+pub fn draw_edge_labels(app: &App, painter: &egui::Painter) {
+    let Some((transform, centre)) = interface::central_tile(app, painter.clip_rect(), |_| true) else {
+        return;
+    };
+    let sampled = app.outline_cache.sampled();
+    let visuals = painter.ctx().style().visuals.clone();
+    let highlight = egui::Stroke::new(6., visuals.selection.bg_fill);
+    let onion = egui::Stroke::new(2., visuals.strong_text_color().gamma_multiply(0.4));
+    let font = egui::FontId::monospace(12.);
+    for shape in app.tiling.shapes() {
+        let t = transform * shape.transform();
+        if shape.id() == app.edge_editor.selected && let Some(edge) = sampled.get(shape.id()) {
+            painter.line(edge.iter().map(|&p| app.view.to_screen(t.transform_point2(p))).collect(), highlight);
+        }
+        if shape.id() == app.edge_editor.selected && let Some(before) = app.edge_editor.onion() {
+            let before = if shape.shape() == EdgeShape::I { before.to_vec() } else { edges::sample(before, PREVIEW_SEGMENTS) };
+            painter.line(before.iter().map(|&p| app.view.to_screen(t.transform_point2(p))).collect(), onion);
+        }
+        // Pulled toward the centre, so the labels of the tiles on either side don't cover each other.
+        let middle = app.view.to_screen(t.transform_point2(vec2(0.5, 0.)));
+        let pos = middle + (centre - middle) * 0.25;
+        let galley = painter.layout_no_wrap(format!("{} {:?}", shape.id(), shape.shape()), font.clone(), visuals.strong_text_color());
+        let rect = egui::Align2::CENTER_CENTER.anchor_size(pos, galley.size());
+        painter.rect_filled(rect.expand(2.), 3., visuals.extreme_bg_color);
+        painter.galley(rect.min, galley, visuals.strong_text_color());
+    }
+}
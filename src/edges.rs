@@ -0,0 +1,293 @@
+//! Geometry helpers for edge shapes.
+//!
+//! An edge shape is a sequence of control points running from `(0, 0)` to `(1, 0)` in the edge's
+//! own frame. The prototile outline is built from these with [`IsohedralTiling::outline`].
+//!
+//! [`IsohedralTiling::outline`]: crate::tiling::IsohedralTiling::outline
+use crate::tiling::EdgeShape;
+use crate::utils::{vec2, Vec2};
+
+
+/// Default number of segments per edge when drawing on screen.
+pub const SCREEN_SEGMENTS: usize = 16;
+
+/// Default number of segments per edge for exports.
+pub const EXPORT_SEGMENTS: usize = 64;
+
+/// Parameters of the fractal noise that [`noise_edge`] displaces an edge with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Noise {
+    /// Largest distance from the chord, relative to its length.
+    pub amplitude: f32,
+    /// Number of bumps along the edge in the coarsest octave.
+    pub frequency: f32,
+    /// Number of octaves, each with twice the frequency and half the amplitude of the one before.
+    pub octaves: usize,
+    /// Seed of the noise; the same parameters always give the same edge.
+    pub seed: u64,
+}
+
+impl Default for Noise {
+    fn default() -> Self {
+        Self { amplitude: 0.15, frequency: 3., octaves: 3, seed: 0 }
+    }
+}
+
+/// Sample a smooth curve through the control points of an edge.
+///
+/// The curve is a uniform Catmull-Rom spline passing through every control point, with roughly
+/// `segments` segments in total (at least one per span). Edges without interior points are
+/// returned unchanged. The spline commutes with affine maps and reversal, so edges that respect
+/// their U/S/I symmetry remain symmetric after sampling.
+pub fn sample(edge: &[Vec2], segments: usize) -> Vec<Vec2> {
+    if edge.len() < 3 {
+        return edge.to_vec();
+    }
+
+    let spans = edge.len() - 1;
+    let steps = segments.div_ceil(spans).max(1);
+    let mut points = Vec::with_capacity(spans * steps + 1);
+
+    for i in 0..spans {
+        let p0 = edge[i.saturating_sub(1)];
+        let p1 = edge[i];
+        let p2 = edge[i + 1];
+        let p3 = edge[(i + 2).min(spans)];
+
+        for s in 0..steps {
+            points.push(catmull_rom(p0, p1, p2, p3, s as f32 / steps as f32));
+        }
+    }
+    points.push(edge[spans]);
+
+    points
+}
+
+/// Resample a polyline into `count` points evenly spaced along its length, keeping both ends.
+pub fn resample(path: &[Vec2], count: usize) -> Vec<Vec2> {
+    if path.len() < 2 || count < 2 {
+        return path.to_vec();
+    }
+    let mut lengths = vec![0.];
+    for w in path.windows(2) {
+        lengths.push(lengths[lengths.len() - 1] + (w[1] - w[0]).length());
+    }
+    let total = lengths[lengths.len() - 1];
+
+    let mut points = Vec::with_capacity(count);
+    let mut segment = 0;
+    for k in 0..count {
+        let target = total * k as f32 / (count - 1) as f32;
+        while segment + 2 < lengths.len() && lengths[segment + 1] < target {
+            segment += 1;
+        }
+        let span = lengths[segment + 1] - lengths[segment];
+        let t = if span > 0. { ((target - lengths[segment]) / span).clamp(0., 1.) } else { 0. };
+        points.push(path[segment] + (path[segment + 1] - path[segment]) * t);
+    }
+    points[count - 1] = path[path.len() - 1];
+    points
+}
+
+/// Smooth a rough polyline by Chaikin's corner cutting, repeated `iterations` times.
+///
+/// Every iteration replaces each segment by the points a quarter and three quarters along it,
+/// roughly doubling the number of points; the endpoints are kept. Like [`sample`], this commutes
+/// with affine maps and reversal, so symmetric edges stay symmetric.
+pub fn smooth(edge: &[Vec2], iterations: usize) -> Vec<Vec2> {
+    let mut points = edge.to_vec();
+    for _ in 0..iterations {
+        if points.len() < 3 {
+            break;
+        }
+        let mut next = Vec::with_capacity(2 * points.len());
+        next.push(points[0]);
+        for w in points.windows(2) {
+            let d = w[1] - w[0];
+            next.extend([w[0] + d * 0.25, w[0] + d * 0.75]);
+        }
+        next.push(points[points.len() - 1]);
+        points = next;
+    }
+    points
+}
+
+/// A wobbly edge of `count` points evenly spaced along the chord, displaced from it by fractal
+/// value noise. The displacement fades out towards both ends, so the edge meets its neighbours
+/// smoothly.
+///
+/// The edge respects the constraint of `shape`: U edges mirror a random half across the middle
+/// and S edges turn it a half turn about the midpoint, both without a corner at the middle. I
+/// edges stay straight.
+pub fn noise_edge(noise: &Noise, shape: EdgeShape, count: usize) -> Vec<Vec2> {
+    use std::f32::consts::PI;
+
+    if shape == EdgeShape::I {
+        return vec![Vec2::ZERO, Vec2::X];
+    }
+    let count = count.max(2);
+    (0..count)
+        .map(|k| {
+            let x = k as f32 / (count - 1) as f32;
+            // Position along the random half, from its end at a vertex to the middle of the edge.
+            let t = 2. * x.min(1. - x);
+            let y = match shape {
+                EdgeShape::J => (PI * x).sin() * fbm(noise, noise.frequency * x),
+                // Flat at the middle, so the mirrored halves join smoothly.
+                EdgeShape::U => {
+                    let s = (PI * t / 2.).sin();
+                    s * fbm(noise, noise.frequency * s / 2.)
+                }
+                EdgeShape::S => (PI * t).sin() * fbm(noise, noise.frequency * t / 2.) * if x <= 0.5 { 1. } else { -1. },
+                EdgeShape::I => 0.,
+            };
+            vec2(x, noise.amplitude * y)
+        })
+        .collect()
+}
+
+/// Fractal sum of the octaves of [`value_noise`] at `x`, scaled into `[-1, 1]`.
+fn fbm(noise: &Noise, x: f32) -> f32 {
+    let weights = (0..noise.octaves).map(|o| (o, 0.5_f32.powi(o as i32)));
+    let total: f32 = weights.clone().map(|(_, w)| w).sum();
+    let sum: f32 = weights.map(|(o, w)| w * value_noise(noise.seed, o as u64, x * 2_f32.powi(o as i32))).sum();
+    sum / total.max(1.)
+}
+
+/// Smoothly interpolated pseudo-random values in `[-1, 1]` at the integers, a different
+/// sequence for every `seed` and `octave`.
+fn value_noise(seed: u64, octave: u64, x: f32) -> f32 {
+    let i = x.floor();
+    let t = x - i;
+    let value = |i: i64| {
+        let mut h = seed ^ octave.wrapping_mul(0x9e37_79b9_7f4a_7c15) ^ (i as u64).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        // The finaliser of SplitMix64.
+        h = (h ^ (h >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        h = (h ^ (h >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        h ^= h >> 31;
+        (h >> 40) as f32 / (1u64 << 23) as f32 - 1.
+    };
+    let s = t * t * (3. - 2. * t);
+    value(i as i64) * (1. - s) + value(i as i64 + 1) * s
+}
+
+/// Simplify an edge with the Ramer–Douglas–Peucker algorithm, dropping points that are closer
+/// than `tolerance` to the simplified polyline. The endpoints are kept.
+///
+/// U and S edges are simplified on their first half, and the second half is rebuilt from the
+/// partners, so they stay symmetric. I edges become straight.
+pub fn simplify(edge: &[Vec2], shape: EdgeShape, tolerance: f32) -> Vec<Vec2> {
+    let n = edge.len();
+    if shape == EdgeShape::I {
+        return vec![Vec2::ZERO, Vec2::X];
+    }
+    if n < 3 || shape == EdgeShape::J {
+        return ramer_douglas_peucker(edge, tolerance);
+    }
+
+    // The first half includes the middle point of an odd edge, which is its own partner.
+    let half = ramer_douglas_peucker(&edge[..n.div_ceil(2)], tolerance);
+    let skip = n % 2;
+    let mirrored: Vec<Vec2> = half.iter().rev().skip(skip).filter_map(|&p| partner(shape, p)).collect();
+    half.into_iter().chain(mirrored).collect()
+}
+
+fn ramer_douglas_peucker(points: &[Vec2], tolerance: f32) -> Vec<Vec2> {
+    let n = points.len();
+    if n < 3 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; n];
+    keep[0] = true;
+    keep[n - 1] = true;
+
+    let mut ranges = vec![(0, n - 1)];
+    while let Some((first, last)) = ranges.pop() {
+        let (a, b) = (points[first], points[last]);
+        let d = b - a;
+        let distance = |p: Vec2| {
+            let len = d.length();
+            if len > 0. { d.perp_dot(p - a).abs() / len } else { (p - a).length() }
+        };
+        let farthest = (first + 1..last).map(|i| (i, distance(points[i]))).max_by(|x, y| x.1.total_cmp(&y.1));
+        if let Some((i, _)) = farthest.filter(|&(_, dist)| dist > tolerance) {
+            keep[i] = true;
+            ranges.extend([(first, i), (i, last)]);
+        }
+    }
+
+    points.iter().zip(keep).filter(|(_, k)| *k).map(|(&p, _)| p).collect()
+}
+
+/// The point that must accompany `p` on an edge of the given shape: its mirror image across the
+/// perpendicular bisector of the chord for U edges, or its half turn about the chord's midpoint
+/// for S edges.
+///
+/// J edges have no constraint and I edges must stay straight, so neither has partners.
+pub fn partner(shape: EdgeShape, p: Vec2) -> Option<Vec2> {
+    match shape {
+        EdgeShape::U => Some(vec2(1. - p.x, p.y)),
+        EdgeShape::S => Some(vec2(1. - p.x, -p.y)),
+        EdgeShape::J | EdgeShape::I => None,
+    }
+}
+
+/// The nearest edge satisfying the constraint of `shape`.
+///
+/// U and S edges average every point with the partner of its mirrored counterpart, which leaves
+/// edges that are already symmetric unchanged. I edges become straight.
+pub fn project(edge: &[Vec2], shape: EdgeShape) -> Vec<Vec2> {
+    let n = edge.len();
+    if shape == EdgeShape::I || n < 2 {
+        return vec![Vec2::ZERO, Vec2::X];
+    }
+    let mut projected: Vec<Vec2> = (0..n)
+        .map(|i| match partner(shape, edge[n - 1 - i]) {
+            Some(q) => (edge[i] + q) * 0.5,
+            None => edge[i],
+        })
+        .collect();
+    projected[0] = Vec2::ZERO;
+    projected[n - 1] = Vec2::X;
+    projected
+}
+
+/// Reflect an edge across its chord.
+pub fn flip(edge: &mut [Vec2]) {
+    for p in edge {
+        p.y = -p.y;
+    }
+}
+
+/// Rotate an edge a half turn about the midpoint of its chord. The points are reversed, so the
+/// edge still runs from `(0, 0)` to `(1, 0)`.
+pub fn rotate_half_turn(edge: &mut [Vec2]) {
+    for p in edge.iter_mut() {
+        *p = vec2(1. - p.x, -p.y);
+    }
+    edge.reverse();
+}
+
+/// Scale the distance of every point from the chord by `factor`: below one flattens the edge,
+/// above one exaggerates it.
+///
+/// Like [`flip`] and [`rotate_half_turn`], this keeps U and S edges symmetric.
+pub fn scale_toward_chord(edge: &mut [Vec2], factor: f32) {
+    for p in edge {
+        p.y *= factor;
+    }
+}
+
+fn catmull_rom(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, t: f32) -> Vec2 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let w0 = -0.5 * t3 + t2 - 0.5 * t;
+    let w1 = 1.5 * t3 - 2.5 * t2 + 1.;
+    let w2 = -1.5 * t3 + 2. * t2 + 0.5 * t;
+    let w3 = 0.5 * t3 - 0.5 * t2;
+
+    vec2(
+        w0 * p0.x + w1 * p1.x + w2 * p2.x + w3 * p3.x,
+        w0 * p0.y + w1 * p1.y + w2 * p2.y + w3 * p3.y,
+    )
+}
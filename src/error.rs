@@ -0,0 +1,70 @@
+//! The error type of the library.
+use thiserror::Error;
+
+
+/// Errors reported by the fallible variants of the [`IsohedralTiling`] API.
+///
+/// [`IsohedralTiling`]: crate::tiling::IsohedralTiling
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum TilingError {
+    /// The tiling type index is not one of the 81 valid tiling types.
+    #[error("there are only 81 valid isohedral tiling types, but index {0} was requested")]
+    InvalidTilingType(usize),
+    /// The vertex index is out of range for the current prototile.
+    #[error("vertex {index} out of range, the prototile has {count} vertices")]
+    VertexOutOfRange { index: usize, count: usize },
+    /// The edge shape index is out of range for the current prototile.
+    #[error("edge shape {index} out of range, the prototile has {count} edge shapes")]
+    EdgeShapeOutOfRange { index: usize, count: usize },
+    /// The builder was asked to build a tiling without a tiling type.
+    #[error("no tiling type was given")]
+    MissingTilingType,
+    /// The number of parameters doesn't match the tiling type.
+    #[error("the tiling type takes {expected} parameters, but {got} were given")]
+    WrongParameterCount { expected: usize, got: usize },
+    /// The number of edges doesn't match the number of edge shapes of the tiling type.
+    #[error("the tiling type has {expected} edge shapes, but {got} edges were given")]
+    WrongEdgeCount { expected: usize, got: usize },
+    /// A point of the edge with this index is NaN or infinite.
+    #[error("edge {0} has a point that is not finite")]
+    NonFiniteEdgePoint(usize),
+    /// A parameter is NaN or infinite.
+    #[error("parameter {0} is not a finite number")]
+    NonFiniteParameter(usize),
+    /// An image can't be created with the requested size.
+    #[error("can't create an image of {width}x{height} pixels")]
+    InvalidImageSize { width: u32, height: u32 },
+    /// Encoding a rendered image as PNG failed.
+    #[error("failed to encode the image as PNG")]
+    PngEncoding,
+    /// The aspect index is out of range for the current tiling type.
+    #[error("aspect {index} out of range, the tiling type has {count} aspects")]
+    AspectOutOfRange { index: usize, count: usize },
+    /// The font data couldn't be parsed.
+    #[error("the font couldn't be read")]
+    FontParsing,
+    /// The font has no outline for the character.
+    #[error("the font has no outline for {0:?}")]
+    MissingGlyph(char),
+    /// Two tilings can only be morphed into each other if their prototiles have the same number
+    /// of vertices.
+    #[error("can't morph a prototile with {from} vertices into one with {to}")]
+    VertexCountMismatch { from: usize, to: usize },
+    /// The tiling type has no perfect colouring with this many colours, or it wasn't searched
+    /// for, see [`perfect_colourings`].
+    ///
+    /// [`perfect_colourings`]: crate::colouring::perfect_colourings
+    #[error("no perfect colouring with {0} colours was found")]
+    NoPerfectColouring(usize),
+    /// SVG path or point data couldn't be read, at the given byte offset.
+    #[error("invalid SVG shape data at byte {0}")]
+    SvgPathData(usize),
+    /// An SVG document has no shapes to make a [`Mask`] from.
+    ///
+    /// [`Mask`]: crate::mask::Mask
+    #[error("the SVG has no closed shapes")]
+    NoSvgShape,
+    /// The text to make a mask from has no visible glyphs.
+    #[error("the text has no visible glyphs")]
+    EmptyText,
+}
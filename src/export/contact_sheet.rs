@@ -0,0 +1,146 @@
+//! A reference sheet with a small sample of every tiling type, labelled with its name.
+//!
+//! The layout is shared by the SVG written by [`svg`] and the raster image rendered by
+//! `render::contact_sheet`. Every sample uses the tiling type's default parameters and straight
+//! edges.
+use std::fmt::Write;
+
+use crate::data::get_tiling_type;
+use crate::export::{hex, ExportOptions};
+use crate::tiling::IsohedralTiling;
+use crate::utils::{vec2, Vec2};
+use crate::TILING_TYPES;
+
+
+/// Layout and style of a contact sheet.
+#[derive(Debug, Clone)]
+pub struct ContactSheet {
+    /// Number of samples per row.
+    pub columns: usize,
+    /// Width and height of each sample, in pixels.
+    pub cell_size: u32,
+    /// Height of the label under each sample, in pixels.
+    pub label_height: u32,
+    /// Space between and around the samples, in pixels.
+    pub gap: u32,
+    /// Roughly how many translational units fit across a sample.
+    pub repeats: f32,
+    /// Colours, outlines and edge sampling of the samples. The size, origin and scale are set
+    /// for each sample, and the background fills the whole sheet.
+    pub style: ExportOptions,
+}
+
+impl Default for ContactSheet {
+    fn default() -> Self {
+        Self { columns: 9, cell_size: 160, label_height: 20, gap: 8, repeats: 2.5, style: ExportOptions::default() }
+    }
+}
+
+/// One sample of a [`ContactSheet`].
+#[derive(Debug)]
+pub struct Cell {
+    pub tiling: IsohedralTiling,
+    /// Straight edges, one per edge shape of the tiling.
+    pub edges: Vec<Vec<Vec2>>,
+    /// The tiling type's name, such as `IH01`.
+    pub label: String,
+    /// Position of the sample's top left corner on the sheet, in pixels.
+    pub x: u32,
+    pub y: u32,
+    /// The sample's own region of the plane, centred on the origin.
+    pub options: ExportOptions,
+}
+
+impl ContactSheet {
+    fn rows(&self) -> usize {
+        TILING_TYPES.len().div_ceil(self.columns.max(1))
+    }
+
+    /// Width of the sheet in pixels.
+    pub fn width(&self) -> u32 {
+        self.gap + self.columns.max(1) as u32 * (self.cell_size + self.gap)
+    }
+
+    /// Height of the sheet in pixels.
+    pub fn height(&self) -> u32 {
+        self.gap + self.rows() as u32 * (self.cell_size + self.label_height + self.gap)
+    }
+
+    /// The samples of all tiling types, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = Cell> + '_ {
+        (0..TILING_TYPES.len()).map(move |i| {
+            let tiling = IsohedralTiling::new(get_tiling_type(i));
+            let (column, row) = ((i % self.columns.max(1)) as u32, (i / self.columns.max(1)) as u32);
+            let size = self.cell_size as f32;
+            // Scale the translational unit to cover `repeats` squared of the sample's area.
+            let unit = tiling.t1().perp_dot(*tiling.t2()).abs().sqrt().max(f32::EPSILON);
+            let scale = size / (self.repeats * unit);
+            let options = ExportOptions {
+                width: self.cell_size,
+                height: self.cell_size,
+                origin: vec2(-size / 2. / scale, -size / 2. / scale),
+                scale,
+                ..self.style.clone()
+            };
+            Cell {
+                edges: vec![vec![Vec2::ZERO, Vec2::X]; tiling.num_edge_shapes()],
+                label: tiling.tiling_type().to_string(),
+                tiling,
+                x: self.gap + column * (self.cell_size + self.gap),
+                y: self.gap + row * (self.cell_size + self.label_height + self.gap),
+                options,
+            }
+        })
+    }
+}
+
+/// Write the contact sheet as an SVG document, each sample clipped to its square with its label
+/// underneath.
+pub fn svg(sheet: &ContactSheet) -> String {
+    let style = &sheet.style;
+    let mut out = String::new();
+
+    // Writing into a `String` can't fail.
+    let (w, h) = (sheet.width(), sheet.height());
+    let _ = writeln!(out, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#);
+    let _ = writeln!(out, "  <!-- Contact sheet of the isohedral tiling types, exported from egui Isohedral. -->");
+    let _ = writeln!(out, r#"  <rect width="100%" height="100%" fill="{}"/>"#, hex(style.background));
+    let stroke = if style.stroke_width > 0. {
+        format!(r#" stroke="{}" stroke-width="{}" stroke-linejoin="round""#, hex(style.stroke_colour), style.stroke_width)
+    } else {
+        String::new()
+    };
+
+    for cell in sheet.cells() {
+        let options = &cell.options;
+        let s = sheet.cell_size;
+        let _ = writeln!(out, r#"  <g transform="translate({} {})">"#, cell.x, cell.y);
+        let _ = writeln!(out, r#"    <clipPath id="{0}"><rect width="{s}" height="{s}"/></clipPath>"#, cell.label);
+        let _ = writeln!(out, r#"    <g clip-path="url(#{})">"#, cell.label);
+        let outline = cell.tiling.outline(&cell.tiling.sample_edges(&cell.edges, options.segments));
+        for tile in options.fill(&cell.tiling) {
+            let transform = tile.transform();
+            let colour = hex(options.colour(tile.colour(&cell.tiling)));
+            let _ = write!(out, r#"      <path fill="{colour}"{stroke} d=""#);
+            for (k, p) in outline.iter().enumerate() {
+                let p = (transform.transform_point2(*p) - options.origin) * options.scale;
+                let _ = write!(out, "{}{:.2} {:.2}", if k == 0 { "M" } else { " L" }, p.x, p.y);
+            }
+            let _ = writeln!(out, r#" Z"/>"#);
+        }
+        let _ = writeln!(out, "    </g>");
+        let _ = writeln!(
+            out,
+            r#"    <text x="{}" y="{}" font-family="sans-serif" font-size="{}" text-anchor="middle" fill="{}">{}</text>"#,
+            s / 2,
+            s + sheet.label_height * 4 / 5,
+            sheet.label_height * 3 / 4,
+            hex(style.stroke_colour),
+            cell.label,
+        );
+        let _ = writeln!(out, "  </g>");
+    }
+    let _ = writeln!(out, "</svg>");
+
+    out
+}
@@ -0,0 +1,43 @@
+//! Export the outlines of the tiles as CSV, for spreadsheets, statistics or CAD scripts.
+use std::fmt::Write;
+
+use crate::export::ExportOptions;
+use crate::tiling::IsohedralTiling;
+use crate::utils::Vec2;
+
+
+/// Header line of the table written by [`vertices`].
+pub const HEADER: &str = "tile,t1,t2,aspect,colour,vertex,x,y";
+
+/// Write a table of the outline points of every tile reaching into the exported region, in world
+/// coordinates, one row per point, see [`HEADER`].
+///
+/// Rows are grouped by tile: `tile` numbers the tiles from zero, `t1`, `t2` and `aspect` say which
+/// tile it is, see [`IsohedralTiling::tile`], and `colour` gives its colour class. `vertex`
+/// numbers the points of each outline in order, starting from the prototile's first corner, and
+/// includes the points sampled along curved edges. With [`ExportOptions::clip`], the outlines
+/// are cut along the border of the region and `vertex` numbers the points of the cut outline.
+pub fn vertices(tiling: &IsohedralTiling, edges: &[Vec<Vec2>], options: &ExportOptions) -> String {
+    let outline = tiling.outline(&tiling.sample_edges(edges, options.segments));
+    let (min, max) = (options.origin, options.world_max());
+    let mut out = String::from(HEADER);
+    out.push('\n');
+
+    // Writing into a `String` can't fail.
+    let mut count = 0;
+    for tile in options.fill(tiling) {
+        let mut points = outline.clone();
+        tile.transform().transform_points(&mut points);
+        let outside = points.iter().all(|p| p.x < min.x) || points.iter().all(|p| p.x > max.x) || points.iter().all(|p| p.y < min.y) || points.iter().all(|p| p.y > max.y);
+        let points = if outside { vec![] } else { options.clipped(&points) };
+        if points.is_empty() {
+            continue;
+        }
+        for (vertex, p) in points.iter().enumerate() {
+            let _ = writeln!(out, "{count},{},{},{},{},{vertex},{},{}", tile.t1(), tile.t2(), tile.aspect(), tile.colour(tiling), p.x, p.y);
+        }
+        count += 1;
+    }
+
+    out
+}
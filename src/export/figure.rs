@@ -0,0 +1,138 @@
+//! Export a figure for papers: a whole number of periods of the tiling as SVG, cropped to exactly
+//! its tiles, with an optional caption.
+use std::fmt::Write;
+
+use crate::export::{hex, ExportOptions, Rgba};
+use crate::tiling::IsohedralTiling;
+use crate::utils::{vec2, Vec2};
+
+
+/// What a figure shows, see [`figure`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Figure {
+    /// Number of periods of the colouring drawn along `t1` and `t2`, see
+    /// [`IsohedralTiling::colour_periods`].
+    pub periods: (usize, usize),
+    /// Caption the figure with the tiling type.
+    pub label: bool,
+    /// List the tiling's parameters under the figure.
+    pub parameters: bool,
+    /// Fill the tiles with the greys of [`monochrome`] and outline them in black, so the figure
+    /// survives printing in black and white.
+    pub monochrome: bool,
+}
+
+impl Default for Figure {
+    fn default() -> Self {
+        Self { periods: (2, 2), label: true, parameters: true, monochrome: true }
+    }
+}
+
+/// `count` greys, evenly spaced in lightness from near white, told apart in black and white.
+pub fn monochrome(count: usize) -> Vec<Rgba> {
+    let step = if count > 1 { 130. / (count - 1) as f32 } else { 0. };
+    (0..count)
+        .map(|i| {
+            let grey = (245. - step * i as f32).round() as u8;
+            [grey, grey, grey, 255]
+        })
+        .collect()
+}
+
+/// Write an SVG document with the tiles of `figure.periods` periods of the tiling, at the scale
+/// and physical size of `options`; the region of `options` is not used.
+///
+/// Whole tiles are drawn, and the document is cropped to their bounding box, so nothing is cut
+/// and there is no margin to trim. The background is left transparent. The caption, if any, goes
+/// under the tiles in the stroke colour.
+pub fn figure(tiling: &IsohedralTiling, edges: &[Vec<Vec2>], options: &ExportOptions, figure: &Figure) -> String {
+    let sampled = tiling.sample_edges(edges, options.segments);
+    let outline = tiling.outline(&sampled);
+    let (p1, p2) = tiling.colour_periods();
+    let (n1, n2) = ((p1.max(1) * figure.periods.0.max(1)) as isize, (p2.max(1) * figure.periods.1.max(1)) as isize);
+    let tiles: Vec<_> = (0..n1)
+        .flat_map(|i| (0..n2).flat_map(move |j| (0..tiling.num_aspects()).map(move |aspect| tiling.tile(i, j, aspect))))
+        .collect();
+    let colours = if figure.monochrome { monochrome(tiling.num_colours()) } else { options.colours.clone() };
+    let stroke = if figure.monochrome { [0, 0, 0, 255] } else { options.stroke_colour };
+
+    let outlines: Vec<Vec<Vec2>> = tiles.iter().map(|tile| outline.iter().map(|&p| tile.transform().transform_point2(p)).collect()).collect();
+    let (lo, hi) = bounds(outlines.iter().flatten());
+    // The strokes reach half their width past the outlines.
+    let s = options.scale;
+    let half = options.stroke_width.max(0.) / 2.;
+    let to_page = |p: Vec2| (p - lo) * s + vec2(half, half);
+    let w = (hi.x - lo.x) * s + 2. * half;
+
+    let mut caption = vec![];
+    if figure.label {
+        caption.push((tiling.tiling_type().to_string(), String::new()));
+    }
+    if figure.parameters {
+        let mut params = [0.; 6];
+        tiling.parameters(&mut params);
+        for (i, v) in params[..tiling.num_params()].iter().enumerate() {
+            caption.push((format!("v{i}"), format!("{v:.4}")));
+        }
+    }
+    let font = (w / 40.).max(8.);
+    let tiles_height = (hi.y - lo.y) * s + 2. * half;
+    let h = tiles_height + if caption.is_empty() { 0. } else { (caption.len() as f32 + 0.5) * 1.4 * font };
+    let mut out = String::new();
+
+    // Writing into a `String` can't fail.
+    let size = match options.physical {
+        Some(physical) => format!(r#"width="{:.3}{u}" height="{:.3}{u}""#, physical.from_pixels(w), physical.from_pixels(h), u = physical.unit.suffix()),
+        None => format!(r#"width="{w:.0}" height="{h:.0}""#),
+    };
+    let _ = writeln!(out, r#"<svg xmlns="http://www.w3.org/2000/svg" {size} viewBox="0 0 {w:.3} {h:.3}">"#);
+    let _ = writeln!(out, "  <!-- {n1} x {n2} translational units of a {} isohedral tiling, exported from egui Isohedral. -->", tiling.tiling_type());
+    for (tile, points) in tiles.iter().zip(&outlines) {
+        let colour = colours.get(tile.colour(tiling) % colours.len().max(1)).copied().unwrap_or(options.background);
+        let _ = write!(out, r#"  <path fill="{}" d=""#, hex(colour));
+        for (k, &p) in points.iter().enumerate() {
+            let p = to_page(p);
+            let _ = write!(out, "{}{:.3} {:.3}", if k == 0 { "M" } else { " L" }, p.x, p.y);
+        }
+        let _ = writeln!(out, r#" Z"/>"#);
+    }
+
+    // The strokes go on top of all the fills, one per edge rather than one per tile outline.
+    if options.stroke_width > 0. {
+        let _ = write!(
+            out,
+            r#"  <path fill="none" stroke="{}" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round" d=""#,
+            hex(stroke),
+            options.stroke_width
+        );
+        for edge in tiling.unique_edges(&sampled, tiles.iter().map(|tile| tile.transform())) {
+            for (k, &p) in edge.iter().enumerate() {
+                let p = to_page(p);
+                let _ = write!(out, "{}{:.3} {:.3}", if k == 0 { " M" } else { " L" }, p.x, p.y);
+            }
+        }
+        let _ = writeln!(out, r#""/>"#);
+    }
+
+    // The caption: the type on its own line, then a row for each parameter with its value.
+    for (row, (name, value)) in caption.iter().enumerate() {
+        let y = tiles_height + (row as f32 + 1.) * 1.4 * font;
+        let text = |out: &mut String, x: f32, text: &str| {
+            let _ = writeln!(out, r#"  <text x="{x:.3}" y="{y:.3}" font-family="serif" font-size="{font:.2}" fill="{}">{text}</text>"#, hex(stroke));
+        };
+        text(&mut out, half, name);
+        if !value.is_empty() {
+            text(&mut out, half + 3. * font, value);
+        }
+    }
+    let _ = writeln!(out, "</svg>");
+
+    out
+}
+
+fn bounds<'a>(points: impl IntoIterator<Item = &'a Vec2>) -> (Vec2, Vec2) {
+    points.into_iter().fold(
+        (vec2(f32::INFINITY, f32::INFINITY), vec2(f32::NEG_INFINITY, f32::NEG_INFINITY)),
+        |(lo, hi), p| (vec2(lo.x.min(p.x), lo.y.min(p.y)), vec2(hi.x.max(p.x), hi.y.max(p.y))),
+    )
+}
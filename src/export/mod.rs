@@ -0,0 +1,237 @@
+//! Exporting a tiling to other formats.
+//!
+//! All exporters share the same [`ExportOptions`], describing the region of the plane to export
+//! and how to draw it.
+use crate::edges::EXPORT_SEGMENTS;
+use crate::hatch::Pattern;
+use crate::mask::Mask;
+use crate::iterators::FillAlgorithm;
+use crate::tiling::IsohedralTiling;
+use crate::utils::{vec2, Vec2};
+
+pub mod contact_sheet;
+pub mod csv;
+pub mod figure;
+pub mod p5;
+pub mod pdf;
+pub mod prototile;
+pub mod svg;
+pub mod tactile;
+pub mod tikz;
+pub mod wallpaper;
+
+
+/// An RGBA colour with straight (not premultiplied) alpha.
+pub type Rgba = [u8; 4];
+
+/// A unit of physical length.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    #[default]
+    Millimetre,
+    Inch,
+}
+
+impl Unit {
+    /// The unit's abbreviation, as understood by SVG and TeX.
+    pub fn suffix(self) -> &'static str {
+        match self {
+            Unit::Millimetre => "mm",
+            Unit::Inch => "in",
+        }
+    }
+
+    /// Number of this unit in an inch.
+    pub fn per_inch(self) -> f32 {
+        match self {
+            Unit::Millimetre => 25.4,
+            Unit::Inch => 1.,
+        }
+    }
+}
+
+/// The real-world size of an export, so a pattern comes out at the intended size on paper, a
+/// cutter or a screen of known resolution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhysicalScale {
+    /// Length of one world unit of the tiling, in `unit`.
+    pub unit_length: f32,
+    pub unit: Unit,
+    /// Resolution of raster exports, in dots per inch.
+    pub dpi: f32,
+}
+
+impl Default for PhysicalScale {
+    fn default() -> Self {
+        Self { unit_length: 10., unit: Unit::Millimetre, dpi: 300. }
+    }
+}
+
+impl PhysicalScale {
+    /// Number of pixels per world unit at this resolution.
+    pub fn pixels_per_unit(&self) -> f32 {
+        self.unit_length / self.unit.per_inch() * self.dpi
+    }
+
+    /// Convert a length in pixels to the physical unit.
+    pub fn from_pixels(&self, pixels: f32) -> f32 {
+        pixels / self.dpi * self.unit.per_inch()
+    }
+}
+
+/// What to export and how to draw it.
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    /// Width of the output in pixels.
+    pub width: u32,
+    /// Height of the output in pixels.
+    pub height: u32,
+    /// The world-space point shown at the top left corner of the output.
+    pub origin: Vec2,
+    /// Number of pixels per world unit.
+    pub scale: f32,
+    /// Fill colour of each colour class, see [`IsohedralTiling::colour`]. Colourings with more
+    /// colours than given reuse them from the start, see [`colour`].
+    ///
+    /// [`colour`]: ExportOptions::colour
+    pub colours: Vec<Rgba>,
+    /// Colour of the output where no tile is drawn.
+    pub background: Rgba,
+    /// Fill pattern of each colour class, indexed like [`colours`]. Classes without one are filled
+    /// flat. Marked patterns are drawn in the stroke colour on the background.
+    ///
+    /// [`colours`]: ExportOptions::colours
+    pub patterns: Vec<Pattern>,
+    /// The silhouette the tiling is cut to, in world space, if any. Only the PNG and SVG exports
+    /// apply it.
+    pub mask: Option<Mask>,
+    /// Colour of the tile outlines.
+    pub stroke_colour: Rgba,
+    /// Width of the tile outlines in pixels. Outlines are not drawn when this is zero.
+    pub stroke_width: f32,
+    /// Number of segments used to sample each edge, see [`IsohedralTiling::sample_edges`].
+    pub segments: usize,
+    /// The real-world size of the output, if any. Set it with [`with_physical`].
+    ///
+    /// [`with_physical`]: ExportOptions::with_physical
+    pub physical: Option<PhysicalScale>,
+    /// Cut the tiles crossing the border of the region along it, see [`clipped`], so the export
+    /// ends in straight borders rather than in whole tiles reaching out of it. Only the CSV and
+    /// PDF exports apply it: the others are cut to the region as they are drawn.
+    ///
+    /// [`clipped`]: ExportOptions::clipped
+    pub clip: bool,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            width: 512,
+            height: 512,
+            origin: Vec2::ZERO,
+            scale: 100.,
+            colours: vec![
+                [255, 208, 120, 255],
+                [214, 96, 77, 255],
+                [90, 140, 170, 255],
+                [130, 175, 105, 255],
+                [165, 120, 175, 255],
+                [240, 150, 60, 255],
+                [120, 120, 120, 255],
+                [205, 200, 130, 255],
+            ],
+            background: [255, 255, 255, 255],
+            patterns: vec![],
+            mask: None,
+            stroke_colour: [40, 40, 40, 255],
+            stroke_width: 2.,
+            segments: EXPORT_SEGMENTS,
+            physical: None,
+            clip: false,
+        }
+    }
+}
+
+impl ExportOptions {
+    /// The world-space point shown at the bottom right corner of the output, the origin if the
+    /// scale is zero.
+    pub fn world_max(&self) -> Vec2 {
+        if self.scale == 0. {
+            return self.origin;
+        }
+        self.origin + vec2(self.width as f32 / self.scale, self.height as f32 / self.scale)
+    }
+
+    /// The same region of the plane at a different output size. The scale and the stroke width
+    /// follow the new width, so the result looks the same at a higher resolution; they are kept
+    /// if the width was zero.
+    pub fn resized(&self, width: u32, height: u32) -> Self {
+        let factor = if self.width == 0 { 1. } else { width as f32 / self.width as f32 };
+        Self {
+            width,
+            height,
+            scale: self.scale * factor,
+            stroke_width: self.stroke_width * factor,
+            ..self.clone()
+        }
+    }
+
+    /// The same region of the plane at a real-world size: the scale follows from the physical
+    /// length of a world unit and the resolution, and the size in pixels and the stroke width
+    /// follow from the scale. They are kept if the scale was zero.
+    pub fn with_physical(&self, physical: PhysicalScale) -> Self {
+        let factor = if self.scale == 0. { 1. } else { physical.pixels_per_unit() / self.scale };
+        Self {
+            width: ((self.width as f32 * factor).round() as u32).max(1),
+            height: ((self.height as f32 * factor).round() as u32).max(1),
+            scale: physical.pixels_per_unit(),
+            stroke_width: self.stroke_width * factor,
+            physical: Some(physical),
+            ..self.clone()
+        }
+    }
+
+    /// Width and height of the output in the physical unit, if a physical size is set.
+    pub fn physical_size(&self) -> Option<(f32, f32, Unit)> {
+        self.physical
+            .map(|p| (p.from_pixels(self.width as f32), p.from_pixels(self.height as f32), p.unit))
+    }
+
+    /// The fill colour of colour class `class`, the background if there are no colours.
+    pub fn colour(&self, class: usize) -> Rgba {
+        self.colours.get(self.colour_index(class)).copied().unwrap_or(self.background)
+    }
+
+    /// The fill pattern of colour class `class`.
+    pub fn pattern(&self, class: usize) -> Pattern {
+        self.patterns.get(self.colour_index(class)).copied().unwrap_or_default()
+    }
+
+    /// The index into [`colours`] of the fill colour of colour class `class`.
+    ///
+    /// [`colours`]: ExportOptions::colours
+    pub fn colour_index(&self, class: usize) -> usize {
+        class % self.colours.len().max(1)
+    }
+
+    /// The part of the world-space `outline` inside the region if [`clip`] is set, all of it
+    /// otherwise. It is empty if the outline lies outside the region.
+    ///
+    /// [`clip`]: ExportOptions::clip
+    pub fn clipped(&self, outline: &[Vec2]) -> Vec<Vec2> {
+        if self.clip { crate::utils::clip_to_rect(outline, self.origin, self.world_max()) } else { outline.to_vec() }
+    }
+
+    /// Fill the exported region, with a margin of one lattice cell so that tiles reaching into
+    /// the region from outside are included.
+    pub fn fill<'a>(&self, tiling: &'a IsohedralTiling) -> FillAlgorithm<'a> {
+        let margin = tiling.t1().length() + tiling.t2().length();
+        let (min, max) = (self.origin, self.world_max());
+        tiling.fill_region(min.x - margin, min.y - margin, max.x + margin, max.y + margin)
+    }
+}
+
+/// Format a colour as a `#rrggbb` hex string, dropping the alpha channel.
+pub(crate) fn hex(c: Rgba) -> String {
+    format!("#{:02x}{:02x}{:02x}", c[0], c[1], c[2])
+}
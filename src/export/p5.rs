@@ -0,0 +1,86 @@
+//! Export as a [p5.js](https://p5js.org) sketch.
+use std::fmt::Write;
+
+use crate::export::{hex, ExportOptions};
+use crate::tiling::IsohedralTiling;
+use crate::utils::Vec2;
+
+
+/// Write a self-contained p5.js sketch drawing the exported region.
+///
+/// The sketch holds the prototile outline and the list of tile transforms, so the design can be
+/// reused programmatically: each entry of `tiles` is `[a, b, c, d, e, f, colour]`, with the first
+/// six values in the order expected by p5's `applyMatrix`.
+pub fn sketch(tiling: &IsohedralTiling, edges: &[Vec<Vec2>], options: &ExportOptions) -> String {
+    let outline = tiling.outline(&tiling.sample_edges(edges, options.segments));
+    let mut out = String::new();
+
+    // Writing into a `String` can't fail.
+    let _ = writeln!(out, "// {} isohedral tiling, exported from egui Isohedral.", tiling.tiling_type());
+    let _ = writeln!(out, "const prototile = [");
+    for p in &outline {
+        let _ = writeln!(out, "  [{}, {}],", p.x, p.y);
+    }
+    let _ = writeln!(out, "];");
+    let _ = writeln!(
+        out,
+        "const colours = [{}];",
+        options.colours.iter().map(|&c| format!("'{}'", hex(c))).collect::<Vec<_>>().join(", ")
+    );
+    let _ = writeln!(out, "const tiles = [");
+    for tile in options.fill(tiling) {
+        let t = tile.transform();
+        let _ = writeln!(
+            out,
+            "  [{}, {}, {}, {}, {}, {}, {}],",
+            t.matrix2.x_axis.x,
+            t.matrix2.x_axis.y,
+            t.matrix2.y_axis.x,
+            t.matrix2.y_axis.y,
+            t.translation.x,
+            t.translation.y,
+            options.colour_index(tile.colour(tiling)),
+        );
+    }
+    let _ = writeln!(out, "];");
+
+    let _ = write!(
+        out,
+        r#"
+function setup() {{
+  createCanvas({width}, {height});
+  noLoop();
+}}
+
+function draw() {{
+  background('{background}');
+  scale({scale});
+  translate({ox}, {oy});
+  stroke('{stroke}');
+  strokeWeight({stroke_width});
+  strokeJoin(ROUND);
+  for (const [a, b, c, d, e, f, colour] of tiles) {{
+    push();
+    applyMatrix(a, b, c, d, e, f);
+    fill(colours[colour]);
+    beginShape();
+    for (const [x, y] of prototile) {{
+      vertex(x, y);
+    }}
+    endShape(CLOSE);
+    pop();
+  }}
+}}
+"#,
+        width = options.width,
+        height = options.height,
+        background = hex(options.background),
+        scale = options.scale,
+        ox = -options.origin.x,
+        oy = -options.origin.y,
+        stroke = hex(options.stroke_colour),
+        stroke_width = options.stroke_width / options.scale,
+    );
+
+    out
+}
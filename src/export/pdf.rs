@@ -0,0 +1,224 @@
+//! Export as a multi-page PDF document, for printing a tiling at its physical size.
+//!
+//! The exported region is cut into pages of the chosen paper size, each showing the part of the
+//! tiling that falls inside its margins, so the printed sheets can be trimmed and laid edge to
+//! edge. The tiles are written as vector paths; colours are opaque, their alpha is dropped.
+use std::fmt::Write;
+
+use crate::export::{ExportOptions, Rgba};
+use crate::tiling::IsohedralTiling;
+use crate::utils::{vec2, Vec2};
+
+
+/// PDF points per millimetre.
+const POINTS_PER_MM: f32 = 72. / 25.4;
+
+/// A paper size, in millimetres, in portrait orientation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Paper {
+    pub name: &'static str,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// The available paper sizes, ISO sizes first.
+pub const PAPERS: [Paper; 5] = [
+    Paper { name: "A4", width: 210., height: 297. },
+    Paper { name: "A3", width: 297., height: 420. },
+    Paper { name: "A5", width: 148., height: 210. },
+    Paper { name: "Letter", width: 215.9, height: 279.4 },
+    Paper { name: "Legal", width: 215.9, height: 355.6 },
+];
+
+/// How the exported region is laid out on paper.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrintLayout {
+    pub paper: Paper,
+    /// Turn the paper sideways.
+    pub landscape: bool,
+    /// Blank border on every side of a page, in millimetres.
+    pub margin: f32,
+}
+
+impl Default for PrintLayout {
+    fn default() -> Self {
+        Self { paper: PAPERS[0], landscape: false, margin: 10. }
+    }
+}
+
+impl PrintLayout {
+    /// Width and height of a page in millimetres.
+    pub fn page_size(&self) -> (f32, f32) {
+        let Paper { width, height, .. } = self.paper;
+        if self.landscape { (height, width) } else { (width, height) }
+    }
+
+    /// Width and height of the printable part of a page, inside the margins, in millimetres.
+    pub fn printable_size(&self) -> (f32, f32) {
+        let (width, height) = self.page_size();
+        ((width - 2. * self.margin).max(1.), (height - 2. * self.margin).max(1.))
+    }
+
+    /// Number of columns and rows of pages needed for the region of `options`.
+    pub fn pages(&self, options: &ExportOptions) -> (usize, usize) {
+        let (width, height) = self.page_world_size(options);
+        let size = options.world_max() - options.origin;
+        (((size.x / width).ceil() as usize).max(1), ((size.y / height).ceil() as usize).max(1))
+    }
+
+    /// The world-space size covered by the printable part of a page.
+    fn page_world_size(&self, options: &ExportOptions) -> (f32, f32) {
+        let mm = mm_per_unit(options);
+        let (width, height) = self.printable_size();
+        (width / mm, height / mm)
+    }
+}
+
+/// Write a PDF document of the region of `options`, paginated onto the paper of `layout`.
+///
+/// The scale comes from [`ExportOptions::physical`], or its default when no physical size is
+/// set. Pages run in rows from the top left; each is labelled with its row and column.
+pub fn document(tiling: &IsohedralTiling, edges: &[Vec<Vec2>], options: &ExportOptions, layout: &PrintLayout) -> Vec<u8> {
+    let sampled = tiling.sample_edges(edges, options.segments);
+    let outline = tiling.outline(&sampled);
+    let (columns, rows) = layout.pages(options);
+    let (page_width, page_height) = layout.page_world_size(options);
+
+    let mut pages = vec![];
+    for row in 0..rows {
+        for column in 0..columns {
+            let min = options.origin + vec2(column as f32 * page_width, row as f32 * page_height);
+            let max = min + vec2(page_width, page_height);
+            let label = format!(
+                "{} - page {} of {}, row {}, column {}",
+                tiling.tiling_type(),
+                pages.len() + 1,
+                rows * columns,
+                row + 1,
+                column + 1
+            );
+            pages.push(page(tiling, &sampled, &outline, options, layout, min, max, &label));
+        }
+    }
+
+    write_document(layout, &pages)
+}
+
+/// Millimetres per world unit.
+fn mm_per_unit(options: &ExportOptions) -> f32 {
+    let physical = options.physical.unwrap_or_default();
+    physical.unit_length * 25.4 / physical.unit.per_inch()
+}
+
+/// The content stream of a page showing the world-space rectangle from `min` to `max`.
+#[allow(clippy::too_many_arguments)]
+fn page(
+    tiling: &IsohedralTiling,
+    sampled: &[Vec<Vec2>],
+    outline: &[Vec2],
+    options: &ExportOptions,
+    layout: &PrintLayout,
+    min: Vec2,
+    max: Vec2,
+    label: &str,
+) -> String {
+    // The last pages reach past the region; clipped, they end where it does.
+    let end = options.world_max();
+    let max = if options.clip { vec2(max.x.min(end.x), max.y.min(end.y)) } else { max };
+    let (_, height) = layout.page_size();
+    let margin = layout.margin * POINTS_PER_MM;
+    let scale = mm_per_unit(options) * POINTS_PER_MM;
+    let (width_pt, height_pt) = ((max.x - min.x) * scale, (max.y - min.y) * scale);
+    let top = height * POINTS_PER_MM - margin;
+    let mut out = String::new();
+
+    // Writing into a `String` can't fail.
+    let _ = writeln!(out, "q");
+    let _ = writeln!(out, "{margin:.3} {:.3} {width_pt:.3} {height_pt:.3} re W n", top - height_pt);
+    let _ = writeln!(out, "{} {margin:.3} {:.3} {width_pt:.3} {height_pt:.3} re f", fill(options.background), top - height_pt);
+    // From here on coordinates are in world units, with y pointing down as on screen.
+    let _ = writeln!(out, "{scale:.5} 0 0 {:.5} {:.4} {:.4} cm", -scale, margin - min.x * scale, top + min.y * scale);
+
+    let lattice = tiling.t1().length() + tiling.t2().length();
+    let tiles = tiling.fill_region(min.x - lattice, min.y - lattice, max.x + lattice, max.y + lattice).collect_tiles();
+    for tile in &tiles {
+        let t = tile.transform();
+        let _ = write!(out, "{}", fill(options.colour(tile.colour(tiling))));
+        for (k, &p) in outline.iter().enumerate() {
+            let p = t.transform_point2(p);
+            let _ = write!(out, " {:.4} {:.4} {}", p.x, p.y, if k == 0 { "m" } else { "l" });
+        }
+        let _ = writeln!(out, " h f*");
+    }
+
+    if options.stroke_width > 0. {
+        let c = options.stroke_colour;
+        let _ = writeln!(out, "{} RG {:.5} w 1 J 1 j", rgb(c), options.stroke_width / options.scale);
+        for edge in tiling.unique_edges(sampled, tiles.iter().map(|tile| tile.transform())) {
+            for (k, p) in edge.iter().enumerate() {
+                let _ = write!(out, "{:.4} {:.4} {} ", p.x, p.y, if k == 0 { "m" } else { "l" });
+            }
+            let _ = writeln!(out, "S");
+        }
+    }
+    let _ = writeln!(out, "Q");
+
+    let _ = writeln!(out, "BT /F1 8 Tf {margin:.3} {:.3} Td ({}) Tj ET", margin / 2., escape(label));
+    out
+}
+
+/// The PDF operator setting the fill colour.
+fn fill(c: Rgba) -> String {
+    format!("{} rg", rgb(c))
+}
+
+fn rgb(c: Rgba) -> String {
+    format!("{:.3} {:.3} {:.3}", c[0] as f32 / 255., c[1] as f32 / 255., c[2] as f32 / 255.)
+}
+
+/// Escape a PDF literal string.
+fn escape(text: &str) -> String {
+    text.chars()
+        .filter(char::is_ascii)
+        .flat_map(|c| match c {
+            '(' | ')' | '\\' => vec!['\\', c],
+            _ => vec![c],
+        })
+        .collect()
+}
+
+/// Assemble the document from the content streams of its pages.
+fn write_document(layout: &PrintLayout, pages: &[String]) -> Vec<u8> {
+    let (width, height) = layout.page_size();
+    let (width, height) = (width * POINTS_PER_MM, height * POINTS_PER_MM);
+
+    // Objects 1 to 3 are the catalog, the page tree and the font; each page adds two more.
+    let kids: Vec<String> = (0..pages.len()).map(|i| format!("{} 0 R", 4 + 2 * i)).collect();
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!("<< /Type /Pages /Kids [{}] /Count {} >>", kids.join(" "), pages.len()),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+    ];
+    for (i, content) in pages.iter().enumerate() {
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {width:.3} {height:.3}] /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+            5 + 2 * i
+        ));
+        objects.push(format!("<< /Length {} >>\nstream\n{content}endstream", content.len()));
+    }
+
+    let mut out = String::from("%PDF-1.4\n");
+    let mut offsets = vec![];
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        let _ = writeln!(out, "{} 0 obj\n{object}\nendobj", i + 1);
+    }
+    let xref = out.len();
+    let _ = writeln!(out, "xref\n0 {}\n0000000000 65535 f ", objects.len() + 1);
+    for offset in offsets {
+        let _ = writeln!(out, "{offset:010} 00000 n ");
+    }
+    let _ = writeln!(out, "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF", objects.len() + 1);
+
+    out.into_bytes()
+}
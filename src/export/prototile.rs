@@ -0,0 +1,115 @@
+//! Export the prototile on its own as SVG, as a template to cut tiles from.
+use std::fmt::Write;
+
+use crate::export::{hex, ExportOptions};
+use crate::tiling::IsohedralTiling;
+use crate::utils::{vec2, Vec2};
+
+
+/// Write an SVG document with the outline of the prototile, at the physical size if one is set.
+///
+/// With `annotate`, the corners are lettered from `A` and every edge is labelled with the
+/// distance between its corners, and also its length along the curve if it isn't straight, and
+/// every corner with the interior angle of the tile there. Lengths are in the physical unit if
+/// one is set, in world units otherwise.
+pub fn drawing(tiling: &IsohedralTiling, edges: &[Vec<Vec2>], options: &ExportOptions, annotate: bool) -> String {
+    let sampled = tiling.sample_edges(edges, options.segments);
+    // Each edge of the outline from its first corner to the next, the corners in order.
+    let sides: Vec<Vec<Vec2>> = tiling
+        .shapes()
+        .map(|e| {
+            let t = e.transform();
+            let mut side: Vec<Vec2> = sampled[e.id()].iter().map(|&p| t.transform_point2(p)).collect();
+            if e.reversed() {
+                side.reverse();
+            }
+            side
+        })
+        .collect();
+    let outline: Vec<Vec2> = sides.iter().flat_map(|side| side[1..].iter().copied()).collect();
+
+    let s = options.scale;
+    let (lo, hi) = bounds(&outline);
+    let font = ((hi - lo).length() * s / 40.).max(6.);
+    let margin = if annotate { 8. * font } else { options.stroke_width.max(1.) };
+    let (w, h) = ((hi.x - lo.x) * s + 2. * margin, (hi.y - lo.y) * s + 2. * margin);
+    let to_page = |p: Vec2| (p - lo) * s + vec2(margin, margin);
+    let mut out = String::new();
+
+    // Writing into a `String` can't fail.
+    let size = match options.physical {
+        Some(physical) => format!(r#"width="{:.3}{u}" height="{:.3}{u}""#, physical.from_pixels(w), physical.from_pixels(h), u = physical.unit.suffix()),
+        None => format!(r#"width="{w:.0}" height="{h:.0}""#),
+    };
+    let _ = writeln!(out, r#"<svg xmlns="http://www.w3.org/2000/svg" {size} viewBox="0 0 {w:.3} {h:.3}">"#);
+    let _ = writeln!(out, "  <!-- Prototile of a {} isohedral tiling, exported from egui Isohedral. -->", tiling.tiling_type());
+    let _ = write!(
+        out,
+        r#"  <path fill="none" stroke="{}" stroke-width="{}" stroke-linejoin="round" d=""#,
+        hex(options.stroke_colour),
+        options.stroke_width.max(1.)
+    );
+    for (k, &p) in outline.iter().enumerate() {
+        let p = to_page(p);
+        let _ = write!(out, "{}{:.3} {:.3}", if k == 0 { "M" } else { " L" }, p.x, p.y);
+    }
+    let _ = writeln!(out, r#" Z"/>"#);
+
+    if annotate {
+        let length = |world: f32| match options.physical {
+            Some(physical) => format!("{:.2} {}", world * physical.unit_length, physical.unit.suffix()),
+            None => format!("{world:.3}"),
+        };
+        let centre = to_page(crate::utils::centroid(&outline));
+        let label = |out: &mut String, at: Vec2, text: &str| {
+            let _ = writeln!(
+                out,
+                r#"  <text x="{:.3}" y="{:.3}" font-family="sans-serif" font-size="{font:.2}" text-anchor="middle" dominant-baseline="middle" fill="{}">{text}</text>"#,
+                at.x,
+                at.y,
+                hex(options.stroke_colour)
+            );
+        };
+        // The outline's orientation decides which way round from an edge the inside is.
+        let turning = signed_area(&outline).signum();
+        let letter = |i: usize| char::from(b'A' + (i % 26) as u8);
+        for (i, side) in sides.iter().enumerate() {
+            let (a, b) = (side[0], side[side.len() - 1]);
+            let chord = (b - a).length();
+            let along: f32 = side.windows(2).map(|pair| (pair[1] - pair[0]).length()).sum();
+            let mut text = format!("{}{} {}", letter(i), letter((i + 1) % sides.len()), length(chord));
+            if along > chord * 1.001 {
+                text += &format!(" ({} along)", length(along));
+            }
+            // Outside the edge's middle point, away from the tile.
+            let middle = to_page(side[side.len() / 2]);
+            let direction = (to_page(b) - to_page(a)) * (1. / (chord * s).max(f32::EPSILON));
+            let outward = vec2(direction.y, -direction.x) * turning;
+            label(&mut out, middle + outward * (1.5 * font), &text);
+
+            // The interior angle where this edge leaves its first corner.
+            let previous = &sides[(i + sides.len() - 1) % sides.len()];
+            let (leaving, arriving) = (side[1] - a, previous[previous.len() - 2] - a);
+            let angle = (turning * leaving.perp_dot(arriving)).atan2(leaving.dot(arriving)).rem_euclid(std::f32::consts::TAU).to_degrees();
+            let corner = to_page(a);
+            let inward = (centre - corner) * (1. / (centre - corner).length().max(f32::EPSILON));
+            label(&mut out, corner - inward * (1.2 * font), &letter(i).to_string());
+            label(&mut out, corner + inward * (2. * font), &format!("{angle:.1}°"));
+        }
+    }
+    let _ = writeln!(out, "</svg>");
+
+    out
+}
+
+fn bounds(points: &[Vec2]) -> (Vec2, Vec2) {
+    points.iter().fold(
+        (vec2(f32::INFINITY, f32::INFINITY), vec2(f32::NEG_INFINITY, f32::NEG_INFINITY)),
+        |(lo, hi), p| (vec2(lo.x.min(p.x), lo.y.min(p.y)), vec2(hi.x.max(p.x), hi.y.max(p.y))),
+    )
+}
+
+/// Twice the signed area of a closed outline, positive if it runs anticlockwise with `y` up.
+fn signed_area(points: &[Vec2]) -> f32 {
+    points.iter().zip(points.iter().cycle().skip(1)).map(|(&p, &q)| p.perp_dot(q)).sum()
+}
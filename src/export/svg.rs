@@ -0,0 +1,115 @@
+//! Export as SVG.
+use std::fmt::Write;
+
+use crate::export::{hex, ExportOptions};
+use crate::tiling::IsohedralTiling;
+use crate::utils::{vec2, Vec2};
+
+
+/// Id of the `<pattern>` element written by [`pattern`].
+pub const PATTERN_ID: &str = "isohedral";
+
+/// Write an SVG document holding exactly one translational unit as a repeating `<pattern>`.
+///
+/// The pattern cell is the parallelogram spanned by the translation vectors, set up through
+/// `patternTransform`, so the pattern repeats seamlessly however the lattice is skewed. Tiles
+/// crossing the cell boundary are included from the neighbouring cells and clipped by the
+/// pattern itself. The document fills its whole area with the pattern, aligned with the exported
+/// region; other documents can reference the pattern as `url(#isohedral)`.
+pub fn pattern(tiling: &IsohedralTiling, edges: &[Vec<Vec2>], options: &ExportOptions) -> String {
+    let sampled = tiling.sample_edges(edges, options.segments);
+    let outline = tiling.outline(&sampled);
+    let (t1, t2) = (*tiling.t1(), *tiling.t2());
+    let s = options.scale;
+    let offset = vec2(-options.origin.x * s, -options.origin.y * s);
+    let mut out = String::new();
+
+    // Writing into a `String` can't fail.
+    let (w, h) = (options.width, options.height);
+    let size = match options.physical_size() {
+        Some((pw, ph, unit)) => format!(r#"width="{pw:.3}{u}" height="{ph:.3}{u}""#, u = unit.suffix()),
+        None => format!(r#"width="{w}" height="{h}""#),
+    };
+    let _ = writeln!(out, r#"<svg xmlns="http://www.w3.org/2000/svg" {size} viewBox="0 0 {w} {h}">"#);
+    let _ = writeln!(out, "  <!-- {} isohedral tiling, exported from egui Isohedral. -->", tiling.tiling_type());
+    let _ = writeln!(out, "  <defs>");
+    let _ = writeln!(
+        out,
+        r#"    <pattern id="{PATTERN_ID}" patternUnits="userSpaceOnUse" width="1" height="1" patternTransform="matrix({} {} {} {} {} {})">"#,
+        t1.x * s,
+        t1.y * s,
+        t2.x * s,
+        t2.y * s,
+        offset.x,
+        offset.y,
+    );
+    let mut copies = vec![];
+
+    for unit_tile in tiling.translational_unit() {
+        // The outline of this aspect in lattice coordinates, where the cell is the unit square.
+        let cell: Vec<Vec2> = outline
+            .iter()
+            .map(|&p| tiling.lattice_coords(unit_tile.transform().transform_point2(p)))
+            .collect();
+        let (lo, hi) = bounds(&cell);
+
+        // Every copy of the tile overlapping the cell, including those from neighbouring cells.
+        for i in (-hi.x).floor() as isize..=(1. - lo.x).ceil() as isize {
+            for j in (-hi.y).floor() as isize..=(1. - lo.y).ceil() as isize {
+                if i as f32 + lo.x >= 1. || i as f32 + hi.x <= 0. || j as f32 + lo.y >= 1. || j as f32 + hi.y <= 0. {
+                    continue;
+                }
+                copies.push(tiling.tile(i, j, unit_tile.aspect()).transform());
+                let colour = hex(options.colour(tiling.colour(i, j, unit_tile.aspect())));
+                let _ = write!(out, r#"      <path fill="{colour}" d=""#);
+                for (k, p) in cell.iter().enumerate() {
+                    let _ = write!(out, "{}{:.5} {:.5}", if k == 0 { "M" } else { " L" }, p.x + i as f32, p.y + j as f32);
+                }
+                let _ = writeln!(out, r#" Z"/>"#);
+            }
+        }
+    }
+
+    // The strokes go on top of all the fills, one per edge rather than one per tile outline.
+    if options.stroke_width > 0. {
+        let _ = write!(
+            out,
+            r#"      <path fill="none" stroke="{}" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round" vector-effect="non-scaling-stroke" d=""#,
+            hex(options.stroke_colour),
+            options.stroke_width
+        );
+        for edge in tiling.unique_edges(&sampled, copies) {
+            for (k, &p) in edge.iter().enumerate() {
+                let p = tiling.lattice_coords(p);
+                let _ = write!(out, "{}{:.5} {:.5}", if k == 0 { " M" } else { " L" }, p.x, p.y);
+            }
+        }
+        let _ = writeln!(out, r#""/>"#);
+    }
+
+    let _ = writeln!(out, "    </pattern>");
+    if let Some(mask) = &options.mask {
+        let _ = write!(out, r#"    <clipPath id="mask"><path clip-rule="evenodd" d=""#);
+        for shape in mask.loops() {
+            for (k, &p) in shape.iter().enumerate() {
+                let _ = write!(out, "{}{:.3} {:.3}", if k == 0 { " M" } else { " L" }, p.x * s + offset.x, p.y * s + offset.y);
+            }
+            let _ = write!(out, " Z");
+        }
+        let _ = writeln!(out, r#""/></clipPath>"#);
+    }
+    let _ = writeln!(out, "  </defs>");
+    let _ = writeln!(out, r#"  <rect width="100%" height="100%" fill="{}"/>"#, hex(options.background));
+    let clip = if options.mask.is_some() { r#" clip-path="url(#mask)""# } else { "" };
+    let _ = writeln!(out, r#"  <rect width="100%" height="100%" fill="url(#{PATTERN_ID})"{clip}/>"#);
+    let _ = writeln!(out, "</svg>");
+
+    out
+}
+
+fn bounds(points: &[Vec2]) -> (Vec2, Vec2) {
+    points.iter().fold(
+        (vec2(f32::INFINITY, f32::INFINITY), vec2(f32::NEG_INFINITY, f32::NEG_INFINITY)),
+        |(lo, hi), p| (vec2(lo.x.min(p.x), lo.y.min(p.y)), vec2(hi.x.max(p.x), hi.y.max(p.y))),
+    )
+}
@@ -0,0 +1,83 @@
+//! Dump the computed state of a tiling in the layout of Craig S. Kaplan's [Tactile] library, for
+//! cross-validating this port against the reference implementation.
+//!
+//! [Tactile]: https://isohedral.ca/software/tactile/
+use std::fmt::Write;
+
+use crate::tiling::{EdgeShape, IsohedralTiling};
+use crate::utils::{Affine2, Vec2};
+
+
+/// Write the state of `tiling` at its current parameters as JSON, with the member names of
+/// Tactile's `IsohedralTiling` and its tiling type data.
+///
+/// Points are written as `{"x": .., "y": ..}` and transforms as Tactile's six-element row-major
+/// arrays `[a, b, c, d, e, f]`, mapping `(x, y)` to `(a x + b y + c, d x + e y + f)`. Edge shapes
+/// use Tactile's `EdgeShape` constants. The dump of the same type and parameters taken from the
+/// JavaScript or C++ library, serialised the same way, should match up to rounding.
+pub fn dump(tiling: &IsohedralTiling) -> String {
+    let ttd = tiling.ttd;
+    let mut params = [0.; 6];
+    tiling.parameters(&mut params);
+    let mut out = String::new();
+
+    // Writing into a `String` can't fail.
+    let _ = writeln!(out, "{{");
+    let _ = writeln!(out, r#"  "ihtype": {},"#, tiling.tiling_type().0);
+    let _ = writeln!(out, r#"  "num_params": {},"#, tiling.num_params());
+    let _ = writeln!(out, r#"  "parameters": [{}],"#, list(&params[..tiling.num_params()], |&p| number(p)));
+    let _ = writeln!(out, r#"  "verts": [{}],"#, list(tiling.vertices(), |&p| point(p)));
+    let edges: Vec<Affine2> = tiling.shapes().map(|e| e.transform()).collect();
+    let _ = writeln!(out, r#"  "edges": [{}],"#, list(&edges, matrix));
+    let reversals: Vec<bool> = tiling.shapes().map(|e| e.reversed()).collect();
+    let _ = writeln!(out, r#"  "reversals": [{}],"#, list(&reversals, bool::to_string));
+    let aspects: Vec<Affine2> = (0..tiling.num_aspects()).map(|i| *tiling.aspect_transform(i)).collect();
+    let _ = writeln!(out, r#"  "aspects": [{}],"#, list(&aspects, matrix));
+    let _ = writeln!(out, r#"  "t1": {},"#, point(*tiling.t1()));
+    let _ = writeln!(out, r#"  "t2": {},"#, point(*tiling.t2()));
+    let _ = writeln!(out, r#"  "ttd": {{"#);
+    let _ = writeln!(out, r#"    "num_params": {},"#, ttd.num_params);
+    let _ = writeln!(out, r#"    "num_aspects": {},"#, ttd.num_aspects);
+    let _ = writeln!(out, r#"    "num_vertices": {},"#, ttd.num_vertices);
+    let _ = writeln!(out, r#"    "num_edge_shapes": {},"#, ttd.num_edge_shapes);
+    let _ = writeln!(out, r#"    "edge_shapes": [{}],"#, list(ttd.edge_shapes, |&s| edge_shape(s).to_string()));
+    let _ = writeln!(out, r#"    "edge_orientations": [{}],"#, list(ttd.edge_orientations, bool::to_string));
+    let _ = writeln!(out, r#"    "edge_shape_ids": [{}],"#, list(ttd.edge_shape_ids, usize::to_string));
+    let _ = writeln!(out, r#"    "default_params": [{}],"#, list(ttd.default_params, |&p| number(p)));
+    let _ = writeln!(out, r#"    "tiling_vertex_coeffs": [{}],"#, list(ttd.tiling_vertex_coeffs, |&p| number(p)));
+    let _ = writeln!(out, r#"    "translation_vertex_coeffs": [{}],"#, list(ttd.translation_vertex_coeffs, |&p| number(p)));
+    let _ = writeln!(out, r#"    "aspect_xform_coeffs": [{}],"#, list(ttd.aspect_xform_coeffs, |&p| number(p)));
+    let _ = writeln!(out, r#"    "colouring": [{}]"#, list(ttd.colouring, u8::to_string));
+    let _ = writeln!(out, "  }}");
+    let _ = writeln!(out, "}}");
+
+    out
+}
+
+/// Tactile's numbering of the edge shapes.
+fn edge_shape(shape: EdgeShape) -> u32 {
+    match shape {
+        EdgeShape::J => 10001,
+        EdgeShape::U => 10002,
+        EdgeShape::S => 10003,
+        EdgeShape::I => 10004,
+    }
+}
+
+fn list<T>(items: &[T], format: impl Fn(&T) -> String) -> String {
+    items.iter().map(format).collect::<Vec<_>>().join(", ")
+}
+
+/// A number as JSON, which has no representation for NaN or infinities.
+fn number(x: f32) -> String {
+    if x.is_finite() { x.to_string() } else { String::from("null") }
+}
+
+fn point(p: Vec2) -> String {
+    format!(r#"{{"x": {}, "y": {}}}"#, number(p.x), number(p.y))
+}
+
+fn matrix(t: &Affine2) -> String {
+    let (x, y, o) = (t.matrix2.x_axis, t.matrix2.y_axis, t.translation);
+    format!("[{}]", [x.x, y.x, o.x, x.y, y.y, o.y].map(number).join(", "))
+}
@@ -0,0 +1,71 @@
+//! Export as a TikZ picture for LaTeX documents.
+use std::fmt::Write;
+
+use crate::export::ExportOptions;
+use crate::tiling::IsohedralTiling;
+use crate::utils::Vec2;
+
+
+/// Write a `tikzpicture` of the exported region, `width` wide.
+///
+/// `width` is any TeX length, for example `\linewidth` or `12cm`. Coordinates are expressed as
+/// fractions of it, so the picture and its line widths scale together. The region is clipped to
+/// the exported rectangle and the y axis is flipped to match TikZ's orientation.
+pub fn picture(
+    tiling: &IsohedralTiling,
+    edges: &[Vec<Vec2>],
+    options: &ExportOptions,
+    width: &str,
+) -> String {
+    let sampled = tiling.sample_edges(edges, options.segments);
+    let outline = tiling.outline(&sampled);
+    let world_width = options.width as f32 / options.scale;
+    let aspect = options.height as f32 / options.width as f32;
+    let to_tikz = |p: Vec2| ((p.x - options.origin.x) / world_width, -(p.y - options.origin.y) / world_width);
+    let mut out = String::new();
+
+    // Writing into a `String` can't fail.
+    let _ = writeln!(out, "% {} isohedral tiling, exported from egui Isohedral.", tiling.tiling_type());
+    for (i, c) in options.colours.iter().enumerate() {
+        let _ = writeln!(out, "\\definecolor{{tile{i}}}{{RGB}}{{{},{},{}}}", c[0], c[1], c[2]);
+    }
+    let c = options.stroke_colour;
+    let _ = writeln!(out, "\\definecolor{{tilestroke}}{{RGB}}{{{},{},{}}}", c[0], c[1], c[2]);
+    let c = options.background;
+    let _ = writeln!(out, "\\definecolor{{tilebackground}}{{RGB}}{{{},{},{}}}", c[0], c[1], c[2]);
+
+    let _ = writeln!(out, "\\begin{{tikzpicture}}[x={width}, y={width}]");
+    let _ = writeln!(out, "  \\clip (0,0) rectangle (1,{:.4});", -aspect);
+    let _ = writeln!(out, "  \\fill[tilebackground] (0,0) rectangle (1,{:.4});", -aspect);
+
+    let tiles = options.fill(tiling).collect_tiles();
+    for tile in &tiles {
+        let t = tile.transform();
+        let _ = write!(out, "  \\fill[tile{}]", options.colour_index(tile.colour(tiling)));
+        for &p in &outline {
+            let (x, y) = to_tikz(t.transform_point2(p));
+            let _ = write!(out, " ({x:.4},{y:.4}) --");
+        }
+        let _ = writeln!(out, " cycle;");
+    }
+
+    // The strokes go on top of all the fills, one per edge rather than one per tile outline.
+    if options.stroke_width > 0. {
+        let width_fraction = options.stroke_width / options.width as f32;
+        let _ = writeln!(out, "  \\begin{{scope}}[draw=tilestroke, line width={{{width_fraction:.5}*{width}}}, line cap=round, line join=round]");
+        for edge in tiling.unique_edges(&sampled, tiles.iter().map(|tile| tile.transform())) {
+            let points: Vec<String> = edge
+                .iter()
+                .map(|&p| {
+                    let (x, y) = to_tikz(p);
+                    format!("({x:.4},{y:.4})")
+                })
+                .collect();
+            let _ = writeln!(out, "    \\draw {};", points.join(" -- "));
+        }
+        let _ = writeln!(out, "  \\end{{scope}}");
+    }
+    let _ = writeln!(out, "\\end{{tikzpicture}}");
+
+    out
+}
@@ -0,0 +1,40 @@
+//! Resolution presets for desktop and phone wallpapers.
+
+
+/// A wallpaper size, with the margins that are likely covered by system UI.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Preset {
+    pub name: &'static str,
+    pub width: u32,
+    pub height: u32,
+    /// Fraction of the width kept clear on the left and right.
+    pub safe_x: f32,
+    /// Fraction of the height kept clear at the top and bottom, for panels, docks and notches.
+    pub safe_y: f32,
+}
+
+impl Preset {
+    const fn desktop(name: &'static str, width: u32, height: u32) -> Self {
+        Self { name, width, height, safe_x: 0.03, safe_y: 0.05 }
+    }
+
+    const fn phone(name: &'static str, width: u32, height: u32) -> Self {
+        Self { name, width, height, safe_x: 0.05, safe_y: 0.12 }
+    }
+
+    /// Width divided by height.
+    pub fn aspect_ratio(&self) -> f32 {
+        self.width as f32 / self.height as f32
+    }
+}
+
+/// The available presets, desktop sizes first.
+pub const PRESETS: [Preset; 7] = [
+    Preset::desktop("1080p", 1920, 1080),
+    Preset::desktop("1440p", 2560, 1440),
+    Preset::desktop("4K", 3840, 2160),
+    Preset::desktop("Ultrawide 1440p", 3440, 1440),
+    Preset::phone("Phone FHD+", 1080, 2400),
+    Preset::phone("Phone 19.5:9", 1170, 2532),
+    Preset::phone("Phone QHD+", 1440, 3200),
+];
@@ -0,0 +1,220 @@
+//! A C interface to the tiling core, for C and C++ programs and game engines.
+//!
+//! Enabled by the `ffi` feature. Build a shared or static library with
+//! `cargo rustc --lib --release --features ffi --crate-type cdylib` (or `staticlib`), and include
+//! `include/egui_isohedral.h`.
+//!
+//! A tiling is created with [`ih_tiling_new`] and must be released with [`ih_tiling_free`].
+//! [`ih_tiling_fill`] writes the tiles of a region into a caller-owned buffer of [`IhTile`],
+//! each with its transform as the six numbers `a b c d e f` of the matrix `[a b c; d e f]`
+//! mapping `(x, y)` to `(ax + by + c, dx + ey + f)`. Functions returning `int` return `0` on
+//! success and `-1` if an argument is invalid, leaving the tiling unchanged.
+use std::ptr;
+
+use crate::data::try_get_tiling_type;
+use crate::tiling::IsohedralTiling;
+
+
+/// A tile written by [`ih_tiling_fill`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct IhTile {
+    /// Position in the lattice, in multiples of the translation vectors.
+    pub t1: i32,
+    pub t2: i32,
+    /// Index of the aspect, see [`IsohedralTiling::aspect_transform`].
+    pub aspect: u32,
+    /// Colour class, see [`IsohedralTiling::colour`].
+    pub colour: u32,
+    /// The transform from the prototile to the tile, see the [module docs](self).
+    pub matrix: [f32; 6],
+}
+
+/// A new tiling of the `index`-th valid tiling type, from `0` to `80`, or null if there is no
+/// such type.
+#[unsafe(no_mangle)]
+pub extern "C" fn ih_tiling_new(index: usize) -> *mut IsohedralTiling {
+    match try_get_tiling_type(index) {
+        Ok(ihtype) => Box::into_raw(Box::new(IsohedralTiling::new(ihtype))),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Release a tiling made by [`ih_tiling_new`]. Null is ignored.
+///
+/// # Safety
+///
+/// `tiling` must be null or come from [`ih_tiling_new`], and not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ih_tiling_free(tiling: *mut IsohedralTiling) {
+    if !tiling.is_null() {
+        // SAFETY: the caller passes a pointer from `Box::into_raw` that is not used again.
+        drop(unsafe { Box::from_raw(tiling) });
+    }
+}
+
+/// Switch `tiling` to the `index`-th valid tiling type, with its default parameters.
+///
+/// # Safety
+///
+/// `tiling` must be null or a live tiling from [`ih_tiling_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ih_tiling_reset(tiling: *mut IsohedralTiling, index: usize) -> i32 {
+    // SAFETY: the caller passes null or a live tiling.
+    let (Some(tiling), Ok(ihtype)) = (unsafe { tiling.as_mut() }, try_get_tiling_type(index)) else {
+        return -1;
+    };
+    tiling.reset(ihtype);
+    0
+}
+
+/// The `IHnn` number of the tiling type of `tiling`, or `0` if it is null.
+///
+/// # Safety
+///
+/// `tiling` must be null or a live tiling from [`ih_tiling_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ih_tiling_type(tiling: *const IsohedralTiling) -> u32 {
+    // SAFETY: the caller passes null or a live tiling.
+    unsafe { tiling.as_ref() }.map_or(0, |tiling| tiling.tiling_type().0 as u32)
+}
+
+/// The number of parameters of `tiling`, at most six, or `0` if it is null.
+///
+/// # Safety
+///
+/// `tiling` must be null or a live tiling from [`ih_tiling_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ih_tiling_num_params(tiling: *const IsohedralTiling) -> usize {
+    // SAFETY: the caller passes null or a live tiling.
+    unsafe { tiling.as_ref() }.map_or(0, IsohedralTiling::num_params)
+}
+
+/// Write the parameters of `tiling` to `params`, which holds [`ih_tiling_num_params`] of them.
+///
+/// # Safety
+///
+/// `tiling` must be null or a live tiling from [`ih_tiling_new`], and `params` null or valid for
+/// writing that many floats.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ih_tiling_get_params(tiling: *const IsohedralTiling, params: *mut f32) -> i32 {
+    // SAFETY: the caller passes null or a live tiling.
+    let Some(tiling) = (unsafe { tiling.as_ref() }) else {
+        return -1;
+    };
+    if params.is_null() {
+        return -1;
+    }
+    let mut all = [0.; 6];
+    tiling.parameters(&mut all);
+    // SAFETY: the caller makes room for `num_params` floats.
+    unsafe { ptr::copy_nonoverlapping(all.as_ptr(), params, tiling.num_params()) };
+    0
+}
+
+/// Set the parameters of `tiling` from the `count` floats at `params`, which must be
+/// [`ih_tiling_num_params`] finite numbers.
+///
+/// # Safety
+///
+/// `tiling` must be null or a live tiling from [`ih_tiling_new`], and `params` null or valid for
+/// reading `count` floats.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ih_tiling_set_params(tiling: *mut IsohedralTiling, params: *const f32, count: usize) -> i32 {
+    // SAFETY: the caller passes null or a live tiling.
+    let Some(tiling) = (unsafe { tiling.as_mut() }) else {
+        return -1;
+    };
+    if params.is_null() || count != tiling.num_params() {
+        return -1;
+    }
+    // SAFETY: the caller passes `count` readable floats.
+    let values = unsafe { std::slice::from_raw_parts(params, count) };
+    let mut all = [0.; 6];
+    tiling.parameters(&mut all);
+    all[..count].copy_from_slice(values);
+    match tiling.try_set_parameters(&all) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// The number of vertices of the prototile of `tiling`, or `0` if it is null.
+///
+/// # Safety
+///
+/// `tiling` must be null or a live tiling from [`ih_tiling_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ih_tiling_num_vertices(tiling: *const IsohedralTiling) -> usize {
+    // SAFETY: the caller passes null or a live tiling.
+    unsafe { tiling.as_ref() }.map_or(0, IsohedralTiling::num_vertices)
+}
+
+/// Write the prototile vertices of `tiling` to `xy` as `x, y` pairs, which holds
+/// [`ih_tiling_num_vertices`] of them.
+///
+/// # Safety
+///
+/// `tiling` must be null or a live tiling from [`ih_tiling_new`], and `xy` null or valid for
+/// writing twice that many floats.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ih_tiling_vertices(tiling: *const IsohedralTiling, xy: *mut f32) -> i32 {
+    // SAFETY: the caller passes null or a live tiling.
+    let Some(tiling) = (unsafe { tiling.as_ref() }) else {
+        return -1;
+    };
+    if xy.is_null() {
+        return -1;
+    }
+    for (i, v) in tiling.vertices().iter().enumerate() {
+        // SAFETY: the caller makes room for two floats per vertex.
+        unsafe {
+            *xy.add(2 * i) = v.x;
+            *xy.add(2 * i + 1) = v.y;
+        }
+    }
+    0
+}
+
+/// Fill the rectangle from `(xmin, ymin)` to `(xmax, ymax)` with tiles, writing up to `capacity`
+/// of them to `tiles`, and return how many tiles cover the rectangle. If that is more than
+/// `capacity`, call again with a larger buffer; a null buffer just counts them.
+///
+/// # Safety
+///
+/// `tiling` must be null or a live tiling from [`ih_tiling_new`], and `tiles` null or valid for
+/// writing `capacity` tiles.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ih_tiling_fill(
+    tiling: *const IsohedralTiling,
+    xmin: f32,
+    ymin: f32,
+    xmax: f32,
+    ymax: f32,
+    tiles: *mut IhTile,
+    capacity: usize,
+) -> usize {
+    // SAFETY: the caller passes null or a live tiling.
+    let Some(tiling) = (unsafe { tiling.as_ref() }) else {
+        return 0;
+    };
+    let capacity = if tiles.is_null() { 0 } else { capacity };
+    let mut count = 0;
+    for tile in tiling.fill_region(xmin, ymin, xmax, ymax) {
+        if count < capacity {
+            let transform = tile.transform();
+            let (m, d) = (transform.matrix2, transform.translation);
+            let out = IhTile {
+                t1: tile.t1() as i32,
+                t2: tile.t2() as i32,
+                aspect: tile.aspect() as u32,
+                colour: tile.colour(tiling) as u32,
+                matrix: [m.x_axis.x, m.y_axis.x, d.x, m.x_axis.y, m.y_axis.y, d.y],
+            };
+            // SAFETY: `count` is within the caller's `capacity`.
+            unsafe { tiles.add(count).write(out) };
+        }
+        count += 1;
+    }
+    count
+}
@@ -0,0 +1,208 @@
+//! Edge shapes from font glyphs, for typographic tilings.
+//!
+//! Enabled by the `glyphs` feature. Glyph outlines are read with [ttf-parser], flattened into
+//! polylines and fitted to the edge frame running from `(0, 0)` to `(1, 0)`. The outlines of a
+//! whole line of text can also be set as a [`Mask`], to tile the inside of the letters.
+//!
+//! [ttf-parser]: https://docs.rs/ttf-parser
+use ttf_parser::{Face, OutlineBuilder};
+
+use crate::edges::resample;
+use crate::error::TilingError;
+use crate::mask::Mask;
+use crate::utils::{vec2, Vec2};
+
+
+/// Number of line segments each Bézier curve of a glyph is flattened into.
+const CURVE_STEPS: usize = 8;
+
+/// Largest distance of a fitted edge from its chord, relative to the chord's length. Taller
+/// glyphs are scaled down to it.
+const MAX_HEIGHT: f32 = 0.5;
+
+/// Space left at both ends of the chord by [`GlyphFit::Outline`], relative to its length.
+const OUTLINE_MARGIN: f32 = 0.1;
+
+/// How a glyph is fitted to an edge.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GlyphFit {
+    /// The top of the glyph, seen from above, as a profile running along the edge.
+    #[default]
+    TopContour,
+    /// The glyph's largest contour, sitting on the middle of the edge.
+    Outline,
+}
+
+/// Fit the outline of character `c` from the TrueType or OpenType `font` to an edge, as `count`
+/// points evenly spaced along it.
+///
+/// Glyphs are read with their top up, so they bulge towards negative y in the edge frame, as on
+/// screen. The result doesn't respect any symmetry; project it with [`edges::project`] before
+/// using it on a U, S or I edge.
+///
+/// [`edges::project`]: crate::edges::project
+pub fn glyph_edge(font: &[u8], c: char, fit: GlyphFit, count: usize) -> Result<Vec<Vec2>, TilingError> {
+    let face = Face::parse(font, 0).map_err(|_| TilingError::FontParsing)?;
+    let glyph = face.glyph_index(c).ok_or(TilingError::MissingGlyph(c))?;
+    let mut contours = Contours::default();
+    face.outline_glyph(glyph, &mut contours).ok_or(TilingError::MissingGlyph(c))?;
+    contours.finish();
+    let contours = contours.contours;
+
+    let (lo, hi) = contours.iter().flatten().fold((vec2(f32::MAX, f32::MAX), vec2(f32::MIN, f32::MIN)), |(lo, hi), p| {
+        (vec2(lo.x.min(p.x), lo.y.min(p.y)), vec2(hi.x.max(p.x), hi.y.max(p.y)))
+    });
+    let width = hi.x - lo.x;
+    if contours.is_empty() || width <= 0. {
+        return Err(TilingError::MissingGlyph(c));
+    }
+
+    let path = match fit {
+        GlyphFit::TopContour => top_contour(&contours, lo.x, width, count.max(2)),
+        GlyphFit::Outline => outline(&contours),
+    };
+    let mut edge = resample(&path, count.max(2));
+
+    let height = edge.iter().fold(0_f32, |height, p| height.max(p.y.abs()));
+    if height > MAX_HEIGHT {
+        for p in &mut edge {
+            p.y *= MAX_HEIGHT / height;
+        }
+    }
+    Ok(edge)
+}
+
+/// The outlines of `text` set in the TrueType or OpenType `font`, as a mask in font units with y
+/// pointing down and the first baseline at `y = 0`.
+///
+/// Glyphs are placed by their advance widths, without kerning, and lines are broken at newlines.
+/// Characters the font has no glyph for are reported; those without an outline, like spaces, just
+/// take up room.
+pub fn text_mask(font: &[u8], text: &str) -> Result<Mask, TilingError> {
+    let face = Face::parse(font, 0).map_err(|_| TilingError::FontParsing)?;
+    let line_height = f32::from(face.height()) + f32::from(face.line_gap());
+    let mut loops = vec![];
+    for (row, line) in text.lines().enumerate() {
+        let mut pen = vec2(0., row as f32 * line_height);
+        for c in line.chars() {
+            let glyph = face.glyph_index(c).ok_or(TilingError::MissingGlyph(c))?;
+            let mut contours = Contours::default();
+            if face.outline_glyph(glyph, &mut contours).is_some() {
+                contours.finish();
+                loops.extend(contours.contours.into_iter().map(|contour| contour.into_iter().map(|p| p + pen).collect::<Vec<_>>()));
+            }
+            pen.x += f32::from(face.glyph_hor_advance(glyph).unwrap_or(0));
+        }
+    }
+    if loops.is_empty() {
+        return Err(TilingError::EmptyText);
+    }
+    Ok(Mask::new(loops))
+}
+
+/// The highest point of the glyph above each of `count` evenly spaced columns, levelled so both
+/// ends lie on the chord.
+fn top_contour(contours: &[Vec<Vec2>], left: f32, width: f32, count: usize) -> Vec<Vec2> {
+    // Stay just inside the glyph at both ends, where the outline is vertical or a single point.
+    let inset = width * 1e-3;
+    let mut profile: Vec<Vec2> = (0..count)
+        .map(|k| {
+            let x = left + inset + (width - 2. * inset) * k as f32 / (count - 1) as f32;
+            let top = contours
+                .iter()
+                .flat_map(|contour| contour.iter().zip(contour.iter().cycle().skip(1)))
+                .filter(|(a, b)| (a.x <= x) != (b.x <= x))
+                .map(|(a, b)| a.y + (x - a.x) / (b.x - a.x) * (b.y - a.y))
+                .fold(f32::MAX, f32::min);
+            vec2((x - left) / width, if top == f32::MAX { 0. } else { top / width })
+        })
+        .collect();
+
+    let (first, last) = (profile[0].y, profile[count - 1].y);
+    for p in &mut profile {
+        p.y -= first + (last - first) * p.x;
+    }
+    profile[0] = Vec2::ZERO;
+    profile[count - 1] = Vec2::X;
+    profile
+}
+
+/// The chord, with a detour around the largest contour starting and ending at its lowest point.
+/// The contour is scaled uniformly to fit between the margins and under [`MAX_HEIGHT`].
+fn outline(contours: &[Vec<Vec2>]) -> Vec<Vec2> {
+    let area = |contour: &Vec<Vec2>| {
+        let n = contour.len();
+        (0..n).map(|i| contour[i].perp_dot(contour[(i + 1) % n])).sum::<f32>().abs()
+    };
+    let Some(contour) = contours.iter().max_by(|a, b| area(a).total_cmp(&area(b))) else {
+        return vec![Vec2::ZERO, Vec2::X];
+    };
+    let start = (0..contour.len())
+        .max_by(|&i, &j| contour[i].y.total_cmp(&contour[j].y).then(contour[j].x.total_cmp(&contour[i].x)))
+        .unwrap_or(0);
+
+    let (lo, hi) = contour.iter().fold((contour[0], contour[0]), |(lo, hi), p| {
+        (vec2(lo.x.min(p.x), lo.y.min(p.y)), vec2(hi.x.max(p.x), hi.y.max(p.y)))
+    });
+    let scale = ((1. - 2. * OUTLINE_MARGIN) / (hi.x - lo.x)).min(MAX_HEIGHT / (hi.y - lo.y).max(f32::EPSILON));
+    let (centre, base) = ((lo.x + hi.x) / 2., contour[start].y);
+    let to_edge = |p: Vec2| vec2(0.5 + (p.x - centre) * scale, (p.y - base) * scale);
+
+    let mut path = vec![Vec2::ZERO];
+    path.extend((0..=contour.len()).map(|k| to_edge(contour[(start + k) % contour.len()])));
+    path.push(Vec2::X);
+    path
+}
+
+/// Collects the contours of a glyph as polylines, with y pointing down.
+#[derive(Default)]
+struct Contours {
+    contours: Vec<Vec<Vec2>>,
+    current: Vec<Vec2>,
+}
+
+impl Contours {
+    fn finish(&mut self) {
+        if self.current.len() > 2 {
+            self.contours.push(std::mem::take(&mut self.current));
+        }
+        self.current.clear();
+    }
+
+    fn last(&self) -> Vec2 {
+        self.current.last().copied().unwrap_or(Vec2::ZERO)
+    }
+}
+
+impl OutlineBuilder for Contours {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.finish();
+        self.current.push(vec2(x, -y));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.current.push(vec2(x, -y));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (p0, p1, p2) = (self.last(), vec2(x1, -y1), vec2(x, -y));
+        for s in 1..=CURVE_STEPS {
+            let t = s as f32 / CURVE_STEPS as f32;
+            let u = 1. - t;
+            self.current.push(p0 * (u * u) + p1 * (2. * u * t) + p2 * (t * t));
+        }
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (p0, p1, p2, p3) = (self.last(), vec2(x1, -y1), vec2(x2, -y2), vec2(x, -y));
+        for s in 1..=CURVE_STEPS {
+            let t = s as f32 / CURVE_STEPS as f32;
+            let u = 1. - t;
+            self.current.push(p0 * (u * u * u) + p1 * (3. * u * u * t) + p2 * (3. * u * t * t) + p3 * (t * t * t));
+        }
+    }
+
+    fn close(&mut self) {
+        self.finish();
+    }
+}
@@ -0,0 +1,109 @@
+//! Procedural fill patterns: hatching, crosshatching and dots clipped to the tile outlines, for a
+//! print-friendly monochrome look.
+//!
+//! Patterns are laid out in world space rather than per tile, so the lines of neighbouring tiles
+//! with the same pattern line up across their shared edge.
+use crate::utils::{contains, vec2, Vec2};
+
+
+/// How the tiles of a colour class are filled.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Pattern {
+    /// A flat fill in the class colour.
+    #[default]
+    Solid,
+    /// Parallel lines `spacing` world units apart, at `angle` radians from the x axis.
+    Hatch { angle: f32, spacing: f32 },
+    /// Two sets of hatching at right angles.
+    Crosshatch { angle: f32, spacing: f32 },
+    /// Dots on a square grid `spacing` world units apart, with a radius of a fifth of the spacing.
+    Dots { spacing: f32 },
+}
+
+impl Pattern {
+    /// Whether the pattern is drawn as marks on a plain background, rather than as a flat fill.
+    pub fn is_marked(self) -> bool {
+        self != Pattern::Solid
+    }
+
+    /// The lines of the pattern inside the closed polygon `outline`, as pairs of end points.
+    pub fn lines(self, outline: &[Vec2]) -> Vec<[Vec2; 2]> {
+        match self {
+            Pattern::Hatch { angle, spacing } => hatch(outline, angle, spacing),
+            Pattern::Crosshatch { angle, spacing } => {
+                let mut lines = hatch(outline, angle, spacing);
+                lines.extend(hatch(outline, angle + std::f32::consts::FRAC_PI_2, spacing));
+                lines
+            }
+            Pattern::Solid | Pattern::Dots { .. } => vec![],
+        }
+    }
+
+    /// The centres and the radius of the dots of the pattern inside the closed polygon `outline`.
+    pub fn dots(self, outline: &[Vec2]) -> (Vec<Vec2>, f32) {
+        let Pattern::Dots { spacing } = self else {
+            return (vec![], 0.);
+        };
+        let Some((lo, hi)) = bounds(outline).filter(|_| spacing > 0.) else {
+            return (vec![], 0.);
+        };
+        let mut dots = vec![];
+        for i in (lo.x / spacing).ceil() as i32..=(hi.x / spacing).floor() as i32 {
+            for j in (lo.y / spacing).ceil() as i32..=(hi.y / spacing).floor() as i32 {
+                let p = vec2(i as f32 * spacing, j as f32 * spacing);
+                if contains(outline, p) {
+                    dots.push(p);
+                }
+            }
+        }
+        (dots, spacing / 5.)
+    }
+}
+
+/// The width of the pattern lines for outlines of `stroke_width`: half as wide, but at least one
+/// pixel so the patterns still show when the outlines are off.
+pub fn mark_width(stroke_width: f32) -> f32 {
+    (stroke_width / 2.).max(1.)
+}
+
+/// Lines at `angle` radians through the points whose distance from the origin across the lines is
+/// a multiple of `spacing`, clipped to the inside of the closed polygon `outline`.
+pub fn hatch(outline: &[Vec2], angle: f32, spacing: f32) -> Vec<[Vec2; 2]> {
+    let n = outline.len();
+    if n < 3 || spacing <= 0. {
+        return vec![];
+    }
+    let (sin, cos) = angle.sin_cos();
+    let (along, across) = (vec2(cos, sin), vec2(-sin, cos));
+    let (lo, hi) = outline.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), p| {
+        let d = p.dot(across);
+        (lo.min(d), hi.max(d))
+    });
+
+    let mut lines = vec![];
+    let mut crossings = vec![];
+    for k in (lo / spacing).ceil() as i32..=(hi / spacing).floor() as i32 {
+        let c = k as f32 * spacing;
+        // Where the line crosses the edges, half-open at the ends so vertices count once.
+        crossings.clear();
+        for i in 0..n {
+            let (a, b) = (outline[i], outline[(i + 1) % n]);
+            let (da, db) = (a.dot(across) - c, b.dot(across) - c);
+            if (da <= 0.) != (db <= 0.) {
+                let p = a + (b - a) * (da / (da - db));
+                crossings.push(p.dot(along));
+            }
+        }
+        crossings.sort_by(f32::total_cmp);
+        // Inside runs between every other pair of crossings.
+        for pair in crossings.chunks_exact(2) {
+            lines.push([along * pair[0] + across * c, along * pair[1] + across * c]);
+        }
+    }
+    lines
+}
+
+fn bounds(points: &[Vec2]) -> Option<(Vec2, Vec2)> {
+    let first = *points.first()?;
+    Some(points.iter().fold((first, first), |(lo, hi), p| (vec2(lo.x.min(p.x), lo.y.min(p.y)), vec2(hi.x.max(p.x), hi.y.max(p.y)))))
+}
@@ -0,0 +1,105 @@
+//! A strip of breadcrumbs through the designs explored, to click back to any of them.
+//!
+//! A breadcrumb, with a thumbnail, is kept whenever the design changes a lot from the one last
+//! kept or gone back to: another tiling type, a big move of the parameters, a new theme, or new
+//! edges all round as the random buttons make. Going back to a breadcrumb keeps the others, and
+//! the next change adds a new one at the end, so nothing explored is lost.
+use eframe::egui;
+use egui_isohedral::render;
+
+use crate::app::App;
+use crate::interface;
+use crate::project::Project;
+
+
+/// Number of breadcrumbs kept, the oldest going first.
+const MAX_BREADCRUMBS: usize = 32;
+
+/// How far the parameters must move, summed over all of them, to make a breadcrumb.
+const BIG_CHANGE: f32 = 0.2;
+
+/// Width and height of a breadcrumb's thumbnail in pixels.
+const BREADCRUMB_SIZE: u32 = 64;
+
+struct Breadcrumb {
+    project: Project,
+    texture: egui::TextureHandle,
+}
+
+/// State of the history strip.
+#[derive(Default)]
+pub struct History {
+    pub open: bool,
+    breadcrumbs: Vec<Breadcrumb>,
+    /// The breadcrumb last kept or gone back to.
+    current: Option<usize>,
+}
+
+/// Whether `design` differs from `from` enough for a breadcrumb, see the [module docs](self).
+fn significant(from: &Project, design: &Project) -> bool {
+    let moved: f32 = from.params.iter().zip(&design.params).map(|(a, b)| (a - b).abs()).sum();
+    from.tiling_type != design.tiling_type
+        || moved > BIG_CHANGE
+        || from.theme != design.theme
+        || (!design.edges.is_empty() && from.edges.iter().zip(&design.edges).all(|(a, b)| a != b))
+}
+
+/// Keep a breadcrumb of the design if it changed a lot, once the pointer is let go so a drag
+/// gives one breadcrumb at its end. `screen` is the area the thumbnail is taken from the middle of.
+pub fn observe(app: &mut App, ctx: &egui::Context, screen: egui::Rect) {
+    if ctx.input(|i| i.pointer.any_down()) || app.timeline.is_playing() || app.recorder.is_replaying() {
+        return;
+    }
+    let design = Project { saved_themes: vec![], ..Project::from_app(app) };
+    let history = &app.history;
+    if history.current.is_some_and(|i| !significant(&history.breadcrumbs[i].project, &design)) {
+        return;
+    }
+
+    let rect = egui::Rect::from_center_size(screen.center(), egui::Vec2::splat(screen.width().min(screen.height()) / 3.));
+    let options = interface::export_options(app, rect).resized(BREADCRUMB_SIZE, BREADCRUMB_SIZE);
+    let Ok(image) = render::render(&app.tiling, &app.edges_shapes, &options) else {
+        return;
+    };
+    let size = [image.width() as usize, image.height() as usize];
+    let texture = ctx.load_texture("breadcrumb", egui::ColorImage::from_rgba_unmultiplied(size, &image.to_rgba8()), egui::TextureOptions::LINEAR);
+    let history = &mut app.history;
+    if history.breadcrumbs.len() == MAX_BREADCRUMBS {
+        history.breadcrumbs.remove(0);
+    }
+    history.breadcrumbs.push(Breadcrumb { project: design, texture });
+    history.current = Some(history.breadcrumbs.len() - 1);
+}
+
+/// Show the strip along the bottom of the window, if it is open.
+pub fn show(app: &mut App, ctx: &egui::Context) {
+    if !app.history.open {
+        return;
+    }
+    let mut back = None;
+    egui::TopBottomPanel::bottom("history").show(ctx, |ui| {
+        egui::ScrollArea::horizontal().stick_to_right(true).show(ui, |ui| {
+            ui.horizontal(|ui| {
+                let history = &app.history;
+                for (i, breadcrumb) in history.breadcrumbs.iter().enumerate() {
+                    let image = egui::Image::new(&breadcrumb.texture).fit_to_exact_size(egui::Vec2::splat(BREADCRUMB_SIZE as f32));
+                    let button = egui::Button::image(image).selected(history.current == Some(i));
+                    let hover = format!("IH{:02}, {} colours", breadcrumb.project.tiling_type, breadcrumb.project.colours);
+                    if ui.add(button).on_hover_text(hover).clicked() {
+                        back = Some(i);
+                    }
+                }
+                if history.breadcrumbs.is_empty() {
+                    ui.weak("Designs you explore appear here.");
+                }
+            });
+        });
+    });
+    if let Some(i) = back {
+        let project = app.history.breadcrumbs[i].project.clone();
+        if let Err(err) = project.apply(app, ctx) {
+            app.status = format!("Couldn't go back: {err}");
+        }
+        app.history.current = Some(i);
+    }
+}
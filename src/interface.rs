@@ -1,82 +1,1216 @@
 
 use eframe::egui;
-use crate::app::App;
-use crate::{data::get_tiling_type, tiling::IsohedralTiling};
-use rand::{thread_rng, Rng};
-use egui_colors::utils;
+use crate::{app::{seeded, App, Roll, Selection}, cache::{Gap, TileKey}, clipboard, console, edge_editor, history, layers, measure, project::{self, Project}, recorder, reference::{self, Reference}, slideshow, tile_painter::{self, PaintContext, Rendering}, thumbnails::{thumbnail_path, THUMBNAIL_SIZE}, timeline, watch};
+use egui_isohedral::{colouring::MAX_COLOURS, edges, export::{self, contact_sheet::{self, ContactSheet}, pdf, wallpaper, ExportOptions, Rgba, Unit}, get_tiling_type, hatch::Pattern, mask::Mask, morph::Morph, palette::{self, Deficiency, Palette}, render::Blend, tessellate::{self, LineJoin}, tiling::IsohedralTiling, centroid, triangulate, view::ViewState, vec2, Affine2, Vec2, TILING_TYPES};
+#[cfg(feature = "glyphs")]
+use egui_isohedral::glyph;
+use rand::Rng;
 
 
-fn draw_isohedrals(app: &mut App, ctx: &egui::Context) {
+/// Scratch buffers reused by the draw loop, so a large fill doesn't allocate per tile.
+#[derive(Default)]
+pub struct DrawBuffers {
+    /// The outline of a tile in world space, on its way to `points` in screen points.
+    world: Vec<Vec2>,
+    points: Vec<Vec2>,
+    stroke: tessellate::Mesh,
+    vertices: usize,
+    indices: usize,
+}
+
+/// Text drawn at the centroid of every tile.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TileLabels {
+    #[default]
+    Off,
+    /// The lattice position `(t1, t2)` and the aspect.
+    Lattice,
+    /// The colour index.
+    Colour,
+}
+
+/// Which tiles have arrows along their edges, see [`draw_edge_arrows`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeArrows {
+    #[default]
+    Off,
+    /// The tile nearest the middle of the view.
+    Prototile,
+    /// Every visible tile.
+    All,
+}
+
+/// How the dual tiling, the graph joining the centres of neighbouring tiles, is drawn.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DualOverlay {
+    #[default]
+    Off,
+    /// On top of the tiles.
+    Over,
+    /// Alone, with the tiles hidden.
+    Instead,
+}
+
+/// Fill colours of the colour classes, enough for any colouring.
+pub fn tile_colours(app: &App) -> [egui::Color32; MAX_COLOURS] {
+    if app.colouring_book {
+        return [egui::Color32::WHITE; MAX_COLOURS];
+    }
+    if app.high_contrast {
+        return app.contrast_cache.colours();
+    }
+    if let Some([_, classes @ ..]) = app.image_colours.as_deref()
+        && !classes.is_empty()
+    {
+        return std::array::from_fn(|i| classes[i % classes.len()]);
+    }
+    if let Some(palette) = app.palette {
+        let colours = palette.colours(app.tiling.num_colours().max(1));
+        return std::array::from_fn(|i| {
+            let [r, g, b, a] = colours[i % colours.len()];
+            egui::Color32::from_rgba_unmultiplied(r, g, b, a)
+        });
+    }
     let tokens = app.colorix.animator.animated_tokens;
-    let rect = ctx.screen_rect();
-    let layer_id = egui::LayerId::background();
-    let painter = egui::Painter::new(ctx.clone(), layer_id, rect);
-    let colors = [tokens.active_ui_element_background(), tokens.solid_backgrounds(), tokens.hovered_ui_element_border()];
-    let stroke = egui::Stroke::new(3., tokens.low_contrast_text());
+    [
+        tokens.active_ui_element_background(),
+        tokens.solid_backgrounds(),
+        tokens.hovered_ui_element_border(),
+        tokens.ui_element_background(),
+        tokens.hovered_solid_backgrounds(),
+        tokens.ui_element_border_and_focus_rings(),
+        tokens.subtle_borders_and_separators(),
+        tokens.hovered_ui_element_background(),
+    ]
+}
+
+/// Width of the outlines in high-contrast mode, in screen points.
+const HIGH_CONTRAST_WIDTH: f32 = 5.;
+
+fn tile_stroke(app: &App) -> egui::Stroke {
+    if app.colouring_book {
+        return egui::Stroke::new(app.line_weight, egui::Color32::BLACK);
+    }
+    if app.high_contrast {
+        let [r, g, b, a] = palette::HIGH_CONTRAST_STROKE;
+        return egui::Stroke::new(HIGH_CONTRAST_WIDTH, egui::Color32::from_rgba_unmultiplied(r, g, b, a));
+    }
+    egui::Stroke::new(3., app.colorix.animator.animated_tokens.low_contrast_text())
+}
+
+/// The colour behind the tiles, on screen and in the exports.
+pub fn canvas_background(app: &App) -> egui::Color32 {
+    if app.colouring_book {
+        egui::Color32::WHITE
+    } else if app.high_contrast {
+        egui::Color32::BLACK
+    } else if let Some(&[background, ..]) = app.image_colours.as_deref() {
+        background
+    } else {
+        app.colorix.animator.animated_tokens.app_background()
+    }
+}
+
+/// `c` as seen with the colour vision deficiency previewed on the canvas, if any.
+fn simulated(app: &App, c: egui::Color32) -> egui::Color32 {
+    match app.simulation {
+        Some(deficiency) => {
+            let [r, g, b, a] = deficiency.simulate(c.to_srgba_unmultiplied());
+            egui::Color32::from_rgba_unmultiplied(r, g, b, a)
+        }
+        None => c,
+    }
+}
+
+/// Width of the anti-aliasing fringe of the tile meshes in screen points, as set by the
+/// tessellation options: egui only feathers the shapes it tessellates itself.
+fn feathering(ctx: &egui::Context) -> f32 {
+    let options = ctx.tessellation_options(|options| *options);
+    if options.feathering { options.feathering_size_in_pixels / ctx.pixels_per_point() } else { 0. }
+}
+
+/// Export settings reproducing what is currently drawn in `rect`, at the physical size if one is
+/// set.
+pub fn export_options(app: &App, rect: egui::Rect) -> ExportOptions {
+    let origin = app.view.to_world(rect.min);
+    let stroke = tile_stroke(app);
+    let options = ExportOptions {
+        width: rect.width().round() as u32,
+        height: rect.height().round() as u32,
+        origin,
+        scale: app.view.scale,
+        colours: tile_colours(app).map(|c| c.to_srgba_unmultiplied()).to_vec(),
+        background: canvas_background(app).to_srgba_unmultiplied(),
+        patterns: app.patterns.to_vec(),
+        mask: app.mask.clone(),
+        stroke_colour: stroke.color.to_srgba_unmultiplied(),
+        stroke_width: stroke.width,
+        segments: app.export_segments,
+        physical: None,
+        clip: app.clip_exports,
+    };
+    if app.physical_units { options.with_physical(app.physical) } else { options }
+}
+
+/// The path of a file called `file_name` in the chosen folder, or in the working directory if
+/// none is chosen. Relative and absolute paths are accepted as well.
+fn in_folder(app: &App, file_name: &str) -> std::path::PathBuf {
+    std::path::Path::new(&app.folder).join(file_name)
+}
+
+/// Record and replay the design and view, see [`recorder`].
+fn recorder_section(app: &mut App, ui: &mut egui::Ui, ctx: &egui::Context) {
+    ui.horizontal(|ui| {
+        if app.recorder.is_recording() {
+            if ui.button("⏹ Stop recording").clicked() {
+                recorder::stop_recording(app);
+            }
+        } else if ui.add_enabled(!app.recorder.is_replaying(), egui::Button::new("⏺ Record")).clicked() {
+            recorder::start_recording(app, ctx);
+        }
+        if app.recorder.is_replaying() {
+            if ui.button("⏹ Stop replay").clicked() {
+                recorder::stop_replay(app);
+            }
+        } else if ui.add_enabled(app.recorder.duration() > 0. && !app.recorder.is_recording(), egui::Button::new("▶ Replay")).clicked() {
+            recorder::start_replay(app, ctx);
+        }
+        ui.label(format!("{:.1} s recorded", app.recorder.duration()));
+    });
+    ui.checkbox(&mut app.recorder.export_frames, "save frames")
+        .on_hover_text(format!("Save every frame of the replay into the folder, at {} frames per second", recorder::FRAME_RATE));
+    if ui.add_enabled(app.recorder.duration() > 0., egui::Button::new("Save session")).clicked() {
+        let text = app.recorder.to_text();
+        save_export(app, &format!("isohedral.{}", recorder::SESSION_EXTENSION), text.as_bytes());
+    }
+}
+
+/// The range of the tiles across slider.
+const TILES_ACROSS_RANGE: std::ops::RangeInclusive<f32> = 1.0..=200.0;
+
+/// Tiles fitting across the window when the app starts.
+const DEFAULT_TILES_ACROSS: f32 = 12.;
+
+/// A slider with a box for typing its exact value next to it, showing `decimals` decimals. The
+/// label can be clicked too.
+fn fine_slider(ui: &mut egui::Ui, value: &mut f32, range: std::ops::RangeInclusive<f32>, text: impl Into<egui::WidgetText>, decimals: usize) -> egui::Response {
+    ui.horizontal(|ui| {
+        let slider = ui.add(egui::Slider::new(value, range.clone()).show_value(false));
+        let exact = ui.add(egui::DragValue::new(value).range(range).speed(10_f64.powi(-(decimals as i32))).fixed_decimals(decimals));
+        let label = ui.add(egui::Label::new(text).sense(egui::Sense::click()));
+        slider | exact | label
+    })
+    .inner
+}
+
+/// How far the arrow keys nudge the selection, in parameter or edge units. Shift makes the steps
+/// ten times larger, Ctrl ten times finer.
+const NUDGE_STEP: f32 = 0.01;
+
+/// Nudge the selected parameter or edge point with the arrow keys, unless a widget has the
+/// keyboard. Escape clears the selection.
+fn nudge(app: &mut App, ctx: &egui::Context) {
+    let Some(selection) = app.selection else {
+        return;
+    };
+    if ctx.memory(|memory| memory.focused().is_some()) {
+        return;
+    }
+    let (right, down, escape, modifiers) = ctx.input(|i| {
+        let axis = |minus, plus| i.key_pressed(plus) as i32 - i.key_pressed(minus) as i32;
+        (axis(egui::Key::ArrowLeft, egui::Key::ArrowRight), axis(egui::Key::ArrowUp, egui::Key::ArrowDown), i.key_pressed(egui::Key::Escape), i.modifiers)
+    });
+    if escape {
+        app.selection = None;
+        return;
+    }
+    if right == 0 && down == 0 {
+        return;
+    }
+    let step = NUDGE_STEP * if modifiers.shift { 10. } else if modifiers.command { 0.1 } else { 1. };
+    match selection {
+        Selection::Param(i) if i < app.tiling.num_params() => {
+            app.params[i] = (app.params[i] + (right - down) as f32 * step).clamp(0., 1.);
+            app.tiling.set_parameters(&app.params);
+        }
+        Selection::Param(_) => app.selection = None,
+        Selection::EdgePoint { edge, point } => edge_editor::nudge_point(app, edge, point, vec2(right as f32, down as f32) * step),
+    }
+}
+
+/// The things the app can save, see [`run_export`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportKind {
+    Project,
+    Png,
+    P5,
+    Svg,
+    Texture,
+    ContactSheet,
+    Tikz,
+    Csv,
+    Prototile,
+    Figure,
+    Print,
+}
+
+impl ExportKind {
+    pub const ALL: [ExportKind; 11] = [
+        ExportKind::Project,
+        ExportKind::Png,
+        ExportKind::P5,
+        ExportKind::Svg,
+        ExportKind::Texture,
+        ExportKind::ContactSheet,
+        ExportKind::Tikz,
+        ExportKind::Csv,
+        ExportKind::Prototile,
+        ExportKind::Figure,
+        ExportKind::Print,
+    ];
+
+    /// A short name, as typed in the console.
+    pub fn name(self) -> &'static str {
+        match self {
+            ExportKind::Project => "project",
+            ExportKind::Png => "png",
+            ExportKind::P5 => "p5",
+            ExportKind::Svg => "svg",
+            ExportKind::Texture => "texture",
+            ExportKind::ContactSheet => "sheet",
+            ExportKind::Tikz => "tikz",
+            ExportKind::Csv => "csv",
+            ExportKind::Prototile => "prototile",
+            ExportKind::Figure => "figure",
+            ExportKind::Print => "print",
+        }
+    }
+}
+
+/// Save the visible region of the tiling, or the whole design for a project, into the chosen
+/// folder and report the outcome in the status line.
+pub fn run_export(app: &mut App, screen: egui::Rect, kind: ExportKind) {
+    let options = export_options(app, screen);
+    match kind {
+        ExportKind::Project => {
+            let file_name = format!("isohedral.{}", project::PROJECT_EXTENSION);
+            let text = Project::from_app(app).to_text();
+            save_export(app, &file_name, text.as_bytes());
+            if app.status.starts_with("Saved") {
+                let path = in_folder(app, &file_name);
+                app.recent.add(&path);
+                if let Err(err) = save_thumbnail(app, screen, &path) {
+                    app.status.push_str(&format!(", but couldn't save its thumbnail: {err}"));
+                }
+            }
+        }
+        ExportKind::Png => export_png(app, screen, "isohedral.png"),
+        ExportKind::P5 => {
+            let sketch = export::p5::sketch(&app.tiling, &app.edges_shapes, &options);
+            save_export(app, "isohedral.js", sketch.as_bytes());
+        }
+        ExportKind::Svg => {
+            let svg = export::svg::pattern(&app.tiling, &app.edges_shapes, &options);
+            save_export(app, "isohedral_pattern.svg", svg.as_bytes());
+        }
+        ExportKind::Texture => export_texture(app, &options),
+        ExportKind::ContactSheet => export_contact_sheet(app, &options),
+        ExportKind::Tikz => {
+            let physical_width = options.physical_size().map(|(width, _, unit)| format!("{width:.3}{}", unit.suffix()));
+            let width = physical_width.as_deref().unwrap_or(&app.tikz_width);
+            let picture = export::tikz::picture(&app.tiling, &app.edges_shapes, &options, width);
+            save_export(app, "isohedral.tex", picture.as_bytes());
+        }
+        ExportKind::Csv => {
+            let table = export::csv::vertices(&app.tiling, &app.edges_shapes, &options);
+            save_export(app, "isohedral_vertices.csv", table.as_bytes());
+        }
+        ExportKind::Prototile => {
+            let drawing = export::prototile::drawing(&app.tiling, &app.edges_shapes, &options.with_physical(app.physical), app.dimensions);
+            save_export(app, "isohedral_prototile.svg", drawing.as_bytes());
+        }
+        ExportKind::Figure => {
+            let figure = export::figure::figure(&app.tiling, &app.edges_shapes, &options, &app.figure);
+            save_export(app, "isohedral_figure.svg", figure.as_bytes());
+        }
+        ExportKind::Print => print(app, &options.with_physical(app.physical)),
+    }
+}
+
+/// Save the view as a PNG image called `file_name` and report the outcome.
+pub fn export_png(app: &mut App, screen: egui::Rect, file_name: &str) {
+    let options = export_options(app, screen);
+    let image = egui_isohedral::render::render(&app.tiling, &app.edges_shapes, &options).and_then(|mut image| {
+        layers::composite(app, &options, &mut image)?;
+        image.encode_png()
+    });
+    match image {
+        Ok(png) => save_export(app, file_name, &png),
+        Err(err) => app.status = format!("Couldn't render {file_name}: {err}"),
+    }
+}
+
+/// Write an export into the chosen folder and report the outcome.
+pub fn save_export(app: &mut App, file_name: &str, contents: &[u8]) {
+    let path = in_folder(app, file_name);
+    app.status = match std::fs::write(&path, contents) {
+        Ok(()) => format!("Saved {}", path.display()),
+        Err(err) => format!("Couldn't save {}: {err}", path.display()),
+    };
+}
+
+/// Render the middle of the view into the thumbnail of the project saved at `project`.
+fn save_thumbnail(app: &mut App, screen: egui::Rect, project: &std::path::Path) -> Result<(), String> {
+    let rect = egui::Rect::from_center_size(screen.center(), egui::Vec2::splat(screen.width().min(screen.height()) / 2.));
+    let options = export_options(app, rect).resized(THUMBNAIL_SIZE, THUMBNAIL_SIZE);
+    let png = egui_isohedral::render::render(&app.tiling, &app.edges_shapes, &options)
+        .and_then(|image| image.encode_png())
+        .map_err(|err| err.to_string())?;
+    std::fs::write(thumbnail_path(project), png).map_err(|err| err.to_string())?;
+    app.thumbnails.forget(project);
+    Ok(())
+}
+
+/// Open a project, session, edge, font, SVG or PNG file, as dropped onto the window or picked in
+/// the Files section.
+///
+/// Projects replace the design, sessions the recording, edge files replace the edge selected in the edge editor and fonts
+/// are used for glyph edges. SVG files mask the tiling, and the main colours of PNG images are
+/// offered in the Style section. The file's folder becomes the one files are saved to.
+fn open_file(app: &mut App, ctx: &egui::Context, name: &str, path: Option<&std::path::Path>, contents: Result<Vec<u8>, String>) {
+    let extension = name.rsplit_once('.').map(|(_, extension)| extension.to_ascii_lowercase()).unwrap_or_default();
+    let text = || contents.clone().and_then(|bytes| String::from_utf8(bytes).map_err(|_| String::from("not a text file")));
+    app.status = match extension.as_str() {
+        project::PROJECT_EXTENSION => match text().and_then(|text| Project::parse(&text)).and_then(|project| project.apply(app, ctx)) {
+            Ok(()) => {
+                if let Some(path) = path {
+                    app.recent.add(path);
+                }
+                format!("Opened {name}")
+            }
+            Err(err) => format!("Couldn't open {name}: {err}"),
+        },
+        recorder::SESSION_EXTENSION => match text().and_then(|text| app.recorder.parse(&text)) {
+            Ok(()) => format!("Opened {name}, replay it in the Recorder section"),
+            Err(err) => format!("Couldn't open {name}: {err}"),
+        },
+        "json" => match text().and_then(|text| project::parse_edge(&text)).map(|edge| edge_editor::replace_selected(app, &edge)) {
+            Ok(Some(i)) => format!("Replaced edge {i} with {name}"),
+            Ok(None) => String::from("The tiling has no edge to replace"),
+            Err(err) => format!("Couldn't open {name}: {err}"),
+        },
+        "png" => match contents.as_deref().map_err(Clone::clone).and_then(decode_png) {
+            Ok((pixels, size)) => {
+                let colours = palette::dominant_colours(&pixels, app.tiling.num_colours() + 1);
+                app.extracted = colours.iter().map(|&([r, g, b, a], _)| egui::Color32::from_rgba_unmultiplied(r, g, b, a)).collect();
+                let file_name = path.and_then(std::path::Path::file_name).map_or_else(|| name.to_owned(), |name| name.to_string_lossy().into_owned());
+                app.reference = Some(Reference::new(app, &file_name, &pixels, size, ctx.screen_rect()));
+                format!("Found {} colours in {name}, apply them in the Style section or trace the image in the Reference image section", app.extracted.len())
+            }
+            Err(err) => format!("Couldn't open {name}: {err}"),
+        },
+        "svg" => match text().and_then(|text| Mask::from_svg(&text).map_err(|err| err.to_string())) {
+            Ok(mask) => {
+                set_mask(app, &mask, ctx.screen_rect());
+                format!("Masked the tiling to {name}")
+            }
+            Err(err) => format!("Couldn't open {name}: {err}"),
+        },
+        #[cfg(feature = "glyphs")]
+        "ttf" | "otf" => match path {
+            Some(path) => {
+                edge_editor::set_font(app, &path.display().to_string());
+                format!("Using {name} for glyph edges")
+            }
+            None => format!("Couldn't open {name}: fonts must be opened from the file system"),
+        },
+        _ => format!("Couldn't open {name}: unknown kind of file"),
+    };
+
+    if let Some(folder) = path.and_then(std::path::Path::parent) {
+        app.folder = folder.display().to_string();
+    }
+}
+
+/// The on-screen frame of the wallpaper preset: the largest rectangle of its aspect ratio that fits
+/// in `screen` with some room around it.
+fn wallpaper_frame(preset: &wallpaper::Preset, screen: egui::Rect) -> egui::Rect {
+    let available = screen.shrink(40.);
+    let size = if available.aspect_ratio() > preset.aspect_ratio() {
+        egui::vec2(available.height() * preset.aspect_ratio(), available.height())
+    } else {
+        egui::vec2(available.width(), available.width() / preset.aspect_ratio())
+    };
+    egui::Rect::from_center_size(available.center(), size)
+}
 
-    painter.extend(app.tiling.fill_region(-2., -2., 20., 20.).iter().map(|tile| {
-        let c = colors[app.tiling.colour(tile.t1, tile.t2, tile.aspect)];
-        let mut points = vec![];
+/// Show the world position under the cursor, its lattice coordinates and the tile containing it.
+fn draw_cursor_readout(app: &App, ctx: &egui::Context) {
+    let Some(pos) = ctx.pointer_hover_pos() else {
+        return;
+    };
+    let world = app.view.to_world(pos);
+    let lattice = app.tiling.lattice_coords(world);
+    let mut text = format!("x {:8.3}  y {:8.3}  lattice {:7.2}, {:7.2}", world.x, world.y, lattice.x, lattice.y);
+    if let Some(tile) = app.view.tile_at(&app.tiling, app.outline_cache.outline(), pos) {
+        text += &format!("  tile {}, {} #{}", tile.t1(), tile.t2(), tile.aspect());
+    }
+    egui::Area::new(egui::Id::new("cursor readout"))
+        .anchor(egui::Align2::LEFT_BOTTOM, egui::vec2(8., -8.))
+        .interactable(false)
+        .show(ctx, |ui| ui.label(egui::RichText::new(text).monospace()));
+}
 
-        app.tiling.shapes().into_iter().for_each(|e| {
-            let edge = &app.edges_shapes[e.id()];
-            let transform = tile.transform * e.transform();
-            let p1 = transform.transform_point2(edge[0]);
-            let p2 = transform.transform_point2(edge[1]);
-            let point1 = egui::pos2(p1.x as f32 * 100., p1.y as f32 * 100.);
-            let point2 = egui::pos2(p2.x as f32 * 100., p2.y as f32 * 100.);
+/// The pixels of a PNG file as straight-alpha RGBA, and its width and height.
+fn decode_png(bytes: &[u8]) -> Result<(Vec<Rgba>, [usize; 2]), String> {
+    let mut decoder = png::Decoder::new(bytes);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().map_err(|err| err.to_string())?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer).map_err(|err| err.to_string())?;
+    let bytes = &buffer[..info.buffer_size()];
+    let pixels = match info.color_type {
+        png::ColorType::Rgba => bytes.chunks_exact(4).map(|p| [p[0], p[1], p[2], p[3]]).collect(),
+        png::ColorType::Rgb => bytes.chunks_exact(3).map(|p| [p[0], p[1], p[2], 0xff]).collect(),
+        png::ColorType::GrayscaleAlpha => bytes.chunks_exact(2).map(|p| [p[0], p[0], p[0], p[1]]).collect(),
+        png::ColorType::Grayscale => bytes.iter().map(|&v| [v, v, v, 0xff]).collect(),
+        png::ColorType::Indexed => return Err(String::from("unsupported colour type")),
+    };
+    Ok((pixels, [info.width as usize, info.height as usize]))
+}
 
-            if points.len() < 1 {
-                points.push(point1)
+/// Mask the tiling to `mask`, fitted into the middle of the view.
+fn set_mask(app: &mut App, mask: &Mask, screen: egui::Rect) {
+    let size = 0.8 * screen.width().min(screen.height()) / app.view.scale;
+    app.mask = Some(mask.placed(app.view.to_world(screen.center()), size));
+}
+
+/// Open the files dropped onto the window, see [`open_file`].
+fn handle_dropped_files(app: &mut App, ctx: &egui::Context) {
+    if ctx.input(|i| !i.raw.hovered_files.is_empty()) {
+        egui::Area::new(egui::Id::new("drop hint"))
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .interactable(false)
+            .show(ctx, |ui| ui.heading("Drop a project, session, edge, font, SVG mask or PNG image file to open it"));
+    }
+
+    for file in ctx.input(|i| i.raw.dropped_files.clone()) {
+        let name = file.path.as_ref().map_or_else(|| file.name.clone(), |path| path.display().to_string());
+        let contents = match (&file.bytes, &file.path) {
+            (Some(bytes), _) => Ok(bytes.to_vec()),
+            (None, Some(path)) => std::fs::read(path).map_err(|err| err.to_string()),
+            (None, None) => Err(String::from("the file has no contents")),
+        };
+        open_file(app, ctx, &name, file.path.as_deref(), contents);
+    }
+}
+
+fn draw_tile_labels(app: &App, painter: &egui::Painter) {
+    let centroid = app.outline_cache.centroid();
+    let font = egui::FontId::monospace(11.);
+    let colour = app.colorix.animator.animated_tokens.high_contrast_text();
+    for (&(t1, t2, aspect), transform) in app.tile_cache.tiles() {
+        let pos = app.view.to_screen(transform.transform_point2(centroid));
+        if !painter.clip_rect().contains(pos) {
+            continue;
+        }
+        let text = match app.tile_labels {
+            TileLabels::Off => continue,
+            TileLabels::Lattice => format!("{t1}, {t2}\n#{aspect}"),
+            TileLabels::Colour => app.tiling.colour(t1, t2, aspect).to_string(),
+        };
+        painter.text(pos, egui::Align2::CENTER_CENTER, text, font.clone(), colour);
+    }
+}
+
+/// The transform and the screen position of the centroid of the cached tile nearest the middle
+/// of `rect` among those `accepted`, if any has its centroid in `rect`.
+pub fn central_tile(app: &App, rect: egui::Rect, accepted: impl Fn(&TileKey) -> bool) -> Option<(Affine2, egui::Pos2)> {
+    let centroid = app.outline_cache.centroid();
+    app.tile_cache
+        .tiles()
+        .filter(|(key, _)| accepted(key))
+        .map(|(_, &transform)| (transform, app.view.to_screen(transform.transform_point2(centroid))))
+        .filter(|&(_, pos)| rect.contains(pos))
+        .min_by(|(_, a), (_, b)| a.distance_sq(rect.center()).total_cmp(&b.distance_sq(rect.center())))
+}
+
+/// The letter F in prototile space, as strokes from its centre, spanning one unit from the bottom
+/// of the stem to its top. Unlike most letters it has no symmetry, so every rotation and
+/// reflection of it looks different.
+const LETTER_F: [[Vec2; 2]; 3] = [
+    [vec2(-0.2, 0.5), vec2(-0.2, -0.5)],
+    [vec2(-0.2, -0.5), vec2(0.3, -0.5)],
+    [vec2(-0.2, -0.05), vec2(0.2, -0.05)],
+];
+
+/// Draw an F in the visible tile of each aspect nearest the middle of the view, transformed like
+/// the tile, so it shows how [`IsohedralTiling::aspect_transform`] turns or flips the prototile.
+fn draw_aspect_frames(app: &App, painter: &egui::Painter) {
+    let rect = painter.clip_rect();
+    let centroid = app.outline_cache.centroid();
+    let tiling = &app.tiling;
+    // About a third of the width of a tile.
+    let size = 0.35 * (tiling.t1().perp_dot(*tiling.t2()).abs() / tiling.num_aspects() as f32).sqrt();
+    let colour = app.colorix.animator.animated_tokens.high_contrast_text();
+    let stroke = egui::Stroke::new(3., colour);
+    let font = egui::FontId::monospace(11.);
+
+    for aspect in 0..tiling.num_aspects() {
+        let Some((transform, pos)) = central_tile(app, rect, |&(_, _, a)| a == aspect) else {
+            continue;
+        };
+        for line in LETTER_F {
+            let points = line.map(|p| app.view.to_screen(transform.transform_point2(centroid + p * size)));
+            painter.line_segment(points, stroke);
+        }
+        painter.text(pos + egui::vec2(0., size * app.view.scale * 0.6), egui::Align2::CENTER_TOP, format!("aspect {aspect}"), font.clone(), colour);
+    }
+}
+
+/// The edges of the dual tiling between the drawn tiles, within the mask if there is one.
+fn draw_dual(app: &App, painter: &egui::Painter) {
+    let inside = |p: Vec2| app.mask.as_ref().is_none_or(|mask| mask.contains(p));
+    for [a, b] in app.tiling.dual_edges(app.tile_cache.tiles().map(|(_, &transform)| transform)) {
+        if inside(a) && inside(b) {
+            painter.line_segment([app.view.to_screen(a), app.view.to_screen(b)], app.dual_stroke);
+        }
+    }
+}
+
+/// Length of the edge arrows in screen points.
+const EDGE_ARROW_LENGTH: f32 = 14.;
+
+/// The point halfway along `points` and the direction of the curve there.
+fn halfway(points: &[Vec2]) -> (Vec2, Vec2) {
+    let total: f32 = points.windows(2).map(|pair| (pair[1] - pair[0]).length()).sum();
+    let mut left = total / 2.;
+    for pair in points.windows(2) {
+        let length = (pair[1] - pair[0]).length();
+        if length >= left && length > 0. {
+            return (pair[0] + (pair[1] - pair[0]) * (left / length), (pair[1] - pair[0]) * (1. / length));
+        }
+        left -= length;
+    }
+    (points[0], Vec2::X)
+}
+
+/// Draw an arrow halfway along every edge of the tiles of [`App::edge_arrows`], pointing from the
+/// start of the edge shape to its end. Edges traversed backwards in the outline, as the tiling
+/// type's reversals and edge orientations make them, have their arrows in the selection colour.
+/// The arrows are pulled toward the middle of their tile, so shared edges show both tiles' arrows.
+fn draw_edge_arrows(app: &App, painter: &egui::Painter) {
+    let rect = painter.clip_rect();
+    let centroid = app.outline_cache.centroid();
+    let sampled = app.outline_cache.sampled();
+    let visuals = painter.ctx().style().visuals.clone();
+    let forward = egui::Stroke::new(2., visuals.strong_text_color());
+    let reversed = egui::Stroke::new(2., visuals.selection.bg_fill);
+    let transforms: Vec<Affine2> = match app.edge_arrows {
+        EdgeArrows::Off => return,
+        EdgeArrows::Prototile => central_tile(app, rect, |_| true).map(|(transform, _)| transform).into_iter().collect(),
+        EdgeArrows::All => app.tile_cache.tiles().map(|(_, &transform)| transform).collect(),
+    };
+    for transform in transforms {
+        let centre = app.view.to_screen(transform.transform_point2(centroid));
+        for shape in app.tiling.shapes() {
+            let Some(edge) = sampled.get(shape.id()).filter(|edge| edge.len() >= 2) else {
+                continue;
+            };
+            let t = transform * shape.transform();
+            let (middle, direction) = halfway(edge);
+            let start = app.view.to_screen(t.transform_point2(middle));
+            let pos = start + (centre - start) * 0.2;
+            if !rect.contains(pos) {
+                continue;
             }
-            if e.reversed() {
-                points.push(point1);
+            let ahead = app.view.to_screen(t.transform_point2(middle + direction * 0.01)) - start;
+            let arrow = ahead.normalized() * EDGE_ARROW_LENGTH;
+            painter.arrow(pos - arrow * 0.5, arrow, if shape.reversed() { reversed } else { forward });
+        }
+    }
+}
+
+/// Outline the edges reported by [`IsohedralTiling::check_edges`] on every visible tile.
+fn draw_edge_warnings(app: &App, painter: &egui::Painter) {
+    let problems = app.outline_cache.problems();
+    let sampled = app.outline_cache.sampled();
+    let stroke = egui::Stroke::new(3., painter.ctx().style().visuals.error_fg_color);
+    for transform in app.tile_cache.tiles().map(|(_, t)| *t) {
+        for shape in app.tiling.shapes().filter(|s| problems.iter().any(|&(id, _)| id == s.id())) {
+            let t = transform * shape.transform();
+            let points: Vec<egui::Pos2> = sampled[shape.id()]
+                .iter()
+                .map(|&p| app.view.to_screen(t.transform_point2(p)))
+                .collect();
+            if points.iter().any(|&p| painter.clip_rect().contains(p)) {
+                painter.line(points, stroke);
             }
-            else {
-                points.push(point2);
+        }
+    }
+}
+
+/// Dim everything outside the wallpaper frame and outline the area clear of system UI.
+fn draw_wallpaper_overlay(app: &App, painter: &egui::Painter) {
+    let preset = &wallpaper::PRESETS[app.wallpaper_preset];
+    let screen = painter.clip_rect();
+    let frame = wallpaper_frame(preset, screen);
+    let shade = egui::Color32::from_black_alpha(160);
+    for outside in [
+        egui::Rect::from_min_max(screen.min, egui::pos2(screen.max.x, frame.min.y)),
+        egui::Rect::from_min_max(egui::pos2(screen.min.x, frame.max.y), screen.max),
+        egui::Rect::from_min_max(egui::pos2(screen.min.x, frame.min.y), egui::pos2(frame.min.x, frame.max.y)),
+        egui::Rect::from_min_max(egui::pos2(frame.max.x, frame.min.y), egui::pos2(screen.max.x, frame.max.y)),
+    ] {
+        painter.rect_filled(outside, 0., shade);
+    }
+
+    let safe = frame.shrink2(egui::vec2(frame.width() * preset.safe_x, frame.height() * preset.safe_y));
+    let stroke = egui::Stroke::new(1.5, egui::Color32::WHITE);
+    painter.rect_stroke(frame, 0., stroke, egui::StrokeKind::Outside);
+    let corners = [safe.left_top(), safe.right_top(), safe.right_bottom(), safe.left_bottom(), safe.left_top()];
+    painter.extend(egui::Shape::dashed_line(&corners, stroke, 8., 6.));
+}
+
+/// Render the framed part of the view at the preset's resolution and save it as a PNG.
+fn export_wallpaper(app: &mut App, screen: egui::Rect) {
+    let preset = wallpaper::PRESETS[app.wallpaper_preset];
+    let options = export_options(app, wallpaper_frame(&preset, screen)).resized(preset.width, preset.height);
+    let file_name = format!("isohedral_{}x{}.png", preset.width, preset.height);
+    let png = match egui_isohedral::render::render(&app.tiling, &app.edges_shapes, &options).and_then(|image| image.encode_png()) {
+        Ok(png) => png,
+        Err(err) => {
+            app.status = format!("Couldn't render {file_name}: {err}");
+            return;
+        }
+    };
+    save_export(app, &file_name, &png);
+
+    #[cfg(feature = "set-wallpaper")]
+    if app.set_wallpaper && app.status.starts_with("Saved") {
+        app.status = match crate::desktop::set_wallpaper(&in_folder(app, &file_name)) {
+            Ok(()) => format!("Set {file_name} as the wallpaper"),
+            Err(err) => format!("Saved {file_name}, but couldn't set it as the wallpaper: {err}"),
+        };
+    }
+}
+
+/// Save a sample of every tiling type, in the current colours, as a PNG image and an SVG.
+fn export_contact_sheet(app: &mut App, options: &ExportOptions) {
+    let sheet = ContactSheet { style: options.clone(), ..ContactSheet::default() };
+    match egui_isohedral::render::contact_sheet(&sheet).and_then(|image| image.encode_png()) {
+        Ok(png) => save_export(app, "isohedral_contact_sheet.png", &png),
+        Err(err) => {
+            app.status = format!("Couldn't render isohedral_contact_sheet.png: {err}");
+            return;
+        }
+    }
+    if app.status.starts_with("Saved") {
+        save_export(app, "isohedral_contact_sheet.svg", contact_sheet::svg(&sheet).as_bytes());
+        if app.status.starts_with("Saved") {
+            app.status = format!("Saved {} and .svg", in_folder(app, "isohedral_contact_sheet.png").display());
+        }
+    }
+}
+
+/// Render one period of the tiling as a texture and save it as a PNG.
+fn export_texture(app: &mut App, options: &ExportOptions) {
+    let file_name = "isohedral_texture.png";
+    match egui_isohedral::render::seamless_texture(&app.tiling, &app.edges_shapes, options) {
+        Ok(texture) => match texture.image().encode_png() {
+            Ok(png) => {
+                save_export(app, file_name, &png);
+                if !texture.is_rectangular() {
+                    app.status.push_str(" (sheared, the lattice has no small rectangular period)");
+                }
             }
+            Err(err) => app.status = format!("Couldn't encode {file_name}: {err}"),
+        },
+        Err(err) => app.status = format!("Couldn't render {file_name}: {err}"),
+    }
+}
+
+/// Write the visible region as a PDF paginated onto the chosen paper, and send it to the printer
+/// if the `print` feature is enabled.
+fn print(app: &mut App, options: &ExportOptions) {
+    let file_name = "isohedral_print.pdf";
+    let document = pdf::document(&app.tiling, &app.edges_shapes, options, &app.print_layout);
+    save_export(app, file_name, &document);
+
+    #[cfg(feature = "print")]
+    if app.status.starts_with("Saved") {
+        app.status = match crate::desktop::print(&in_folder(app, file_name)) {
+            Ok(()) => format!("Sent {file_name} to the printer"),
+            Err(err) => format!("Saved {file_name}, but couldn't print it: {err}"),
+        };
+    }
+}
+
+/// The parts of the triangles of the screen-space `mesh` inside the world-space `mask`, with the
+/// vertex colours interpolated, so whatever the painter drew is cut to the silhouette.
+fn clip_mesh(mesh: &egui::Mesh, mask: &Mask, view: &ViewState) -> egui::Mesh {
+    let mut out = egui::Mesh::default();
+    for triangle in mesh.indices.chunks_exact(3) {
+        let vertices = [0, 1, 2].map(|k| mesh.vertices[triangle[k] as usize]);
+        let corners = vertices.map(|v| view.to_world(v.pos));
+        let area = (corners[1] - corners[0]).perp_dot(corners[2] - corners[0]);
+        if area == 0. {
+            continue;
+        }
+        for piece in mask.clip_convex(&corners) {
+            let base = out.vertices.len() as u32;
+            out.vertices.extend(piece.iter().map(|&p| {
+                // Barycentric weights of the corners at `p`.
+                let w1 = (p - corners[0]).perp_dot(corners[2] - corners[0]) / area;
+                let w2 = (corners[1] - corners[0]).perp_dot(p - corners[0]) / area;
+                let weights = [1. - w1 - w2, w1, w2];
+                let channel = |c: usize| weights.iter().zip(&vertices).map(|(w, v)| w * v.color[c] as f32).sum::<f32>().round().clamp(0., 255.) as u8;
+                egui::epaint::Vertex {
+                    pos: view.to_screen(p),
+                    uv: vertices[0].uv,
+                    color: egui::Color32::from_rgba_premultiplied(channel(0), channel(1), channel(2), channel(3)),
+                }
+            }));
+            out.indices.extend((1..piece.len() as u32 - 1).flat_map(|i| [base, base + i, base + i + 1]));
+        }
+    }
+    out
+}
+
+/// Append the outline and triangulation of every tile to `mesh`, transformed into screen space
+/// and filled with the tile's colour.
+#[allow(clippy::too_many_arguments)]
+fn add_tiles(
+    mesh: &mut egui::Mesh,
+    buffers: &mut DrawBuffers,
+    outline: &[Vec2],
+    triangles: &[u32],
+    tiles: impl Iterator<Item = (egui::Color32, Affine2)>,
+    view: &ViewState,
+    stroke: egui::Stroke,
+) {
+    for (c, transform) in tiles {
+        buffers.world.clear();
+        buffers.world.extend_from_slice(outline);
+        transform.transform_points(&mut buffers.world);
+        buffers.points.clear();
+        buffers.points.extend(buffers.world.iter().map(|&p| {
+            let p = view.to_screen(p);
+            vec2(p.x, p.y)
+        }));
+
+        // Tiles may be concave, so fill from the cached triangulation rather than as a convex path.
+        let base = mesh.vertices.len() as u32;
+        mesh.vertices.extend(buffers.points.iter().map(|p| egui::epaint::Vertex { pos: egui::pos2(p.x, p.y), uv: egui::epaint::WHITE_UV, color: c }));
+        mesh.indices.extend(triangles.iter().map(|i| base + i));
+
+        if outline.len() >= 2 {
+            tile_painter::stroke_outline(&buffers.points, stroke, LineJoin::Miter, &mut buffers.stroke, mesh);
+        }
+    }
+}
+
+/// A running transition to another tiling type.
+pub struct MorphAnimation {
+    morph: Morph,
+    /// Index of the tiling type being morphed into.
+    target: usize,
+    /// Edge shapes of the target tiling.
+    edges: Vec<Vec<Vec2>>,
+    /// Time the transition started, in seconds.
+    start: f64,
+    /// Length of the transition in seconds.
+    duration: f32,
+}
+
+impl MorphAnimation {
+    /// How far the transition has come, from `0` to `1`, eased in and out.
+    fn progress(&self, ctx: &egui::Context) -> f32 {
+        let t = ((ctx.input(|i| i.time) - self.start) as f32 / self.duration.max(f32::EPSILON)).clamp(0., 1.);
+        t * t * (3. - 2. * t)
+    }
+}
+
+/// Tiling types whose prototiles have the same number of vertices as the current one, and so can
+/// be morphed into.
+fn morph_targets(app: &App) -> Vec<usize> {
+    let vertices = app.tiling.num_vertices();
+    (0..TILING_TYPES.len())
+        .filter(|&i| i != app.tile_type_num && IsohedralTiling::new(get_tiling_type(i)).num_vertices() == vertices)
+        .collect()
+}
+
+/// Start morphing into `app.morph_target`, carrying the edge shapes over where their classes allow.
+fn start_morph(app: &mut App, ctx: &egui::Context) {
+    let target = IsohedralTiling::new(get_tiling_type(app.morph_target));
+    let edges: Vec<Vec<Vec2>> = (0..target.num_edge_shapes())
+        .map(|i| edges::project(&app.edges_shapes[i % app.edges_shapes.len()], target.edge_shape(i)))
+        .collect();
+    match Morph::new(&app.tiling, &app.edges_shapes, &target, &edges, app.screen_segments) {
+        Ok(morph) => {
+            let start = ctx.input(|i| i.time);
+            app.morph = Some(MorphAnimation { morph, target: app.morph_target, edges, start, duration: app.morph_duration });
+        }
+        Err(err) => app.status = format!("Couldn't morph: {err}"),
+    }
+}
+
+/// Opacity of a newly pinned ghost.
+const GHOST_OPACITY: f32 = 0.35;
+
+/// A tiling drawn see-through over the current one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SeeThrough {
+    /// The slideshow's previous tiling, as it fades out.
+    Fading,
+    /// The tiling pinned for comparing with.
+    Ghost,
+    /// One of [`App::layers`], in its own colours and blend.
+    Layer(usize),
+}
+
+/// Draw a see-through tiling over the current one, at its opacity.
+fn draw_layer(app: &mut App, ctx: &egui::Context, painter: &egui::Painter, layer: SeeThrough) {
+    let (tiling, edges, opacity, palette, blend) = match layer {
+        SeeThrough::Fading | SeeThrough::Ghost => {
+            let Some(fading) = (if layer == SeeThrough::Fading { app.slideshow.fading() } else { app.ghost.as_ref() }) else {
+                return;
+            };
+            (&fading.tiling, &fading.edges, fading.opacity, None, Blend::Normal)
+        }
+        SeeThrough::Layer(i) => match &app.layers[i] {
+            layer if layer.visible => (&layer.tiling, &layer.edges, layer.opacity, Some(layer.palette), layer.blend),
+            _ => return,
+        },
+    };
+    let rect = painter.clip_rect();
+    let region = app.view.world_region(tiling, rect);
+    let sampled = tiling.sample_edges(edges, app.screen_segments);
+    let outline = tiling.outline(&sampled);
+    let outline = app.gap.apply(&outline, centroid(&outline));
+    let triangles = triangulate(&outline);
+
+    let colors = palette.unwrap_or_else(|| tile_colours(app)).map(|c| c.gamma_multiply(opacity));
+    let mut stroke = tile_stroke(app);
+    stroke.color = stroke.color.gamma_multiply(opacity);
+    let tiles = tiling.fill_region(region.min.x, region.min.y, region.max.x, region.max.y).collect_tiles();
+    let cx = PaintContext {
+        tiling,
+        outline: &outline,
+        triangles: &triangles,
+        edges: &sampled,
+        colours: &colors,
+        patterns: &app.patterns,
+        stroke,
+        background: canvas_background(app).gamma_multiply(opacity),
+        view: app.view,
+        feathering: feathering(ctx),
+        apart: app.gap.is_open(),
+        join: app.gap.join(),
+    };
+    let mut mesh = egui::Mesh::default();
+    for tile in &tiles {
+        app.tile_painter.paint_tile(&cx, tile, tile.colour(tiling), &mut mesh);
+    }
+    app.tile_painter.paint_overlay(&cx, &tiles, &mut mesh);
+    if let Some(mask) = &app.mask {
+        mesh = clip_mesh(&mesh, mask, &app.view);
+    }
+    if app.simulation.is_some() {
+        for vertex in &mut mesh.vertices {
+            vertex.color = simulated(app, vertex.color);
+        }
+    }
+    if blend == Blend::Add {
+        // Premultiplied colours without alpha add to what is under them.
+        for vertex in &mut mesh.vertices {
+            let [r, g, b, _] = vertex.color.to_array();
+            vertex.color = egui::Color32::from_rgba_premultiplied(r, g, b, 0);
+        }
+    }
+    painter.add(egui::Shape::mesh(mesh));
+}
+
+/// Switch to the target tiling of the finished transition.
+fn finish_morph(app: &mut App) {
+    if let Some(animation) = app.morph.take() {
+        app.tile_type_num = animation.target;
+        app.tiling = IsohedralTiling::new(get_tiling_type(animation.target));
+        app.edges_shapes = animation.edges;
+        app.set_default_params();
+        app.apply_colouring();
+    }
+}
+
+/// Pick the fill pattern of colour class `class`, shown with its `colour`.
+fn pattern_picker(ui: &mut egui::Ui, class: usize, colour: egui::Color32, pattern: &mut Pattern) {
+    ui.horizontal(|ui| {
+        let (swatch, _) = ui.allocate_exact_size(egui::vec2(14., 14.), egui::Sense::hover());
+        ui.painter().rect_filled(swatch, 2., colour);
+        // Switching keeps the angle and spacing of the pattern before.
+        let (angle, spacing) = match *pattern {
+            Pattern::Hatch { angle, spacing } | Pattern::Crosshatch { angle, spacing } => (angle, spacing),
+            Pattern::Dots { spacing } => (std::f32::consts::FRAC_PI_4, spacing),
+            Pattern::Solid => (std::f32::consts::FRAC_PI_4, 0.08),
+        };
+        let name = match pattern {
+            Pattern::Solid => "solid",
+            Pattern::Hatch { .. } => "hatch",
+            Pattern::Crosshatch { .. } => "crosshatch",
+            Pattern::Dots { .. } => "dots",
+        };
+        egui::ComboBox::from_id_salt(("pattern", class)).selected_text(name).show_ui(ui, |ui| {
+            ui.selectable_value(pattern, Pattern::Solid, "solid");
+            ui.selectable_value(pattern, Pattern::Hatch { angle, spacing }, "hatch");
+            ui.selectable_value(pattern, Pattern::Crosshatch { angle, spacing }, "crosshatch");
+            ui.selectable_value(pattern, Pattern::Dots { spacing }, "dots");
         });
-        egui::Shape::convex_polygon(points, c, stroke)
+        match pattern {
+            Pattern::Hatch { angle, spacing } | Pattern::Crosshatch { angle, spacing } => {
+                ui.drag_angle(angle);
+                ui.add(egui::DragValue::new(spacing).speed(0.002).range(0.02..=0.5)).on_hover_text("spacing");
+            }
+            Pattern::Dots { spacing } => {
+                ui.add(egui::DragValue::new(spacing).speed(0.002).range(0.02..=0.5)).on_hover_text("spacing");
+            }
+            Pattern::Solid => {}
+        }
+    });
+}
+
+/// Fill the central panel, under all the windows, with the tiling. Dragging it pans the view and
+/// scrolling or pinching zooms it.
+fn draw_canvas(app: &mut App, ctx: &egui::Context) {
+    // The background shows between tiles drawn apart, as it does in the exports.
+    let background = simulated(app, canvas_background(app));
+    egui::CentralPanel::default().frame(egui::Frame::NONE.fill(background)).show(ctx, |ui| {
+        let response = ui.allocate_rect(ui.max_rect(), egui::Sense::click_and_drag());
+        if !app.screensaver {
+            app.view.navigate(ctx, response.drag_delta(), response.hover_pos());
+            // The menu stays on the tile it was opened on, wherever the pointer goes next.
+            if response.secondary_clicked() {
+                app.menu_tile = response.interact_pointer_pos().and_then(|pos| app.view.tile_at(&app.tiling, app.outline_cache.outline(), pos));
+            }
+            let tile = app.menu_tile;
+            response.context_menu(|ui| clipboard::menu(app, ui, tile));
+            if app.measure.active
+                && response.clicked()
+                && let Some(pos) = response.interact_pointer_pos()
+            {
+                measure::click(app, pos);
+            }
+        }
+        let painter = ui.painter_at(response.rect);
+        reference::draw(app, ctx, &painter);
+        draw_isohedrals(app, ctx, &painter);
+        for i in 0..app.layers.len() {
+            draw_layer(app, ctx, &painter, SeeThrough::Layer(i));
+        }
+        if app.slideshow.active {
+            draw_layer(app, ctx, &painter, SeeThrough::Fading);
+        } else if app.ghost.is_some() {
+            draw_layer(app, ctx, &painter, SeeThrough::Ghost);
+        }
+        if app.measure.active {
+            measure::draw(app, &painter, response.hover_pos());
+        }
+    });
+}
+
+fn draw_isohedrals(app: &mut App, ctx: &egui::Context, painter: &egui::Painter) {
+    let rect = painter.clip_rect();
+    let colors = reference::fill_colours(app, tile_colours(app));
+    let stroke = tile_stroke(app);
+    let background = canvas_background(app);
+
+    if app.morph.as_ref().is_some_and(|animation| animation.progress(ctx) >= 1.) {
+        finish_morph(app);
+    }
+    app.tile_cache.update(&app.tiling, app.view.world_region(&app.tiling, rect));
+    app.outline_cache.update(&app.tiling, &app.edges_shapes, app.screen_segments, app.gap);
+    let feathering = feathering(ctx);
+
+    // All tiles go into a single mesh, sized from the previous frame.
+    let buffers = &mut app.draw_buffers;
+    let mut mesh = egui::Mesh::default();
+    mesh.vertices.reserve(buffers.vertices);
+    mesh.indices.reserve(buffers.indices);
+
+    if let Some(animation) = &app.morph {
+        // Intermediate outlines may cross themselves; the triangulation copes by fanning.
+        let t = animation.progress(ctx);
+        let outline = animation.morph.outline(t);
+        let triangles = triangulate(&outline);
+        let cache = &app.tile_cache;
+        let tiles = cache
+            .arena()
+            .iter()
+            .zip(cache.classes())
+            .map(|(tile, &class)| (colors[class], animation.morph.transform(tile.t1(), tile.t2(), tile.aspect(), t)));
+        // The intermediate tiles don't meet edge to edge, so each one is stroked on its own.
+        add_tiles(&mut mesh, buffers, &outline, &triangles, tiles, &app.view, stroke);
+        ctx.request_repaint();
+    } else {
+        let cx = PaintContext {
+            tiling: &app.tiling,
+            outline: app.outline_cache.drawn(),
+            triangles: app.outline_cache.triangles(),
+            edges: app.outline_cache.sampled(),
+            colours: &colors,
+            patterns: &app.patterns,
+            stroke,
+            background,
+            view: app.view,
+            feathering,
+            apart: app.gap.is_open(),
+            join: app.gap.join(),
+        };
+        let cache = &app.tile_cache;
+        for (tile, &class) in cache.arena().iter().zip(cache.classes()) {
+            app.tile_painter.paint_tile(&cx, tile, class, &mut mesh);
+        }
+        app.tile_painter.paint_overlay(&cx, cache.arena(), &mut mesh);
+    }
+
+    buffers.vertices = mesh.vertices.len();
+    buffers.indices = mesh.indices.len();
+    if let Some(mask) = &app.mask {
+        mesh = clip_mesh(&mesh, mask, &app.view);
+    }
+    if app.simulation.is_some() {
+        for vertex in &mut mesh.vertices {
+            vertex.color = simulated(app, vertex.color);
+        }
+    }
+    if app.morph.is_some() || app.dual != DualOverlay::Instead {
+        painter.add(egui::Shape::mesh(mesh));
+    }
+
+    if app.morph.is_some() {
+        return;
+    }
+    if app.dual != DualOverlay::Off {
+        draw_dual(app, painter);
+    }
+    if !app.outline_cache.problems().is_empty() {
+        draw_edge_warnings(app, painter);
+    }
+    if app.tile_labels != TileLabels::Off {
+        draw_tile_labels(app, painter);
+    }
+    if app.aspect_frames {
+        draw_aspect_frames(app, painter);
+    }
+    if app.edge_arrows != EdgeArrows::Off {
+        draw_edge_arrows(app, painter);
+    }
+    if app.edge_editor.open {
+        edge_editor::draw_edge_labels(app, painter);
+    }
+    if app.wallpaper_mode {
+        draw_wallpaper_overlay(app, painter);
     }
-    ))
 }
 
 pub fn draw_interface(app: &mut App, ctx: &egui::Context) {
     ctx.style_mut(|style| {
         style.visuals.panel_fill = app.colorix.animator.animated_tokens.subtle_background(); 
     });
+    if app.high_contrast {
+        app.contrast_cache.update(&app.tiling);
+    }
+    if app.screensaver {
+        slideshow::screensaver(app, ctx);
+    }
+    handle_dropped_files(app, ctx);
+    watch::poll(app, ctx);
+    if !app.view_fitted {
+        app.view.set_tiles_across(&app.tiling, ctx.screen_rect(), DEFAULT_TILES_ACROSS);
+        app.view_fitted = true;
+    }
+    timeline::advance(app, ctx);
+    nudge(app, ctx);
+    recorder::advance(app, ctx, ctx.screen_rect());
+    if app.slideshow.active {
+        slideshow::advance(app, ctx);
+        draw_canvas(app, ctx);
+        return;
+    }
+    history::observe(app, ctx, ctx.screen_rect());
     egui::Window::new("Isohedrals").show(ctx, |ui| {
         ui.horizontal(|ui| {
             app.colorix.light_dark_toggle_button(ui, 30.);
             ui.add_space(10.);
-            app.colorix.themes_dropdown(ui, None, false);
+            let names: Vec<String> = (1..=app.saved_themes.len()).map(|i| format!("Custom {i}")).collect();
+            let saved = (!names.is_empty()).then(|| (names.iter().map(String::as_str).collect(), app.saved_themes.clone()));
+            app.colorix.themes_dropdown(ui, saved, false);
         });
         ui.vertical_centered(|ui| {
             let type_nr = app.tile_type_num;
             ui.add_space(5.);
             if ui.add(egui::Slider::new(&mut app.tile_type_num, 0..=80).text(format!("type: {}", get_tiling_type(type_nr)))).changed() {
-                app.tiling = IsohedralTiling::new(get_tiling_type(app.tile_type_num));
-                app.set_default_edges();
-                app.set_default_params();
+                app.set_tiling_type(app.tile_type_num);
             };
-            for i in 0..app.tiling.num_params {
+            ui.add_space(5.);
+            if ui.add(egui::Slider::new(&mut app.colours, 1..=MAX_COLOURS).text("colours")).changed() {
+                app.apply_colouring();
+            }
+            for i in 0..app.tiling.num_params() {
                 ui.add_space(5.);
-                if ui.add(egui::Slider::new(&mut app.params[i], 0.0..=1.).text(format!("v{}", i))).changed() {
+                let selected = app.selection == Some(Selection::Param(i));
+                let label = if selected { egui::RichText::new(format!("v{}", i)).strong().underline() } else { egui::RichText::new(format!("v{}", i)) };
+                let response = fine_slider(ui, &mut app.params[i], 0.0..=1., label, app.decimals);
+                if response.changed() {
                     app.tiling.set_parameters(&app.params);
                 };
+                if response.clicked() || response.drag_started() || response.gained_focus() {
+                    app.selection = Some(Selection::Param(i));
+                }
             };
-            let mut rng = thread_rng();
-            if ui.button("Random theme").clicked() {
-                app.set_params = true;
-                let rand_theme = rng.gen_range(0..8);
-                app.colorix.update_theme(ctx, utils::THEMES[rand_theme]) 
-            }  
+            ui.add_space(5.);
+            ui.horizontal(|ui| {
+                let screen = ctx.screen_rect();
+                let mut tiles = app.view.tiles_across(&app.tiling, screen.width());
+                let slider = ui.add(egui::Slider::new(&mut tiles, TILES_ACROSS_RANGE).logarithmic(true).show_value(false));
+                let exact = ui.add(egui::DragValue::new(&mut tiles).range(TILES_ACROSS_RANGE).speed(0.05).fixed_decimals(app.decimals.min(2)));
+                ui.label("tiles across").on_hover_text("How many tiles fit across the window, setting the density of the pattern");
+                if (slider | exact).changed() {
+                    app.view.set_tiles_across(&app.tiling, screen, tiles);
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.toggle_value(&mut app.edge_editor.open, "Edit edges");
+                ui.toggle_value(&mut app.console.open, "Console");
+                ui.toggle_value(&mut app.timeline.open, "Timeline");
+                ui.toggle_value(&mut app.history.open, "History").on_hover_text("Thumbnails of the designs explored, to go back to");
+                if ui.button("Random edges").clicked() {
+                    app.next_seed();
+                    app.set_random_edges();
+                }
+            });
+            ui.horizontal(|ui| {
+                if ui.button("Random theme").clicked() {
+                    app.next_seed();
+                    app.set_params = true;
+                    app.set_random_theme(ctx);
+                }
+                if ui.button("Randomize").on_hover_text("Random parameters, edges and theme").clicked() {
+                    app.next_seed();
+                    app.randomize(ctx);
+                }
+                let seed = ui.add(egui::DragValue::new(&mut app.seed).prefix("seed ")).on_hover_text("Every random action draws from this seed: the same seed gives the same design");
+                if seed.changed() {
+                    app.randomize(ctx);
+                }
+            });
             if app.set_params {
                 let (r, g, b, _) = app.colorix.animator.tokenshifts[2].to_tuple();
                 let (r2, g2, b2, _) = app.colorix.animator.tokenshifts[1].to_tuple();
                 let params = [r as f32/ 255., g as f32/ 255., b as f32/ 255., r2 as f32/ 255., g2 as f32/ 255., b2 as f32/ 255.];
-                if app.tiling.num_params != 0 {
-                    let rand_param = rng.gen_range(0..app.tiling.num_params);
+                if app.tiling.num_params() != 0 {
+                    // A different parameter at each step of the animation, the same ones for the same seed.
+                    let step = u64::from(app.colorix.animator.progress.to_bits());
+                    let rand_param = seeded(app.seed ^ step, Roll::Params).gen_range(0..app.tiling.num_params());
                     app.params[rand_param] = params[rand_param];
                     app.tiling.set_parameters(&app.params); 
                 }
@@ -84,7 +1218,511 @@ pub fn draw_interface(app: &mut App, ctx: &egui::Context) {
                     app.set_params = false
                 }
             }         
-        })
+        });
+        ui.collapsing("Morph", |ui| {
+            let targets = morph_targets(app);
+            if !targets.contains(&app.morph_target) {
+                app.morph_target = targets.first().copied().unwrap_or(app.tile_type_num);
+            }
+            egui::ComboBox::from_label("into")
+                .selected_text(format!("{}", get_tiling_type(app.morph_target)))
+                .show_ui(ui, |ui| {
+                    for i in targets.iter().copied() {
+                        ui.selectable_value(&mut app.morph_target, i, format!("{}", get_tiling_type(i)));
+                    }
+                });
+            ui.add(egui::Slider::new(&mut app.morph_duration, 0.5..=10.).suffix(" s").text("duration"));
+            if ui.add_enabled(!targets.is_empty() && app.morph.is_none(), egui::Button::new("Morph")).clicked() {
+                start_morph(app, ctx);
+            }
+        });
+        ui.collapsing("Slideshow", |ui| {
+            ui.add(egui::Slider::new(&mut app.slideshow.interval, 2.0..=60.).suffix(" s").text("per tiling"));
+            ui.add(egui::Slider::new(&mut app.slideshow.fade, 0.0..=5.).suffix(" s").text("crossfade"));
+            if ui.button("Start slideshow").on_hover_text("Press Escape to stop").clicked() {
+                app.slideshow.start();
+            }
+        });
+        ui.collapsing("Recorder", |ui| recorder_section(app, ui, ctx));
+        ui.collapsing("Quality", |ui| {
+            ui.add(egui::Slider::new(&mut app.screen_segments, 1..=64).text("segments per edge (screen)"));
+            ui.add(egui::Slider::new(&mut app.export_segments, 1..=256).text("segments per edge (export)"));
+            ui.add(egui::DragValue::new(&mut app.decimals).range(0..=6).suffix(" decimals")).on_hover_text("Precision of the exact values next to the sliders");
+            let mut tessellation = ctx.tessellation_options(|options| *options);
+            ui.checkbox(&mut tessellation.feathering, "Anti-aliasing");
+            ui.add_enabled(tessellation.feathering, egui::Slider::new(&mut tessellation.feathering_size_in_pixels, 0.25..=4.).suffix(" px").text("feathering"));
+            ctx.tessellation_options_mut(|options| *options = tessellation);
+            let msaa = match app.multisampling {
+                0 => String::from("off"),
+                samples => format!("{samples}x"),
+            };
+            ui.label(format!("multisampling: {msaa}")).on_hover_text("Set at startup with --msaa <samples>");
+        });
+        ui.collapsing("Style", |ui| {
+            let rendering = app.rendering;
+            ui.horizontal(|ui| {
+                ui.label("draw");
+                ui.selectable_value(&mut app.rendering, Rendering::Tiles, "tiles");
+                let weave = match app.rendering {
+                    Rendering::Weave { .. } => app.rendering,
+                    Rendering::Tiles => Rendering::WEAVE,
+                };
+                ui.selectable_value(&mut app.rendering, weave, "weave").on_hover_text("Ribbons passing alternately over and under each other, like Celtic knotwork");
+            });
+            if let Rendering::Weave { width, angle } = &mut app.rendering {
+                ui.add(egui::Slider::new(width, 0.01..=0.3).text("ribbon width"));
+                ui.add(egui::Slider::new(angle, 0.2..=1.4).text("angle to edges"));
+            }
+            if app.rendering != rendering {
+                app.tile_painter = app.rendering.painter();
+            }
+            ui.horizontal(|ui| {
+                ui.label("palette");
+                let selected = if app.image_colours.is_some() { "image" } else { app.palette.map_or("theme", Palette::name) };
+                egui::ComboBox::from_id_salt("palette").selected_text(selected).show_ui(ui, |ui| {
+                    // Picking any palette replaces the colours taken from an image.
+                    if ui.selectable_value(&mut app.palette, None, "theme").clicked() {
+                        app.image_colours = None;
+                    }
+                    for palette in Palette::ALL {
+                        if ui.selectable_value(&mut app.palette, Some(palette), palette.name()).clicked() {
+                            app.image_colours = None;
+                        }
+                    }
+                });
+                ui.label("simulate").on_hover_text("Preview the canvas as seen with a colour vision deficiency; exports are not affected");
+                egui::ComboBox::from_id_salt("simulation").selected_text(app.simulation.map_or("off", Deficiency::name)).show_ui(ui, |ui| {
+                    ui.selectable_value(&mut app.simulation, None, "off");
+                    for deficiency in Deficiency::ALL {
+                        ui.selectable_value(&mut app.simulation, Some(deficiency), deficiency.name());
+                    }
+                });
+            });
+            if !app.extracted.is_empty() {
+                ui.horizontal(|ui| {
+                    ui.label("from image").on_hover_text("The main colours of the dropped image, most common first; it goes to the background and the next ones to the colour classes");
+                    for &colour in &app.extracted {
+                        let (swatch, _) = ui.allocate_exact_size(egui::vec2(14., 14.), egui::Sense::hover());
+                        ui.painter().rect_filled(swatch, 2., colour);
+                    }
+                    if ui.button("Apply").clicked() {
+                        app.image_colours = Some(std::mem::take(&mut app.extracted));
+                    }
+                    if ui.button("Dismiss").clicked() {
+                        app.extracted.clear();
+                    }
+                });
+            }
+            ui.checkbox(&mut app.high_contrast, "High contrast").on_hover_text("Bright colours as different as possible where classes meet, with heavy black outlines, whatever the theme");
+            ui.checkbox(&mut app.colouring_book, "Colouring book").on_hover_text("Black outlines on white, for printable colouring pages; exports follow");
+            if app.colouring_book {
+                fine_slider(ui, &mut app.line_weight, 0.5..=8., "line weight (px)", app.decimals);
+            }
+            ui.label("fill patterns").on_hover_text("Marked patterns are drawn in the outline colour, for a print-friendly monochrome look");
+            let colours = tile_colours(app);
+            let count = app.tiling.num_colours();
+            for (class, (pattern, colour)) in app.patterns.iter_mut().zip(colours).take(count).enumerate() {
+                pattern_picker(ui, class, colour, pattern);
+            }
+            ui.horizontal(|ui| {
+                ui.label("mask");
+                if app.mask.is_some() {
+                    if ui.button("Remove").clicked() {
+                        app.mask = None;
+                    }
+                } else {
+                    ui.weak("open or drop an SVG silhouette");
+                }
+            });
+            #[cfg(feature = "glyphs")]
+            ui.horizontal(|ui| {
+                ui.add(egui::TextEdit::singleline(&mut app.mask_text).desired_width(120.));
+                let font = edge_editor::font_path(app).to_string();
+                let button = ui.add_enabled(!font.is_empty(), egui::Button::new("Mask to text"));
+                if button.on_hover_text("Tile the inside of the letters, set in the font picked for glyph edges").on_disabled_hover_text("Open a font file first").clicked() {
+                    match std::fs::read(&font).map_err(|err| err.to_string()).and_then(|data| glyph::text_mask(&data, &app.mask_text).map_err(|err| err.to_string())) {
+                        Ok(mask) => {
+                            set_mask(app, &mask, ui.ctx().screen_rect());
+                            app.status = format!("Masked the tiling to \"{}\"", app.mask_text);
+                        }
+                        Err(err) => app.status = format!("Couldn't set the text: {err}"),
+                    }
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label("gap");
+                let amount = match app.gap {
+                    Gap::Shrink(amount) | Gap::Inset(amount, _) => amount,
+                };
+                ui.selectable_value(&mut app.gap, Gap::Shrink(amount), "shrink").on_hover_text("Shrink every tile toward its centre");
+                let join = match app.gap {
+                    Gap::Inset(_, join) => join,
+                    Gap::Shrink(_) => LineJoin::Miter,
+                };
+                ui.selectable_value(&mut app.gap, Gap::Inset(amount, join), "inset").on_hover_text("Move every tile's outline inward, keeping the gaps equally wide");
+            });
+            match &mut app.gap {
+                Gap::Shrink(fraction) => {
+                    ui.add(egui::Slider::new(fraction, 0.0..=0.3).text("fraction"));
+                }
+                Gap::Inset(distance, join) => {
+                    ui.add(egui::Slider::new(distance, 0.0..=0.2).text("distance"));
+                    ui.horizontal(|ui| {
+                        ui.label("corners");
+                        ui.selectable_value(join, LineJoin::Miter, "miter");
+                        ui.selectable_value(join, LineJoin::Round, "round");
+                        ui.selectable_value(join, LineJoin::Bevel, "bevel");
+                    });
+                }
+            }
+        });
+        ui.collapsing("Custom theme", |ui| {
+            ui.horizontal(|ui| {
+                ui.label("seed colour");
+                app.colorix.custom_picker(ui);
+                if ui.button("Derive theme").on_hover_text("All twelve colours from the seed colour").clicked() {
+                    app.colorix.twelve_from_custom(ui);
+                }
+            });
+            app.colorix.ui_combo_12(ui, false);
+            let theme = *app.colorix.theme();
+            let button = ui.add_enabled(!app.saved_themes.contains(&theme), egui::Button::new("Save theme"));
+            if button.on_hover_text("Add the theme to the themes menu; saved themes are stored in projects").clicked() {
+                app.saved_themes.push(theme);
+                app.status = format!("Saved the theme as Custom {}", app.saved_themes.len());
+            }
+        });
+        ui.collapsing("Layers", |ui| layers::section(app, ui));
+        ui.collapsing("Reference image", |ui| reference::section(app, ui, ctx.screen_rect()));
+        ui.collapsing("Overlays", |ui| {
+            ui.horizontal(|ui| {
+                ui.label("tile labels");
+                ui.selectable_value(&mut app.tile_labels, TileLabels::Off, "off");
+                ui.selectable_value(&mut app.tile_labels, TileLabels::Lattice, "lattice");
+                ui.selectable_value(&mut app.tile_labels, TileLabels::Colour, "colour");
+            });
+            ui.horizontal(|ui| {
+                ui.label("dual tiling").on_hover_text("The graph joining the centres of neighbouring tiles");
+                ui.selectable_value(&mut app.dual, DualOverlay::Off, "off");
+                ui.selectable_value(&mut app.dual, DualOverlay::Over, "over");
+                ui.selectable_value(&mut app.dual, DualOverlay::Instead, "instead");
+            });
+            if app.dual != DualOverlay::Off {
+                ui.horizontal(|ui| {
+                    ui.label("dual stroke");
+                    ui.add(&mut app.dual_stroke);
+                });
+            }
+            ui.horizontal(|ui| {
+                if ui.button("Pin ghost").on_hover_text("Keep the tiling as it is now, drawn see-through over the tiling as it changes").clicked() {
+                    app.pin_ghost(app.ghost.as_ref().map_or(GHOST_OPACITY, |ghost| ghost.opacity));
+                }
+                if let Some(ghost) = &mut app.ghost {
+                    ui.add(egui::Slider::new(&mut ghost.opacity, 0.05..=1.).text("opacity"));
+                    if ui.button("Remove").clicked() {
+                        app.ghost = None;
+                    }
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut app.measure.active, "measure").on_hover_text("Click two points on the canvas for the distance between them, or three for the angle at the second; clicks near a tile corner snap to it");
+                if app.measure.active && ui.button("Clear").clicked() {
+                    app.measure.clear();
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label("edge arrows").on_hover_text("Which way each edge shape runs along the tile; edges the outline runs backwards have their arrows in the selection colour");
+                ui.selectable_value(&mut app.edge_arrows, EdgeArrows::Off, "off");
+                ui.selectable_value(&mut app.edge_arrows, EdgeArrows::Prototile, "prototile");
+                ui.selectable_value(&mut app.edge_arrows, EdgeArrows::All, "all");
+            });
+            ui.checkbox(&mut app.aspect_frames, "aspect frames").on_hover_text("An F in one tile of each aspect, showing how its aspect transform turns or flips the prototile");
+        });
+        ui.collapsing("Files", |ui| {
+            ui.horizontal(|ui| {
+                ui.label("folder");
+                ui.add(egui::TextEdit::singleline(&mut app.folder).hint_text("working directory").desired_width(200.));
+            });
+            ui.horizontal(|ui| {
+                let open = ui.button("Open").on_hover_text("A project, session, edge, font, SVG mask or PNG image file; files can also be dropped onto the window");
+                let field = ui.add(egui::TextEdit::singleline(&mut app.open_path).hint_text("file in the folder, or a path").desired_width(200.));
+                if open.clicked() || (field.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter))) {
+                    let path = in_folder(app, &app.open_path);
+                    let contents = std::fs::read(&path).map_err(|err| err.to_string());
+                    open_file(app, ctx, &path.display().to_string(), Some(&path), contents);
+                    // The folder is now the file's, so keep just its name.
+                    if let Some(name) = path.file_name() {
+                        app.open_path = name.to_string_lossy().into_owned();
+                    }
+                }
+            });
+            ui.horizontal(|ui| {
+                if ui.button("Save project").clicked() {
+                    run_export(app, ctx.screen_rect(), ExportKind::Project);
+                }
+                let mut reopen = None;
+                ui.add_enabled_ui(!app.recent.paths().is_empty(), |ui| {
+                    ui.menu_button("Recent", |ui| {
+                        for path in app.recent.paths() {
+                            let name = path.file_name().map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().into_owned());
+                            let button = match app.thumbnails.get(ctx, path) {
+                                Some(texture) => egui::Button::image_and_text(egui::Image::new(texture).max_height(48.), name),
+                                None => egui::Button::new(name),
+                            };
+                            if ui.add(button).on_hover_text(path.display().to_string()).clicked() {
+                                reopen = Some(path.clone());
+                                ui.close_menu();
+                            }
+                        }
+                        ui.separator();
+                        if ui.button("Clear").clicked() {
+                            app.recent.clear();
+                            ui.close_menu();
+                        }
+                    });
+                });
+                if let Some(path) = reopen {
+                    let contents = std::fs::read(&path).map_err(|err| err.to_string());
+                    open_file(app, ctx, &path.display().to_string(), Some(&path), contents);
+                }
+            });
+            ui.horizontal(|ui| {
+                if ui.button("Copy share code").on_hover_text("The whole design as a short text, for chats and issue reports").clicked() {
+                    ctx.copy_text(Project::from_app(app).share_code());
+                    app.status = String::from("copied the share code to the clipboard");
+                }
+                let paste = ui.button("Paste share code");
+                let field = ui.add(egui::TextEdit::singleline(&mut app.share_code).hint_text("share code").desired_width(140.));
+                if paste.clicked() || (field.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter))) {
+                    match Project::from_share_code(&app.share_code).and_then(|project| project.apply(app, ctx)) {
+                        Ok(()) => {
+                            app.status = String::from("opened the share code");
+                            app.share_code.clear();
+                        }
+                        Err(err) => app.status = format!("Couldn't open the share code: {err}"),
+                    }
+                }
+            });
+            if !app.status.is_empty() {
+                ui.label(&app.status);
+            }
+        });
+        ui.collapsing("Export", |ui| {
+            ui.checkbox(&mut app.clip_exports, "Clip to the region").on_hover_text("Cut the tiles crossing the border of the view along it, for straight borders in the CSV and PDF exports");
+            ui.checkbox(&mut app.physical_units, "Physical size");
+            if app.physical_units {
+                ui.horizontal(|ui| {
+                    ui.label("1 unit =");
+                    ui.add(egui::DragValue::new(&mut app.physical.unit_length).range(0.01..=1000.).speed(0.1));
+                    egui::ComboBox::from_id_salt("physical unit")
+                        .selected_text(app.physical.unit.suffix())
+                        .show_ui(ui, |ui| {
+                            for unit in [Unit::Millimetre, Unit::Inch] {
+                                ui.selectable_value(&mut app.physical.unit, unit, unit.suffix());
+                            }
+                        });
+                    ui.add(egui::DragValue::new(&mut app.physical.dpi).range(1.0..=2400.).suffix(" dpi"));
+                });
+            }
+            let options = export_options(app, ctx.screen_rect());
+            if let Some((width, height, unit)) = options.physical_size() {
+                ui.label(format!("{width:.1} x {height:.1} {}, {} x {} px", unit.suffix(), options.width, options.height));
+            }
+            let screen = ctx.screen_rect();
+            if ui.button("PNG image").clicked() {
+                run_export(app, screen, ExportKind::Png);
+            }
+            if ui.button("p5.js sketch").clicked() {
+                run_export(app, screen, ExportKind::P5);
+            }
+            if ui.button("SVG pattern").clicked() {
+                run_export(app, screen, ExportKind::Svg);
+            }
+            if ui.button("Seamless texture").clicked() {
+                run_export(app, screen, ExportKind::Texture);
+            }
+            if ui.button("Contact sheet").on_hover_text("Every tiling type on one sheet, as PNG and SVG").clicked() {
+                run_export(app, screen, ExportKind::ContactSheet);
+            }
+            ui.horizontal(|ui| {
+                if ui.button("TikZ picture").clicked() {
+                    run_export(app, screen, ExportKind::Tikz);
+                }
+                ui.label("width");
+                ui.add_enabled(options.physical.is_none(), egui::TextEdit::singleline(&mut app.tikz_width).desired_width(80.));
+            });
+            if ui.button("CSV vertices").on_hover_text("The outline points of every tile in view, in world coordinates").clicked() {
+                run_export(app, screen, ExportKind::Csv);
+            }
+            ui.horizontal(|ui| {
+                if ui.button("Prototile template").on_hover_text("One tile at the physical size set under Print, to cut tiles from").clicked() {
+                    run_export(app, screen, ExportKind::Prototile);
+                }
+                ui.checkbox(&mut app.dimensions, "dimensions").on_hover_text("Label the edges with their lengths and the corners with their interior angles");
+            });
+            ui.horizontal(|ui| {
+                if ui.button("Publication figure").on_hover_text("Whole periods of the tiling as SVG, cropped to the tiles, for papers").clicked() {
+                    run_export(app, screen, ExportKind::Figure);
+                }
+                let figure = &mut app.figure;
+                ui.add(egui::DragValue::new(&mut figure.periods.0).range(1..=20).prefix("periods "));
+                ui.label("x");
+                ui.add(egui::DragValue::new(&mut figure.periods.1).range(1..=20));
+            });
+            ui.horizontal(|ui| {
+                let figure = &mut app.figure;
+                ui.checkbox(&mut figure.label, "type label");
+                ui.checkbox(&mut figure.parameters, "parameters");
+                ui.checkbox(&mut figure.monochrome, "monochrome").on_hover_text("Greys and black outlines, safe to print in black and white");
+            });
+            if !app.status.is_empty() {
+                ui.label(&app.status);
+            }
+        });
+        ui.collapsing("Print", |ui| {
+            let layout = &mut app.print_layout;
+            egui::ComboBox::from_label("paper")
+                .selected_text(layout.paper.name)
+                .show_ui(ui, |ui| {
+                    for paper in pdf::PAPERS {
+                        ui.selectable_value(&mut layout.paper, paper, paper.name);
+                    }
+                });
+            ui.checkbox(&mut layout.landscape, "Landscape");
+            ui.add(egui::Slider::new(&mut layout.margin, 0.0..=30.).text("margin").suffix(" mm"));
+            ui.horizontal(|ui| {
+                ui.label("1 unit =");
+                ui.add(egui::DragValue::new(&mut app.physical.unit_length).range(0.01..=1000.).speed(0.1));
+                ui.label(app.physical.unit.suffix());
+            });
+            let options = export_options(app, ctx.screen_rect()).with_physical(app.physical);
+            let (columns, rows) = app.print_layout.pages(&options);
+            ui.label(format!("{} page(s), {columns} x {rows}", columns * rows));
+            if ui.button("Print").on_hover_text("The visible region, at the chosen scale").clicked() {
+                run_export(app, ctx.screen_rect(), ExportKind::Print);
+            }
+            if !app.status.is_empty() {
+                ui.label(&app.status);
+            }
+        });
+        ui.collapsing("Wallpaper", |ui| {
+            ui.checkbox(&mut app.wallpaper_mode, "Wallpaper mode");
+            egui::ComboBox::from_label("size")
+                .selected_text(preset_label(&wallpaper::PRESETS[app.wallpaper_preset]))
+                .show_ui(ui, |ui| {
+                    for (i, preset) in wallpaper::PRESETS.iter().enumerate() {
+                        ui.selectable_value(&mut app.wallpaper_preset, i, preset_label(preset));
+                    }
+                });
+            #[cfg(feature = "set-wallpaper")]
+            ui.checkbox(&mut app.set_wallpaper, "Set as desktop wallpaper");
+            if ui.add_enabled(app.wallpaper_mode, egui::Button::new("Export wallpaper")).clicked() {
+                export_wallpaper(app, ctx.screen_rect());
+            }
+            if !app.status.is_empty() {
+                ui.label(&app.status);
+            }
+        });
+        ui.collapsing("Inspector", |ui| {
+            let text = inspector_text(&app.tiling);
+            if ui.button("Copy").on_hover_text("Copy all the numbers to the clipboard").clicked() {
+                ctx.copy_text(text.clone());
+            }
+            egui::ScrollArea::both().max_height(300.).show(ui, |ui| {
+                ui.label(egui::RichText::new(text).monospace());
+            });
+        });
+        ui.collapsing("Statistics", |ui| statistics(app, ui, ctx.screen_rect()));
     });
-    draw_isohedrals(app, ctx);
-}
\ No newline at end of file
+    edge_editor::show(app, ctx);
+    console::show(app, ctx);
+    timeline::show(app, ctx);
+    history::show(app, ctx);
+    draw_canvas(app, ctx);
+    draw_cursor_readout(app, ctx);
+}
+
+/// Counts and areas of the tiles whose centres are in the view `screen`, for estimating materials
+/// and checking that the tiles cover the plane once.
+fn statistics(app: &App, ui: &mut egui::Ui, screen: egui::Rect) {
+    let outline = app.outline_cache.outline();
+    let n = outline.len();
+    let tile_area = (0..n).map(|i| outline[i].perp_dot(outline[(i + 1) % n])).sum::<f32>().abs() / 2.;
+    let (lo, hi) = (app.view.to_world(screen.min), app.view.to_world(screen.max));
+    let view_area = (hi.x - lo.x) * (hi.y - lo.y);
+
+    let centroid = app.outline_cache.centroid();
+    let (mut classes, mut aspects) = (vec![0; app.tiling.num_colours()], vec![0; app.tiling.num_aspects()]);
+    let mut drawn = 0;
+    for (&(t1, t2, aspect), transform) in app.tile_cache.tiles() {
+        drawn += 1;
+        let c = transform.transform_point2(centroid);
+        if (lo.x..hi.x).contains(&c.x) && (lo.y..hi.y).contains(&c.y) {
+            classes[app.tiling.colour(t1, t2, aspect)] += 1;
+            aspects[aspect] += 1;
+        }
+    }
+    let in_view: usize = aspects.iter().sum();
+
+    // Areas in the physical unit when one is set, as for the exports.
+    let area = |a: f32| {
+        if app.physical_units {
+            format!("{:.2} {}²", a * app.physical.unit_length * app.physical.unit_length, app.physical.unit.suffix())
+        } else {
+            format!("{a:.4} units²")
+        }
+    };
+    let list = |counts: &[usize]| counts.iter().enumerate().map(|(i, c)| format!("{i}: {c}")).collect::<Vec<_>>().join(", ");
+    egui::Grid::new("statistics").num_columns(2).show(ui, |ui| {
+        ui.label("tiles drawn");
+        ui.label(drawn.to_string());
+        ui.end_row();
+        ui.label("tiles centred in view");
+        ui.label(in_view.to_string());
+        ui.end_row();
+        ui.label("per colour");
+        ui.label(list(&classes));
+        ui.end_row();
+        ui.label("per aspect");
+        ui.label(list(&aspects));
+        ui.end_row();
+        ui.label("tile area");
+        ui.label(area(tile_area));
+        ui.end_row();
+        ui.label("tiles in view");
+        ui.label(area(tile_area * in_view as f32));
+        ui.end_row();
+        ui.label("view");
+        ui.label(area(view_area));
+        ui.end_row();
+        ui.label("coverage").on_hover_text("Area of the tiles centred in view over the area of the view; near 100 % when the tiles cover the plane once");
+        ui.label(format!("{:.1} %", 100. * tile_area * in_view as f32 / view_area.max(f32::EPSILON)));
+        ui.end_row();
+    });
+}
+
+/// The translation vectors, the aspect transforms and the edge transforms of `tiling`, with the
+/// affine matrices as rows `[a b c; d e f]` mapping `(x, y)` to `(ax + by + c, dx + ey + f)`, as
+/// Tactile lays them out.
+fn inspector_text(tiling: &IsohedralTiling) -> String {
+    let matrix = |t: &Affine2| {
+        let (m, d) = (t.matrix2, t.translation);
+        format!("[{:9.5} {:9.5} {:9.5}; {:9.5} {:9.5} {:9.5}]", m.x_axis.x, m.y_axis.x, d.x, m.x_axis.y, m.y_axis.y, d.y)
+    };
+    let (t1, t2) = (tiling.t1(), tiling.t2());
+    let mut lines = vec![
+        format!("{}", tiling.tiling_type()),
+        format!("t1 = ({:.5}, {:.5})", t1.x, t1.y),
+        format!("t2 = ({:.5}, {:.5})", t2.x, t2.y),
+    ];
+    lines.extend((0..tiling.num_aspects()).map(|i| format!("aspect {i} {}", matrix(tiling.aspect_transform(i)))));
+    lines.extend(tiling.shapes().enumerate().map(|(i, shape)| {
+        let reversed = if shape.reversed() { " reversed" } else { "" };
+        format!("edge {i} {} class {} {:?}{reversed}", matrix(&shape.transform()), shape.id(), shape.shape())
+    }));
+    lines.join("\n")
+}
+
+fn preset_label(preset: &wallpaper::Preset) -> String {
+    format!("{} ({}x{})", preset.name, preset.width, preset.height)
+}
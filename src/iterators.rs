@@ -7,6 +7,9 @@ use crate::utils::{mat2, Affine2, Vec2, vec2};
 
 const EPSILON: f32 = 1e-7;
 
+/// Furthest a fill reaches along `t1` or `t2`, past which `f32` can't count tiles one by one.
+const MAX_CELLS: f32 = 16_777_216.;
+
 #[derive(Debug)]
 pub struct Shape {
     t: Affine2,
@@ -138,16 +141,59 @@ impl<'tiling> Iterator for TilingShapePartIterator<'tiling> {
     }
 }
 
-#[derive(Debug)]
-pub struct FillRegionStep {
-    pub t1: isize,
-    pub t2: isize,
-    pub aspect: usize,
-    pub transform: Affine2,
+/// A single tile produced while filling a region.
+///
+/// The tile is the prototile placed by [`transform`]. Its lattice coordinates [`t1`], [`t2`] and
+/// [`aspect`] identify it uniquely and determine its colour.
+///
+/// [`transform`]: Tile::transform
+/// [`t1`]: Tile::t1
+/// [`t2`]: Tile::t2
+/// [`aspect`]: Tile::aspect
+#[derive(Debug, Clone, Copy)]
+pub struct Tile {
+    pub(crate) t1: isize,
+    pub(crate) t2: isize,
+    pub(crate) aspect: usize,
+    pub(crate) transform: Affine2,
 }
 
+impl Tile {
+    /// The number of steps along the first translation vector.
+    pub fn t1(&self) -> isize {
+        self.t1
+    }
+
+    /// The number of steps along the second translation vector.
+    pub fn t2(&self) -> isize {
+        self.t2
+    }
+
+    /// The aspect index of this tile, see [`IsohedralTiling::aspect_transform`].
+    pub fn aspect(&self) -> usize {
+        self.aspect
+    }
+
+    /// The transform taking the prototile to this tile.
+    pub fn transform(&self) -> Affine2 {
+        self.transform
+    }
+
+    /// The colour index of this tile in the given tiling, see [`IsohedralTiling::colour`].
+    pub fn colour(&self, tiling: &IsohedralTiling) -> usize {
+        tiling.colour(self.t1, self.t2, self.aspect)
+    }
+
+    /// The key tiles are enumerated by: `(t2, t1, aspect)`, compared in that order, see
+    /// [`FillAlgorithm`].
+    pub fn sort_key(&self) -> (isize, isize, usize) {
+        (self.t2, self.t1, self.aspect)
+    }
+}
+
+/// Iterator over the tiles of a [`FillAlgorithm`], in the order it guarantees.
 pub struct FillRegionIterator<'tiling> {
-    algo: &'tiling FillAlgorithm<'tiling>,
+    algo: FillAlgorithm<'tiling>,
     done: bool,
     x: f32,
     y: f32,
@@ -175,18 +221,13 @@ impl<'tiling> Debug for FillRegionIterator<'tiling> {
 }
 
 impl<'tiling> Iterator for FillRegionIterator<'tiling> {
-    type Item = FillRegionStep;
+    type Item = Tile;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.done {
             None
         } else {
-            let item = FillRegionStep {
-                t1: self.x as isize,
-                t2: self.y as isize,
-                aspect: self.asp,
-                transform: self.transform(),
-            };
+            let item = self.algo.tiling.tile(self.x as isize, self.y as isize, self.asp);
 
             //  Move the iterator step forward
             self.update_state();
@@ -197,7 +238,7 @@ impl<'tiling> Iterator for FillRegionIterator<'tiling> {
 }
 
 impl<'tiling> FillRegionIterator<'tiling> {
-    pub fn new(algo: &'tiling FillAlgorithm<'tiling>, x: f32, y: f32, xlo: f32, xhi: f32) -> Self {
+    pub fn new(algo: FillAlgorithm<'tiling>, x: f32, y: f32, xlo: f32, xhi: f32) -> Self {
         Self {
             algo,
             x,
@@ -216,8 +257,8 @@ impl<'tiling> FillRegionIterator<'tiling> {
             self.asp = 0;
             self.x += 1.0;
             if self.x >= (self.xhi + EPSILON) {
-                self.xlo += self.algo.data[self.call_idx].dxlo;
-                self.xhi += self.algo.data[self.call_idx].dxhi;
+                self.xlo = (self.xlo + self.algo.data[self.call_idx].dxlo).clamp(-MAX_CELLS, MAX_CELLS);
+                self.xhi = (self.xhi + self.algo.data[self.call_idx].dxhi).clamp(-MAX_CELLS, MAX_CELLS);
                 self.y += 1.0;
                 self.x = self.xlo.floor();
                 if self.y.floor() >= self.algo.data[self.call_idx].ymax {
@@ -234,22 +275,25 @@ impl<'tiling> FillRegionIterator<'tiling> {
             }
         }
     }
-
-    fn transform(&self) -> Affine2 {
-        let mut m = *self.algo.tiling.aspect_transform(self.asp);
-        let t1 = self.algo.tiling.t1();
-        let t2 = self.algo.tiling.t2();
-
-        let x = self.x;
-        let y = self.y;
-        m.translation.x += x * t1.x + y * t2.x;
-        m.translation.y += x * t1.y + y * t2.y;
-
-        m
-    }
 }
 
-#[derive(Debug)]
+/// The tiles covering a region of the plane, see [`IsohedralTiling::fill_region`].
+///
+/// The tiles come in a fixed order, row by row: sorted by [`Tile::sort_key`], that is by `t2`,
+/// then `t1`, then `aspect`, each tile once. The order only depends on the tiling and the region,
+/// so exports enumerating the same region come out the same on every run and platform. Tiles
+/// gathered from several fills can be put in the same order with [`sort_tiles`].
+///
+/// A region that isn't finite, or that reaches more than 2²⁴ translations from the origin, has no
+/// tiles.
+///
+/// ```
+/// # use egui_isohedral::{get_tiling_type, tiling::IsohedralTiling};
+/// let tiling = IsohedralTiling::new(get_tiling_type(4));
+/// let tiles = tiling.fill_region(-3., -2., 4., 5.).collect_tiles();
+/// assert!(tiles.windows(2).all(|pair| pair[0].sort_key() < pair[1].sort_key()));
+/// ```
+#[derive(Debug, Clone, Copy)]
 pub struct FillAlgorithm<'tiling> {
     tiling: &'tiling IsohedralTiling,
     num_calls: usize,
@@ -277,6 +321,9 @@ impl<'tiling> FillAlgorithm<'tiling> {
         if det < 0.0 {
             pts.swap(1, 3);
         }
+        if !pts.iter().all(|p| p.x.abs() <= MAX_CELLS && p.y.abs() <= MAX_CELLS) {
+            return algo;
+        }
 
         if (pts[0].y - pts[1].y).abs() < EPSILON {
             algo.fill_fix_y(&pts[0], &pts[1], &pts[2], &pts[3], true);
@@ -335,9 +382,9 @@ impl<'tiling> FillAlgorithm<'tiling> {
 
     fn do_fill(&mut self, a: &Vec2, b: &Vec2, c: &Vec2, d: &Vec2, do_top: bool) {
         self.data[self.num_calls].xlo = a.x;
-        self.data[self.num_calls].dxlo = (d.x - a.x) / (d.y - a.y);
+        self.data[self.num_calls].dxlo = slope(a, d);
         self.data[self.num_calls].xhi = b.x;
-        self.data[self.num_calls].dxhi = (c.x - b.x) / (c.y - b.y);
+        self.data[self.num_calls].dxhi = slope(b, c);
         self.data[self.num_calls].ymin = a.y;
         self.data[self.num_calls].ymax = c.y;
 
@@ -348,27 +395,59 @@ impl<'tiling> FillAlgorithm<'tiling> {
         self.num_calls += 1;
     }
 
-    pub fn iter(&self) -> FillRegionIterator<'_> {
-        FillRegionIterator::new(
-            self,
+    pub fn iter(&self) -> FillRegionIterator<'tiling> {
+        let mut iter = FillRegionIterator::new(
+            *self,
             self.data[0].xlo.floor(),
             self.data[0].ymin.floor(),
             self.data[0].xlo,
             self.data[0].xhi,
-        )
+        );
+        iter.done = self.num_calls == 0;
+        iter
+    }
+
+    /// Collect all the tiles of the region into an owned vector.
+    ///
+    /// Unlike the fill algorithm itself, the result doesn't borrow the tiling, so it can be kept
+    /// around or sent to another thread.
+    pub fn collect_tiles(&self) -> Vec<Tile> {
+        self.iter().collect()
     }
 }
 
-impl<'algo, 'tiling> IntoIterator for &'algo FillAlgorithm<'tiling> {
-    type Item = FillRegionStep;
+impl<'tiling> IntoIterator for FillAlgorithm<'tiling> {
+    type Item = Tile;
 
-    type IntoIter = FillRegionIterator<'algo>;
+    type IntoIter = FillRegionIterator<'tiling>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
+impl<'tiling> IntoIterator for &FillAlgorithm<'tiling> {
+    type Item = Tile;
+
+    type IntoIter = FillRegionIterator<'tiling>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Sort `tiles` into the order of [`FillAlgorithm`] and drop repeated tiles, such as those where
+/// fills of neighbouring regions overlap.
+pub fn sort_tiles(tiles: &mut Vec<Tile>) {
+    tiles.sort_by_key(Tile::sort_key);
+    tiles.dedup_by_key(|tile| tile.sort_key());
+}
+
+/// Change in x per unit of y from `p` to `q`, none if they're level.
+fn slope(p: &Vec2, q: &Vec2) -> f32 {
+    if q.y == p.y { 0. } else { (q.x - p.x) / (q.y - p.y) }
+}
+
 fn sample_at_height(p: &Vec2, q: &Vec2, y: f32) -> Vec2 {
     let t = (y - p.y) / (q.y - p.y);
     vec2((1.0 - t) * p.x + t * q.x, y)
@@ -383,3 +462,33 @@ struct AlgoData {
     dxlo: f32,
     dxhi: f32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_tiling_type, TILING_TYPES};
+
+    #[test]
+    fn fill_order() {
+        let regions = [(-3., -2., 4., 5.), (-20., -15., -12., -9.), (7.5, 3.25, 9., 11.), (-0.1, -0.1, 0.1, 0.1)];
+        for index in 0..TILING_TYPES.len() {
+            let tiling = IsohedralTiling::new(get_tiling_type(index));
+            for (xmin, ymin, xmax, ymax) in regions {
+                let tiles = tiling.fill_region(xmin, ymin, xmax, ymax).collect_tiles();
+                assert!(!tiles.is_empty(), "{} has no tiles in {:?}", tiling.tiling_type(), (xmin, ymin, xmax, ymax));
+                assert!(
+                    tiles.windows(2).all(|pair| pair[0].sort_key() < pair[1].sort_key()),
+                    "{} out of order in {:?}",
+                    tiling.tiling_type(),
+                    (xmin, ymin, xmax, ymax)
+                );
+
+                let mut sorted = tiles.clone();
+                sorted.reverse();
+                sorted.extend_from_slice(&tiles);
+                sort_tiles(&mut sorted);
+                assert!(sorted.iter().map(Tile::sort_key).eq(tiles.iter().map(Tile::sort_key)));
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Layers: further tilings drawn over the design, each with its own type, parameters, edges and
+//! colours, and with an opacity and a blend, for overlaying patterns the way printed textiles do.
+//!
+//! The design is the bottom layer, and the others are drawn over it in order. A layer is edited
+//! by swapping it with the design, so the whole panel works on it, and swapping back. The PNG
+//! export draws the layers as the canvas does; the other exports hold the design alone.
+use eframe::egui;
+use egui_isohedral::{
+    colouring::MAX_COLOURS,
+    error::TilingError,
+    export::ExportOptions,
+    render::{self, Blend, RenderedImage},
+    tiling::IsohedralTiling,
+    Vec2,
+};
+
+use crate::app::App;
+use crate::interface;
+
+
+/// Opacity of a newly added layer.
+const LAYER_OPACITY: f32 = 0.5;
+
+/// A tiling drawn over the design.
+pub struct Layer {
+    /// Index of the tiling type in [`TILING_TYPES`](egui_isohedral::TILING_TYPES).
+    pub tile_type_num: usize,
+    /// Holds the parameters and the colouring.
+    pub tiling: IsohedralTiling,
+    pub edges: Vec<Vec<Vec2>>,
+    /// Fill colour of each colour class.
+    pub palette: [egui::Color32; MAX_COLOURS],
+    pub opacity: f32,
+    pub blend: Blend,
+    pub visible: bool,
+}
+
+impl Layer {
+    /// A copy of the design, in its colours made opaque.
+    pub fn of_design(app: &App) -> Self {
+        Self {
+            tile_type_num: app.tile_type_num,
+            tiling: app.copy_tiling(),
+            edges: app.edges_shapes.clone(),
+            palette: interface::tile_colours(app).map(egui::Color32::to_opaque),
+            opacity: LAYER_OPACITY,
+            blend: Blend::Normal,
+            visible: true,
+        }
+    }
+}
+
+fn blend_name(blend: Blend) -> &'static str {
+    match blend {
+        Blend::Normal => "normal",
+        Blend::Add => "add",
+    }
+}
+
+/// Exchange the tiling of layer `i` with the design's, so it can be edited; each keeps its
+/// colours.
+fn swap(app: &mut App, i: usize) {
+    let layer = &mut app.layers[i];
+    std::mem::swap(&mut layer.tile_type_num, &mut app.tile_type_num);
+    std::mem::swap(&mut layer.tiling, &mut app.tiling);
+    std::mem::swap(&mut layer.edges, &mut app.edges_shapes);
+    app.morph = None;
+    app.selection = None;
+    app.colours = app.tiling.num_colours();
+    app.set_default_params();
+}
+
+/// The layers, each with its settings and buttons to swap, move or remove it.
+pub fn section(app: &mut App, ui: &mut egui::Ui) {
+    let (mut swapped, mut raised, mut removed) = (None, None, None);
+    let count = app.layers.len();
+    for (i, layer) in app.layers.iter_mut().enumerate() {
+        ui.push_id(("layer", i), |ui| {
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut layer.visible, layer.tiling.tiling_type().to_string());
+                ui.add(egui::Slider::new(&mut layer.opacity, 0.0..=1.).text("opacity"));
+                egui::ComboBox::from_id_salt("blend").selected_text(blend_name(layer.blend)).show_ui(ui, |ui| {
+                    for blend in [Blend::Normal, Blend::Add] {
+                        ui.selectable_value(&mut layer.blend, blend, blend_name(blend));
+                    }
+                });
+            });
+            ui.horizontal(|ui| {
+                for colour in &mut layer.palette[..layer.tiling.num_colours().min(MAX_COLOURS)] {
+                    ui.color_edit_button_srgba(colour);
+                }
+                if ui.button("Swap").on_hover_text("Exchange the tiling of the layer and the design, to edit it").clicked() {
+                    swapped = Some(i);
+                }
+                if ui.add_enabled(i > 0, egui::Button::new("⬇")).on_hover_text("Move the layer down").clicked() {
+                    raised = Some(i - 1);
+                }
+                if ui.add_enabled(i + 1 < count, egui::Button::new("⬆")).on_hover_text("Move the layer up").clicked() {
+                    raised = Some(i);
+                }
+                if ui.button("Remove").clicked() {
+                    removed = Some(i);
+                }
+            });
+        });
+    }
+    if let Some(i) = swapped {
+        swap(app, i);
+    }
+    if let Some(i) = raised {
+        app.layers.swap(i, i + 1);
+    }
+    if let Some(i) = removed {
+        app.layers.remove(i);
+    }
+    if ui.button("Add layer").on_hover_text("Put a copy of the design over it, to change the design under the copy").clicked() {
+        let layer = Layer::of_design(app);
+        app.layers.push(layer);
+    }
+}
+
+/// Draw the visible layers over `image`, rendered with `options` but each in its own colours.
+pub fn composite(app: &App, options: &ExportOptions, image: &mut RenderedImage) -> Result<(), TilingError> {
+    for layer in app.layers.iter().filter(|layer| layer.visible) {
+        let options = ExportOptions {
+            colours: layer.palette.map(|c| c.to_srgba_unmultiplied()).to_vec(),
+            background: [0; 4],
+            ..options.clone()
+        };
+        image.composite(&render::render(&layer.tiling, &layer.edges, &options)?, layer.opacity, layer.blend);
+    }
+    Ok(())
+}
+
@@ -0,0 +1,38 @@
+//! Isohedral tilings of the plane, ported from Craig S. Kaplan's
+//! [Tactile](https://isohedral.ca/software/tactile/) library.
+//!
+//! Pick one of the tiling types with [`get_tiling_type`], create an [`IsohedralTiling`] from it
+//! and fill a region of the plane with [`IsohedralTiling::fill_region`]. Each [`Tile`] gives the
+//! transform to apply to the prototile outline built by [`IsohedralTiling::outline`].
+//!
+//! [`IsohedralTiling`]: tiling::IsohedralTiling
+//! [`IsohedralTiling::fill_region`]: tiling::IsohedralTiling::fill_region
+//! [`IsohedralTiling::outline`]: tiling::IsohedralTiling::outline
+//! [`Tile`]: iterators::Tile
+pub mod tiling;
+pub mod colouring;
+pub mod palette;
+pub mod iterators;
+pub mod edges;
+pub mod error;
+pub mod export;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod morph;
+pub mod offset;
+pub mod hatch;
+pub mod mask;
+#[cfg(feature = "glyphs")]
+pub mod glyph;
+#[cfg(feature = "render")]
+pub mod render;
+pub mod tessellate;
+#[cfg(feature = "widget")]
+pub mod view;
+pub mod weave;
+mod utils;
+mod data;
+
+pub use data::{get_tiling_type, try_get_tiling_type, TILING_TYPES};
+pub use error::TilingError;
+pub use utils::{centroid, clip_to_rect, contains, mat2, triangulate, vec2, Affine2, Mat2, Vec2};
@@ -0,0 +1,82 @@
+//! Links to a configuration: the tiling type, parameters, number of colours and theme in the
+//! fragment of a URL, like `#type=4&params=0.12,0.5&colours=3&theme=...`.
+//!
+//! `theme` holds the twelve colours of the egui theme as comma-separated `r,g,b` triples, as in
+//! a [project file](crate::project), so a link shows the tiling in the same colours; it is
+//! optional. The app opens the link given after `--link`.
+use eframe::egui;
+use egui_colors::tokens::ThemeColor;
+use egui_isohedral::{colouring::MAX_COLOURS, get_tiling_type, tiling::IsohedralTiling, TILING_TYPES};
+
+use crate::app::App;
+use crate::project;
+
+
+/// The fragment, without the `#`, of a link to the app's tiling.
+pub fn fragment(app: &App) -> String {
+    let params: Vec<String> = app.params[..app.tiling.num_params()].iter().map(f32::to_string).collect();
+    let theme: Vec<String> = app.colorix.theme().iter().flat_map(ThemeColor::rgb).map(|v| v.to_string()).collect();
+    format!("type={}&params={}&colours={}&theme={}", TILING_TYPES[app.tile_type_num], params.join(","), app.colours, theme.join(","))
+}
+
+/// Show the tiling of a link, or of just its fragment, in its theme if it has one, applied to
+/// `ctx`. Leaves the app unchanged if the link doesn't describe a tiling.
+pub fn open(app: &mut App, ctx: &egui::Context, link: &str) -> Result<(), String> {
+    let fragment = link.rsplit_once('#').map_or(link, |(_, fragment)| fragment);
+    let (mut tiling_type, mut params, mut colours, mut theme) = (None, vec![], app.colours, None);
+    for pair in fragment.split('&').filter(|pair| !pair.is_empty()) {
+        let (key, value) = pair.split_once('=').ok_or_else(|| format!("{pair:?} is not a key=value pair"))?;
+        let whole = || value.parse::<usize>().map_err(|_| format!("{key} takes a whole number, not {value:?}"));
+        let numbers = || {
+            value
+                .split(',')
+                .filter(|p| !p.is_empty())
+                .map(|p| p.parse::<f32>().map_err(|_| format!("{p:?} is not a number")))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        match key {
+            "type" => tiling_type = Some(whole()?),
+            "params" => params = numbers()?,
+            "colours" => colours = whole()?,
+            "theme" => theme = Some(project::parse_theme(&numbers()?).ok_or("a theme needs twelve r,g,b colours")?),
+            _ => return Err(format!("unknown setting {key:?}")),
+        }
+    }
+    show(app, tiling_type.ok_or("the link has no tiling type")?, &params, colours)?;
+    if let Some(theme) = theme {
+        app.colorix.update_theme(ctx, theme);
+    }
+    Ok(())
+}
+
+/// Show tiling type IH`ih` with the parameters `params`, its default ones if there are none, and
+/// `colours` colours. Edge shapes are kept if the tiling type stays the same, and otherwise made
+/// straight. Leaves the app unchanged if the type or parameters aren't valid.
+pub fn show(app: &mut App, ih: usize, params: &[f32], colours: usize) -> Result<(), String> {
+    let type_num = TILING_TYPES.iter().position(|&t| t == ih).ok_or_else(|| format!("IH{ih:02} is not a valid tiling type"))?;
+    let tiling = match params {
+        [] => IsohedralTiling::new(get_tiling_type(type_num)),
+        _ => IsohedralTiling::builder()
+            .tiling_type(get_tiling_type(type_num))
+            .parameters(params)
+            .build()
+            .map_err(|err| err.to_string())?,
+    };
+
+    app.morph = None;
+    let same_type = type_num == app.tile_type_num && app.edges_shapes.len() == tiling.num_edge_shapes();
+    app.tile_type_num = type_num;
+    app.tiling = tiling;
+    if !same_type {
+        app.set_default_edges();
+    }
+    app.set_default_params();
+    app.colours = colours.clamp(1, MAX_COLOURS);
+    app.apply_colouring();
+    Ok(())
+}
+
+/// The link given on the command line after `--link`.
+pub fn from_args() -> Option<String> {
+    std::env::args().skip_while(|arg| arg != "--link").nth(1)
+}
@@ -1,10 +1,33 @@
 mod app;
-pub mod tiling;
-mod iterators;
-mod utils;
-mod data;
+mod cache;
+mod clipboard;
+mod console;
+#[cfg(any(feature = "set-wallpaper", feature = "print"))]
+mod desktop;
+mod edge_editor;
+mod history;
 mod interface;
+mod layers;
+mod link;
+mod measure;
+mod project;
+mod recent;
+mod recorder;
+mod reference;
+#[cfg(feature = "remote")]
+mod remote;
+mod slideshow;
+mod thumbnails;
+mod tile_painter;
+mod timeline;
+mod watch;
 
 fn main() -> Result<(), eframe::Error> {
-    app::init()   
+    // `--screensaver` runs full-screen, animating on its own until a key or button is pressed.
+    let screensaver = std::env::args().skip(1).any(|arg| arg == "--screensaver");
+    // `--msaa <samples>` asks for a multisampled window, smoothing edges beyond egui's feathering.
+    let multisampling = std::env::args().skip_while(|arg| arg != "--msaa").nth(1).and_then(|samples| samples.parse().ok()).unwrap_or(0);
+    // `--link <url>` opens the tiling of a link's fragment, see `link`, and `--watch <file>`
+    // follows the settings in a file, see `watch`.
+    app::init(screensaver, multisampling)
 }
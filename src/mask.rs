@@ -0,0 +1,391 @@
+//! Masking a tiling to a silhouette, such as a logo or an animal read from an SVG file.
+//!
+//! A [`Mask`] is a set of closed loops filled by the even-odd rule, so a loop inside another cuts
+//! a hole. To clip convex polygons, like the triangles of a mesh, the mask is cut at the height
+//! of every vertex into trapezoids, which are convex; the pieces of a polygon inside the mask are
+//! its intersections with the trapezoids. Loops must not cross each other or themselves.
+use crate::error::TilingError;
+use crate::utils::{vec2, Vec2};
+
+
+/// Number of segments every curve of an SVG path is flattened into.
+const CURVE_SEGMENTS: usize = 16;
+
+/// A region of the plane bounded by closed loops, see the [module docs](self).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Mask {
+    loops: Vec<Vec<Vec2>>,
+    trapezoids: Vec<[Vec2; 4]>,
+    /// The horizontal slabs the trapezoids fill: the top and bottom of each, and the range of its
+    /// trapezoids, from top to bottom.
+    slabs: Vec<(f32, f32, std::ops::Range<usize>)>,
+}
+
+impl Mask {
+    /// The region inside an odd number of `loops`. Loops with fewer than three points are
+    /// dropped.
+    pub fn new(loops: Vec<Vec<Vec2>>) -> Self {
+        let loops: Vec<Vec<Vec2>> = loops.into_iter().filter(|l| l.len() >= 3).collect();
+        let edges: Vec<(Vec2, Vec2)> = loops
+            .iter()
+            .flat_map(|l| (0..l.len()).map(move |i| (l[i], l[(i + 1) % l.len()])))
+            .filter(|(a, b)| a.y != b.y)
+            .map(|(a, b)| if a.y < b.y { (a, b) } else { (b, a) })
+            .collect();
+        let mut ys: Vec<f32> = loops.iter().flatten().map(|p| p.y).collect();
+        ys.sort_by(f32::total_cmp);
+        ys.dedup();
+
+        let mut trapezoids = vec![];
+        let mut slabs = vec![];
+        let x_at = |(a, b): (Vec2, Vec2), y: f32| a.x + (b.x - a.x) * (y - a.y) / (b.y - a.y);
+        for pair in ys.windows(2) {
+            let (top, bottom) = (pair[0], pair[1]);
+            let middle = (top + bottom) / 2.;
+            let mut crossing: Vec<(Vec2, Vec2)> = edges.iter().copied().filter(|&(a, b)| a.y <= top && b.y >= bottom).collect();
+            crossing.sort_by(|&e, &f| x_at(e, middle).total_cmp(&x_at(f, middle)));
+            let start = trapezoids.len();
+            // Inside runs between every other pair of edges.
+            for pair in crossing.chunks_exact(2) {
+                let (left, right) = (pair[0], pair[1]);
+                trapezoids.push([vec2(x_at(left, top), top), vec2(x_at(right, top), top), vec2(x_at(right, bottom), bottom), vec2(x_at(left, bottom), bottom)]);
+            }
+            slabs.push((top, bottom, start..trapezoids.len()));
+        }
+        Self { loops, trapezoids, slabs }
+    }
+
+    /// The mask made of the shapes of an SVG document: its `path`, `polygon`, `rect`, `circle`
+    /// and `ellipse` elements, in the document's own coordinates. Transforms and styles are
+    /// ignored, and every path is taken as closed.
+    pub fn from_svg(svg: &str) -> Result<Self, TilingError> {
+        let mut loops = vec![];
+        for (start, _) in svg.match_indices('<') {
+            let Some(end) = svg[start..].find('>') else {
+                break;
+            };
+            let tag = &svg[start + 1..start + end];
+            let name = tag.split(|c: char| c.is_whitespace() || c == '/').next().unwrap_or_default();
+            let number = |attr: &str| {
+                attribute(tag, attr).and_then(|v| v.trim().parse::<f32>().ok()).filter(|v| v.is_finite()).unwrap_or(0.)
+            };
+            match name {
+                "path" => loops.extend(parse_path(attribute(tag, "d").unwrap_or_default())?),
+                "polygon" | "polyline" => {
+                    let values: Vec<f32> = attribute(tag, "points")
+                        .unwrap_or_default()
+                        .split(|c: char| c.is_whitespace() || c == ',')
+                        .filter(|s| !s.is_empty())
+                        .map(|s| s.parse::<f32>().ok().filter(|v| v.is_finite()).ok_or(TilingError::SvgPathData(start)))
+                        .collect::<Result<_, _>>()?;
+                    loops.push(values.chunks_exact(2).map(|p| vec2(p[0], p[1])).collect());
+                }
+                "rect" => {
+                    let (x, y, w, h) = (number("x"), number("y"), number("width"), number("height"));
+                    loops.push(vec![vec2(x, y), vec2(x + w, y), vec2(x + w, y + h), vec2(x, y + h)]);
+                }
+                "circle" | "ellipse" => {
+                    let (r, rx, ry) = (number("r"), number("rx"), number("ry"));
+                    let (rx, ry) = if name == "circle" { (r, r) } else { (rx, ry) };
+                    let centre = vec2(number("cx"), number("cy"));
+                    let steps = CURVE_SEGMENTS * 4;
+                    loops.push(
+                        (0..steps)
+                            .map(|i| {
+                                let (sin, cos) = (std::f32::consts::TAU * i as f32 / steps as f32).sin_cos();
+                                centre + vec2(rx * cos, ry * sin)
+                            })
+                            .collect(),
+                    );
+                }
+                _ => {}
+            }
+        }
+        let mask = Mask::new(loops);
+        if mask.loops.is_empty() { Err(TilingError::NoSvgShape) } else { Ok(mask) }
+    }
+
+    /// The closed loops bounding the mask.
+    pub fn loops(&self) -> &[Vec<Vec2>] {
+        &self.loops
+    }
+
+    /// The smallest and largest coordinates of the mask.
+    pub fn bounds(&self) -> (Vec2, Vec2) {
+        self.loops.iter().flatten().fold(
+            (vec2(f32::INFINITY, f32::INFINITY), vec2(f32::NEG_INFINITY, f32::NEG_INFINITY)),
+            |(lo, hi), p| (vec2(lo.x.min(p.x), lo.y.min(p.y)), vec2(hi.x.max(p.x), hi.y.max(p.y))),
+        )
+    }
+
+    /// The mask scaled so its larger side is `size` long, and moved so its bounds are centred on
+    /// `centre`.
+    pub fn placed(&self, centre: Vec2, size: f32) -> Self {
+        let (lo, hi) = self.bounds();
+        let middle = (lo + hi) * 0.5;
+        let scale = size / (hi.x - lo.x).max(hi.y - lo.y).max(f32::EPSILON);
+        Mask::new(self.loops.iter().map(|l| l.iter().map(|&p| centre + (p - middle) * scale).collect()).collect())
+    }
+
+    /// Whether `p` is inside the mask.
+    pub fn contains(&self, p: Vec2) -> bool {
+        self.loops.iter().filter(|l| crate::utils::contains(l, p)).count() % 2 == 1
+    }
+
+    /// The pieces of the convex `polygon` inside the mask, each of them convex.
+    pub fn clip_convex(&self, polygon: &[Vec2]) -> Vec<Vec<Vec2>> {
+        let ((lo, hi), mut pieces) = (bounds(polygon), vec![]);
+        let first = self.slabs.partition_point(|&(_, bottom, _)| bottom <= lo.y);
+        for (top, _, range) in &self.slabs[first..] {
+            if *top >= hi.y {
+                break;
+            }
+            for trapezoid in &self.trapezoids[range.clone()] {
+                if trapezoid[1].x.max(trapezoid[2].x) <= lo.x || trapezoid[0].x.min(trapezoid[3].x) >= hi.x {
+                    continue;
+                }
+                let piece = trapezoid.iter().zip(trapezoid.iter().cycle().skip(1)).fold(polygon.to_vec(), |piece, (&a, &b)| clip_half_plane(&piece, a, b));
+                if piece.len() >= 3 {
+                    pieces.push(piece);
+                }
+            }
+        }
+        pieces
+    }
+}
+
+/// The part of the convex `polygon` on the side of the line from `a` to `b` where
+/// `(b - a).perp_dot(p - a)` is positive, the side the trapezoids of a [`Mask`] have their inside
+/// on.
+pub(crate) fn clip_half_plane(polygon: &[Vec2], a: Vec2, b: Vec2) -> Vec<Vec2> {
+    if a == b {
+        return polygon.to_vec();
+    }
+    let side = |p: Vec2| (b - a).perp_dot(p - a);
+    let n = polygon.len();
+    let mut out = Vec::with_capacity(n + 1);
+    for i in 0..n {
+        let (p, q) = (polygon[i], polygon[(i + 1) % n]);
+        let (sp, sq) = (side(p), side(q));
+        if sp >= 0. {
+            out.push(p);
+        }
+        if (sp >= 0.) != (sq >= 0.) {
+            out.push(p + (q - p) * (sp / (sp - sq)));
+        }
+    }
+    out
+}
+
+fn bounds(points: &[Vec2]) -> (Vec2, Vec2) {
+    points.iter().fold(
+        (vec2(f32::INFINITY, f32::INFINITY), vec2(f32::NEG_INFINITY, f32::NEG_INFINITY)),
+        |(lo, hi), p| (vec2(lo.x.min(p.x), lo.y.min(p.y)), vec2(hi.x.max(p.x), hi.y.max(p.y))),
+    )
+}
+
+/// The value of the attribute `name` of a tag's text.
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let mut offset = 0;
+    while let Some(i) = tag[offset..].find(name) {
+        let at = offset + i;
+        offset = at + name.len();
+        if !tag[..at].ends_with(char::is_whitespace) {
+            continue;
+        }
+        let Some(value) = tag[offset..].trim_start().strip_prefix('=').map(str::trim_start) else {
+            continue;
+        };
+        let quote = value.chars().next().filter(|&c| c == '"' || c == '\'')?;
+        let value = &value[1..];
+        return value.find(quote).map(|end| &value[..end]);
+    }
+    None
+}
+
+/// The loops of the SVG path data `d`, with curves flattened.
+fn parse_path(d: &str) -> Result<Vec<Vec<Vec2>>, TilingError> {
+    let mut scanner = Scanner { bytes: d.as_bytes(), pos: 0 };
+    let (mut loops, mut current) = (vec![], vec![]);
+    let (mut cursor, mut start) = (Vec2::ZERO, Vec2::ZERO);
+    // The second control point of the last curve, reflected by the smooth curve commands.
+    let mut control: Option<(u8, Vec2)> = None;
+    let mut command = None;
+    loop {
+        scanner.skip_separators();
+        let Some(&next) = scanner.bytes.get(scanner.pos) else {
+            break;
+        };
+        if next.is_ascii_alphabetic() {
+            scanner.pos += 1;
+            command = Some(next);
+        } else if command.is_none_or(|c: u8| c.eq_ignore_ascii_case(&b'z')) {
+            return Err(TilingError::SvgPathData(scanner.pos));
+        }
+        let c = command.unwrap_or_default();
+        let relative = c.is_ascii_lowercase();
+        let origin = if relative { cursor } else { Vec2::ZERO };
+        let error = TilingError::SvgPathData(scanner.pos);
+        let point = |scanner: &mut Scanner| scanner.point().map(|p| origin + p).ok_or(error);
+        let kind = c.to_ascii_uppercase();
+        // Drawing on after a close starts a new loop where the last one started.
+        if current.is_empty() && kind != b'M' {
+            current.push(cursor);
+        }
+        match kind {
+            b'M' => {
+                let p = point(&mut scanner)?;
+                loops.push(std::mem::take(&mut current));
+                current.push(p);
+                (cursor, start) = (p, p);
+                // Further coordinate pairs are lines.
+                command = Some(if relative { b'l' } else { b'L' });
+            }
+            b'L' => cursor = point(&mut scanner)?,
+            b'H' => cursor.x = origin.x + scanner.number().ok_or(error)?,
+            b'V' => cursor.y = origin.y + scanner.number().ok_or(error)?,
+            b'C' | b'S' | b'Q' | b'T' => {
+                let reflected = match control {
+                    Some((last, c)) if (kind == b'S' && matches!(last, b'C' | b'S')) || (kind == b'T' && matches!(last, b'Q' | b'T')) => cursor + (cursor - c),
+                    _ => cursor,
+                };
+                let (c1, c2, end) = match kind {
+                    b'C' => (point(&mut scanner)?, point(&mut scanner)?, point(&mut scanner)?),
+                    b'S' => (reflected, point(&mut scanner)?, point(&mut scanner)?),
+                    b'Q' => {
+                        let c = point(&mut scanner)?;
+                        (c, c, point(&mut scanner)?)
+                    }
+                    _ => (reflected, reflected, point(&mut scanner)?),
+                };
+                let p0 = cursor;
+                current.extend((1..=CURVE_SEGMENTS).map(|i| {
+                    let t = i as f32 / CURVE_SEGMENTS as f32;
+                    let u = 1. - t;
+                    if matches!(kind, b'Q' | b'T') {
+                        p0 * (u * u) + c1 * (2. * u * t) + end * (t * t)
+                    } else {
+                        p0 * (u * u * u) + c1 * (3. * u * u * t) + c2 * (3. * u * t * t) + end * (t * t * t)
+                    }
+                }));
+                control = Some((kind, c2));
+                cursor = end;
+                continue;
+            }
+            b'A' => {
+                let (rx, ry, rotation) = (scanner.number().ok_or(error)?, scanner.number().ok_or(error)?, scanner.number().ok_or(error)?);
+                let (large, sweep) = (scanner.flag().ok_or(error)?, scanner.flag().ok_or(error)?);
+                let end = point(&mut scanner)?;
+                arc(cursor, rx, ry, rotation.to_radians(), large, sweep, end, &mut current);
+                cursor = end;
+            }
+            b'Z' => {
+                loops.push(std::mem::take(&mut current));
+                cursor = start;
+                control = None;
+                continue;
+            }
+            _ => return Err(error),
+        }
+        if kind != b'M' && kind != b'A' {
+            current.push(cursor);
+        }
+        control = None;
+    }
+    loops.push(current);
+    Ok(loops)
+}
+
+/// Append the points of an elliptical arc from `p0` to `p1` to `out`, as described in the SVG
+/// specification's implementation notes.
+#[allow(clippy::too_many_arguments)]
+fn arc(p0: Vec2, rx: f32, ry: f32, rotation: f32, large: bool, sweep: bool, p1: Vec2, out: &mut Vec<Vec2>) {
+    let (mut rx, mut ry) = (rx.abs(), ry.abs());
+    if p0 == p1 {
+        return;
+    }
+    if rx == 0. || ry == 0. {
+        out.push(p1);
+        return;
+    }
+    let (sin, cos) = rotation.sin_cos();
+    let half = (p0 - p1) * 0.5;
+    let (x1, y1) = (cos * half.x + sin * half.y, -sin * half.x + cos * half.y);
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if lambda > 1. {
+        (rx, ry) = (rx * lambda.sqrt(), ry * lambda.sqrt());
+    }
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let sign = if large == sweep { -1. } else { 1. };
+    let coefficient = sign * (numerator / denominator).max(0.).sqrt();
+    let (cx, cy) = (coefficient * rx * y1 / ry, -coefficient * ry * x1 / rx);
+    let middle = (p0 + p1) * 0.5;
+    let centre = vec2(cos * cx - sin * cy + middle.x, sin * cx + cos * cy + middle.y);
+
+    let angle = |u: Vec2, v: Vec2| u.perp_dot(v).atan2(u.dot(v));
+    let start = angle(vec2(1., 0.), vec2((x1 - cx) / rx, (y1 - cy) / ry));
+    let mut delta = angle(vec2((x1 - cx) / rx, (y1 - cy) / ry), vec2((-x1 - cx) / rx, (-y1 - cy) / ry));
+    if !sweep && delta > 0. {
+        delta -= std::f32::consts::TAU;
+    } else if sweep && delta < 0. {
+        delta += std::f32::consts::TAU;
+    }
+    out.extend((1..=CURVE_SEGMENTS).map(|i| {
+        let (s, c) = (start + delta * i as f32 / CURVE_SEGMENTS as f32).sin_cos();
+        let (x, y) = (rx * c, ry * s);
+        centre + vec2(cos * x - sin * y, sin * x + cos * y)
+    }));
+}
+
+/// Reads the numbers of SVG path data.
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    fn skip_separators(&mut self) {
+        while self.bytes.get(self.pos).is_some_and(|b| b.is_ascii_whitespace() || *b == b',') {
+            self.pos += 1;
+        }
+    }
+
+    fn number(&mut self) -> Option<f32> {
+        self.skip_separators();
+        let start = self.pos;
+        let mut seen_dot = false;
+        let mut seen_exponent = false;
+        while let Some(&b) = self.bytes.get(self.pos) {
+            let first = self.pos == start;
+            let after_exponent = self.pos > start && matches!(self.bytes[self.pos - 1], b'e' | b'E');
+            match b {
+                b'+' | b'-' if first || after_exponent => {}
+                b'0'..=b'9' => {}
+                // A second dot starts the next number, as in `1.5.5`.
+                b'.' if !seen_dot && !seen_exponent => seen_dot = true,
+                b'e' | b'E' if !first && !seen_exponent => seen_exponent = true,
+                _ => break,
+            }
+            self.pos += 1;
+        }
+        // Too many digits overflow to infinity.
+        std::str::from_utf8(&self.bytes[start..self.pos]).ok()?.parse().ok().filter(|v: &f32| v.is_finite())
+    }
+
+    fn point(&mut self) -> Option<Vec2> {
+        Some(vec2(self.number()?, self.number()?))
+    }
+
+    /// An arc flag, which may be written without a separator after it.
+    fn flag(&mut self) -> Option<bool> {
+        self.skip_separators();
+        let flag = match self.bytes.get(self.pos)? {
+            b'0' => false,
+            b'1' => true,
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(flag)
+    }
+}
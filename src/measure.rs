@@ -0,0 +1,97 @@
+//! Measuring the tiling on the canvas: click two points to read the distance between them, or
+//! three points to read the angle at the second. Points near a tile's corner snap to it, so
+//! edges and corner angles can be measured exactly. Distances are in world units, those of the
+//! tiling's vertices, or the physical unit set for the exports.
+use eframe::egui;
+use egui_isohedral::Vec2;
+
+use crate::app::App;
+
+
+/// How near a corner, in points on the screen, a click snaps to it.
+const SNAP_DISTANCE: f32 = 10.;
+
+/// State of the measuring tool.
+#[derive(Default)]
+pub struct Measure {
+    /// Clicks on the canvas place points instead of doing nothing.
+    pub active: bool,
+    /// The points placed, in world space, at most three.
+    points: Vec<Vec2>,
+}
+
+impl Measure {
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+}
+
+/// The distance between two points, or the angle at the second of three, as text. Lengths are in
+/// the physical unit when one is set, as for the exports.
+pub fn reading(app: &App) -> Option<String> {
+    let decimals = app.decimals;
+    let length = |a: Vec2, b: Vec2| {
+        let length = (b - a).length();
+        if app.physical_units {
+            format!("{:.decimals$} {}", length * app.physical.unit_length, app.physical.unit.suffix())
+        } else {
+            format!("{length:.decimals$}")
+        }
+    };
+    match app.measure.points[..] {
+        [a, b] => Some(format!("distance {}", length(a, b))),
+        [a, b, c] => {
+            let (u, v) = (a - b, c - b);
+            let angle = u.perp_dot(v).atan2(u.dot(v)).abs().to_degrees();
+            Some(format!("angle {angle:.1}°  sides {}, {}", length(a, b), length(b, c)))
+        }
+        _ => None,
+    }
+}
+
+/// The world position under `pos`, or the tile corner within [`SNAP_DISTANCE`] of it.
+fn snapped(app: &App, pos: egui::Pos2) -> Vec2 {
+    let world = app.view.to_world(pos);
+    let reach = SNAP_DISTANCE / app.view.scale;
+    let tiling = &app.tiling;
+    app.tile_cache
+        .tiles()
+        .flat_map(|(_, transform)| (0..tiling.num_vertices()).map(|i| transform.transform_point2(*tiling.vertex(i))))
+        .filter(|&corner| (corner - world).length() <= reach)
+        .min_by(|&a, &b| (a - world).length().total_cmp(&(b - world).length()))
+        .unwrap_or(world)
+}
+
+/// Place a point at a click on the canvas, starting over after three.
+pub fn click(app: &mut App, pos: egui::Pos2) {
+    let point = snapped(app, pos);
+    let measure = &mut app.measure;
+    if measure.points.len() == 3 {
+        measure.points.clear();
+    }
+    measure.points.push(point);
+}
+
+/// Draw the points and the lines between them, with the reading, and mark where a click at
+/// `hover` would place a point.
+pub fn draw(app: &App, painter: &egui::Painter, hover: Option<egui::Pos2>) {
+    let visuals = painter.ctx().style().visuals.clone();
+    let colour = visuals.warn_fg_color;
+    let stroke = egui::Stroke::new(2., colour);
+    let points: Vec<egui::Pos2> = app.measure.points.iter().map(|&p| app.view.to_screen(p)).collect();
+    for pair in points.windows(2) {
+        painter.line_segment([pair[0], pair[1]], stroke);
+    }
+    for &point in &points {
+        painter.circle_filled(point, 4., colour);
+    }
+    if let Some(pos) = hover {
+        painter.circle_stroke(app.view.to_screen(snapped(app, pos)), 6., stroke);
+    }
+    if let (Some(&last), Some(text)) = (points.last(), reading(app)) {
+        let galley = painter.layout_no_wrap(text, egui::FontId::monospace(13.), visuals.text_color());
+        let rect = egui::Align2::LEFT_BOTTOM.anchor_size(last + egui::vec2(10., -10.), galley.size()).expand(4.);
+        painter.rect_filled(rect, 4., visuals.extreme_bg_color);
+        painter.galley(rect.min + egui::vec2(4., 4.), galley, visuals.text_color());
+    }
+}
@@ -0,0 +1,102 @@
+//! Animated transitions between two tilings whose prototiles have the same number of vertices.
+//!
+//! A [`Morph`] pairs up the outlines of both prototiles vertex by vertex and interpolates them,
+//! along with the placement of every tile. The intermediate frames don't tile the plane; they are
+//! a visual effect for demos and videos.
+use crate::edges::resample;
+use crate::error::TilingError;
+use crate::tiling::IsohedralTiling;
+use crate::utils::{Affine2, Mat2, Vec2};
+
+
+/// Number of points each edge of the tile contributes to an interpolated outline.
+const EDGE_POINTS: usize = 16;
+
+/// A transition from one tiling to another, see the module documentation.
+#[derive(Debug, Clone)]
+pub struct Morph {
+    from: Side,
+    to: Side,
+}
+
+/// What a [`Morph`] keeps of each of its two tilings.
+#[derive(Debug, Clone)]
+struct Side {
+    outline: Vec<Vec2>,
+    t1: Vec2,
+    t2: Vec2,
+    aspects: Vec<Affine2>,
+}
+
+impl Side {
+    fn new(tiling: &IsohedralTiling, edges: &[Vec<Vec2>], segments: usize) -> Self {
+        let sampled = tiling.sample_edges(edges, segments);
+        let mut outline = Vec::with_capacity(tiling.num_vertices() * EDGE_POINTS);
+        for e in tiling.shapes() {
+            let t = e.transform();
+            let mut edge: Vec<Vec2> = sampled[e.id()].iter().map(|&p| t.transform_point2(p)).collect();
+            if e.reversed() {
+                edge.reverse();
+            }
+            // Each edge starts where the previous one ended.
+            outline.extend(resample(&edge, EDGE_POINTS + 1).into_iter().take(EDGE_POINTS));
+        }
+        let aspects = (0..tiling.num_aspects()).map(|i| *tiling.aspect_transform(i)).collect();
+        Self { outline, t1: *tiling.t1(), t2: *tiling.t2(), aspects }
+    }
+
+    fn transform(&self, t1: isize, t2: isize, aspect: usize) -> Affine2 {
+        let mut transform = self.aspects[aspect % self.aspects.len()];
+        transform.translation = transform.translation + self.t1 * t1 as f32 + self.t2 * t2 as f32;
+        transform
+    }
+}
+
+impl Morph {
+    /// Prepare a transition between two tilings with their edge shapes, sampling each edge shape
+    /// with about `segments` segments.
+    ///
+    /// Fails with [`TilingError::VertexCountMismatch`] unless both prototiles have the same number
+    /// of vertices.
+    pub fn new(
+        from: &IsohedralTiling,
+        from_edges: &[Vec<Vec2>],
+        to: &IsohedralTiling,
+        to_edges: &[Vec<Vec2>],
+        segments: usize,
+    ) -> Result<Self, TilingError> {
+        if from.num_vertices() != to.num_vertices() {
+            return Err(TilingError::VertexCountMismatch { from: from.num_vertices(), to: to.num_vertices() });
+        }
+        let from = Side::new(from, from_edges, segments);
+        let mut to = Side::new(to, to_edges, segments);
+        // Run both outlines the same way round, starting from vertex 0, so they don't turn inside out.
+        if signed_area(&from.outline).signum() != signed_area(&to.outline).signum() {
+            to.outline[1..].reverse();
+        }
+        Ok(Self { from, to })
+    }
+
+    /// The prototile outline at `t`, running from the first tiling's at `0` to the second's at `1`.
+    pub fn outline(&self, t: f32) -> Vec<Vec2> {
+        self.from.outline.iter().zip(&self.to.outline).map(|(&a, &b)| a + (b - a) * t).collect()
+    }
+
+    /// The transform of the tile at lattice position `(t1, t2)` with the given aspect at `t`.
+    ///
+    /// The aspect is taken modulo the number of aspects of each tiling, so tiles of the first
+    /// tiling all move somewhere, even if the second has fewer aspects.
+    pub fn transform(&self, t1: isize, t2: isize, aspect: usize, t: f32) -> Affine2 {
+        let (a, b) = (self.from.transform(t1, t2, aspect), self.to.transform(t1, t2, aspect));
+        let lerp = |p: Vec2, q: Vec2| p + (q - p) * t;
+        Affine2 {
+            matrix2: Mat2 { x_axis: lerp(a.matrix2.x_axis, b.matrix2.x_axis), y_axis: lerp(a.matrix2.y_axis, b.matrix2.y_axis) },
+            translation: lerp(a.translation, b.translation),
+        }
+    }
+}
+
+fn signed_area(points: &[Vec2]) -> f32 {
+    let n = points.len();
+    (0..n).map(|i| points[i].perp_dot(points[(i + 1) % n])).sum::<f32>() / 2.
+}
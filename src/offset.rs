@@ -0,0 +1,228 @@
+//! Offsetting tile outlines inward or outward by a fixed distance.
+//!
+//! Unlike scaling toward the centroid, an offset keeps the distance between the old and the new
+//! outline the same all around, so the gaps between inset tiles are equally wide whatever the
+//! tile's shape. Every edge is moved along its normal, neighbouring edges are joined as chosen by
+//! [`OffsetOptions::join`], and the loops that appear where the outline is narrower than the
+//! offset are cut away.
+use crate::tessellate::{dedup, normal, LineJoin};
+use crate::utils::{vec2, Vec2};
+
+
+/// Parameters for [`inset`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OffsetOptions {
+    /// The join at the corners where the moved edges come apart: the reflex corners of an inset
+    /// and the convex ones of an outset.
+    pub join: LineJoin,
+    /// Maximum ratio between the miter length and the offset distance.
+    pub miter_limit: f32,
+    /// Maximum deviation of round joins from the true arc.
+    pub tolerance: f32,
+}
+
+impl Default for OffsetOptions {
+    fn default() -> Self {
+        Self {
+            join: LineJoin::Miter,
+            miter_limit: 4.,
+            tolerance: 0.005,
+        }
+    }
+}
+
+/// The closed `outline` moved inward by `distance`, or outward if it is negative.
+///
+/// The result has the winding of `outline`. Where an inset splits the outline into several
+/// pieces only the largest is kept, and it is empty when nothing is left of the outline.
+pub fn inset(outline: &[Vec2], distance: f32, options: &OffsetOptions) -> Vec<Vec2> {
+    let mut points = dedup(outline);
+    if points.len() < 3 || distance == 0. {
+        return points;
+    }
+    // Work counter-clockwise, so the inside is to the left of every edge.
+    let clockwise = signed_area(&points) < 0.;
+    if clockwise {
+        points.reverse();
+    }
+
+    let n = points.len();
+    let mut lines: Vec<Line> = (0..n).map(|i| Line::new(&points, i, (i + 1) % n, distance)).collect();
+    // Edges shorter than the offset, on curves or between close corners, come out reversed: drop
+    // them and let their neighbours meet instead, until every edge runs the way it did.
+    while lines.len() >= 3 {
+        let count = lines.len();
+        let reversed = (0..count).find(|&i| {
+            let (prev, line, next) = (&lines[(i + count - 1) % count], &lines[i], &lines[(i + 1) % count]);
+            let start = prev.meet(line, distance).unwrap_or(line.from);
+            let end = line.meet(next, distance).unwrap_or(line.to);
+            (end - start).dot(line.dir) < 0.
+        });
+        match reversed {
+            Some(i) => {
+                lines.remove(i);
+            }
+            None => break,
+        }
+    }
+    if lines.len() < 3 {
+        return vec![];
+    }
+
+    let count = lines.len();
+    let mut moved = Vec::with_capacity(count * 2);
+    for i in 0..count {
+        let (prev, line) = (&lines[(i + count - 1) % count], &lines[i]);
+        if let Some(p) = prev.meet(line, distance) {
+            moved.push(p);
+            continue;
+        }
+        // The edges come apart. Joins are centred on the corner of the outline, if the edges still
+        // share one.
+        let corner = (prev.end == line.start).then(|| points[line.start]);
+        let (a, b) = (prev.to, line.from);
+        match (options.join, corner) {
+            (LineJoin::Miter, Some(p)) => {
+                let miter = (a - p + (b - p)) * (1. / (1. + prev.dir.dot(line.dir)).max(f32::EPSILON));
+                if miter.length() <= options.miter_limit * distance.abs() {
+                    moved.push(p + miter);
+                } else {
+                    moved.extend([a, b]);
+                }
+            }
+            (LineJoin::Round, Some(p)) => {
+                let (a, b) = (a - p, b - p);
+                let radius = distance.abs();
+                let angle = a.perp_dot(b).atan2(a.dot(b));
+                let step = 2. * (1. - options.tolerance / radius).clamp(-1., 1.).acos();
+                let steps = (angle.abs() / step.max(0.01)).ceil().max(1.) as usize;
+                moved.extend((0..=steps).map(|s| {
+                    let (sin, cos) = (angle * s as f32 / steps as f32).sin_cos();
+                    p + vec2(a.x * cos - a.y * sin, a.x * sin + a.y * cos)
+                }));
+            }
+            _ => moved.extend([a, b]),
+        }
+    }
+
+    // Pieces closer to the outline than the offset are where it overshot, as when a tile is inset
+    // past its centre and turns inside out.
+    let min_distance = distance.abs() * 0.99;
+    let mut result = untangle(moved, |piece| piece.iter().all(|&p| distance_to(&points, p) >= min_distance));
+    if clockwise {
+        result.reverse();
+    }
+    result
+}
+
+/// An edge of the outline moved along its normal.
+struct Line {
+    /// The moved end points of the edge.
+    from: Vec2,
+    to: Vec2,
+    /// Unit direction of the edge.
+    dir: Vec2,
+    /// Indices of the outline points the edge runs between.
+    start: usize,
+    end: usize,
+}
+
+impl Line {
+    fn new(points: &[Vec2], start: usize, end: usize, distance: f32) -> Self {
+        let offset = normal(points[start], points[end]) * distance;
+        let d = points[end] - points[start];
+        Self {
+            from: points[start] + offset,
+            to: points[end] + offset,
+            dir: d * (1. / d.length()),
+            start,
+            end,
+        }
+    }
+
+    /// Where this line meets the `next` one, if they cross at the corner between them rather than
+    /// come apart.
+    fn meet(&self, next: &Line, distance: f32) -> Option<Vec2> {
+        let turn = self.dir.perp_dot(next.dir);
+        if turn.abs() <= 1e-6 {
+            // Straight on: the lines are one. A turn right back is a corner coming apart.
+            return (self.dir.dot(next.dir) > 0.).then_some(next.from);
+        }
+        if turn * distance < 0. {
+            return None;
+        }
+        let t = (next.from - self.from).perp_dot(next.dir) / turn;
+        Some(self.from + self.dir * t)
+    }
+}
+
+/// Twice the signed area of a polygon, positive if it winds counter-clockwise.
+fn signed_area(points: &[Vec2]) -> f32 {
+    let n = points.len();
+    (0..n).map(|i| points[i].perp_dot(points[(i + 1) % n])).sum()
+}
+
+/// The distance from `p` to the closed polygon `points`.
+fn distance_to(points: &[Vec2], p: Vec2) -> f32 {
+    let n = points.len();
+    (0..n)
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            let ab = b - a;
+            let t = ((p - a).dot(ab) / ab.dot(ab).max(f32::EPSILON)).clamp(0., 1.);
+            (p - (a + ab * t)).length()
+        })
+        .fold(f32::INFINITY, f32::min)
+}
+
+/// Split a counter-clockwise polygon at its self-intersections and keep the largest
+/// counter-clockwise piece that is `valid`. The pieces winding the other way are where the
+/// offset overshot.
+fn untangle(points: Vec<Vec2>, valid: impl Fn(&[Vec2]) -> bool) -> Vec<Vec2> {
+    let mut pending = vec![points];
+    let mut best: Option<(f32, Vec<Vec2>)> = None;
+    while let Some(points) = pending.pop() {
+        match first_crossing(&points) {
+            Some((i, j, x)) => {
+                let n = points.len();
+                let inner: Vec<Vec2> = std::iter::once(x).chain(points[i + 1..=j].iter().copied()).collect();
+                let outer: Vec<Vec2> = points[..=i].iter().copied().chain(std::iter::once(x)).chain(points[(j + 1).min(n)..].iter().copied()).collect();
+                pending.push(inner);
+                pending.push(outer);
+            }
+            None => {
+                let area = signed_area(&points);
+                if points.len() >= 3 && area > 0. && best.as_ref().is_none_or(|(best, _)| area > *best) && valid(&points) {
+                    best = Some((area, points));
+                }
+            }
+        }
+    }
+    best.map(|(_, points)| points).unwrap_or_default()
+}
+
+/// The first pair of non-neighbouring segments `i < j` crossing each other, and where they
+/// cross. Segment `i` runs from point `i` to the next one.
+fn first_crossing(points: &[Vec2]) -> Option<(usize, usize, Vec2)> {
+    let n = points.len();
+    for i in 0..n {
+        let (a, b) = (points[i], points[(i + 1) % n]);
+        for j in i + 2..n {
+            if i == 0 && j == n - 1 {
+                continue;
+            }
+            let (c, d) = (points[j], points[(j + 1) % n]);
+            let (r, s) = (b - a, d - c);
+            let denom = r.perp_dot(s);
+            if denom.abs() <= f32::EPSILON {
+                continue;
+            }
+            let t = (c - a).perp_dot(s) / denom;
+            let u = (c - a).perp_dot(r) / denom;
+            if t > 0. && t < 1. && u > 0. && u < 1. {
+                return Some((i, j, a + r * t));
+            }
+        }
+    }
+    None
+}
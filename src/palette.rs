@@ -0,0 +1,280 @@
+//! Colour-blind-safe palettes for the colour classes, and a simulation of how colours look with
+//! a colour vision deficiency.
+//!
+//! The [Okabe-Ito] colours stay distinct under the common deficiencies; the viridis and cividis
+//! ramps vary steadily in lightness, so they also read in greyscale. The simulation uses the
+//! full-severity matrices of [Machado et al. (2009)] in linear RGB.
+//!
+//! [`high_contrast`] picks bright colours for the classes that are as far apart in CIELAB as they
+//! can be wherever two classes meet, for black outlines on a projector or for low vision.
+//! [`dominant_colours`] finds the main colours of an image, to match a tiling to existing artwork.
+//!
+//! [Okabe-Ito]: https://jfly.uni-koeln.de/color/
+//! [Machado et al. (2009)]: https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html
+use crate::export::Rgba;
+
+
+/// A curated set of colours for the colour classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Palette {
+    /// The eight colours of Okabe and Ito, in an order keeping the first few far apart.
+    OkabeIto,
+    /// Evenly spaced samples of matplotlib's viridis, from dark purple to yellow.
+    Viridis,
+    /// Evenly spaced samples of cividis, a ramp from blue to yellow that is nearly the same for
+    /// all colour vision.
+    Cividis,
+}
+
+impl Palette {
+    pub const ALL: [Palette; 3] = [Palette::OkabeIto, Palette::Viridis, Palette::Cividis];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Palette::OkabeIto => "Okabe-Ito",
+            Palette::Viridis => "viridis",
+            Palette::Cividis => "cividis",
+        }
+    }
+
+    /// Colours for `count` colour classes. Okabe-Ito repeats after eight classes; the ramps are
+    /// sampled from end to end so neighbouring classes differ as much as they can.
+    pub fn colours(self, count: usize) -> Vec<Rgba> {
+        match self {
+            Palette::OkabeIto => OKABE_ITO.iter().copied().cycle().take(count).collect(),
+            Palette::Viridis => sample(&VIRIDIS, count),
+            Palette::Cividis => sample(&CIVIDIS, count),
+        }
+    }
+}
+
+const OKABE_ITO: [Rgba; 8] = [
+    [0xe6, 0x9f, 0x00, 0xff],
+    [0x56, 0xb4, 0xe9, 0xff],
+    [0x00, 0x9e, 0x73, 0xff],
+    [0xf0, 0xe4, 0x42, 0xff],
+    [0x00, 0x72, 0xb2, 0xff],
+    [0xd5, 0x5e, 0x00, 0xff],
+    [0xcc, 0x79, 0xa7, 0xff],
+    [0x00, 0x00, 0x00, 0xff],
+];
+
+const VIRIDIS: [Rgba; 9] = [
+    [0x44, 0x01, 0x54, 0xff],
+    [0x47, 0x2d, 0x7b, 0xff],
+    [0x3b, 0x52, 0x8b, 0xff],
+    [0x2c, 0x72, 0x8e, 0xff],
+    [0x21, 0x91, 0x8c, 0xff],
+    [0x28, 0xae, 0x80, 0xff],
+    [0x5e, 0xc9, 0x62, 0xff],
+    [0xad, 0xdc, 0x30, 0xff],
+    [0xfd, 0xe7, 0x25, 0xff],
+];
+
+const CIVIDIS: [Rgba; 9] = [
+    [0x00, 0x22, 0x4e, 0xff],
+    [0x1d, 0x37, 0x6e, 0xff],
+    [0x41, 0x4d, 0x6b, 0xff],
+    [0x5f, 0x63, 0x6f, 0xff],
+    [0x7c, 0x7b, 0x78, 0xff],
+    [0x9b, 0x93, 0x77, 0xff],
+    [0xbc, 0xaf, 0x6f, 0xff],
+    [0xdd, 0xcb, 0x61, 0xff],
+    [0xfe, 0xe8, 0x38, 0xff],
+];
+
+/// `count` colours evenly spaced along the ramp through `stops`, interpolated in sRGB.
+fn sample(stops: &[Rgba], count: usize) -> Vec<Rgba> {
+    let last = (stops.len() - 1) as f32;
+    (0..count)
+        .map(|i| {
+            let t = if count > 1 { i as f32 / (count - 1) as f32 } else { 0.5 } * last;
+            let k = (t as usize).min(stops.len() - 2);
+            let f = t - k as f32;
+            let (a, b) = (stops[k], stops[k + 1]);
+            [0, 1, 2, 3].map(|c| (a[c] as f32 + (b[c] as f32 - a[c] as f32) * f).round() as u8)
+        })
+        .collect()
+}
+
+/// The outline colour going with [`high_contrast`].
+pub const HIGH_CONTRAST_STROKE: Rgba = [0x00, 0x00, 0x00, 0xff];
+
+/// Bright, saturated colours that all stand out against black.
+const HIGH_CONTRAST: [Rgba; 8] = [
+    [0xff, 0xff, 0xff, 0xff],
+    [0xff, 0xd4, 0x00, 0xff],
+    [0x33, 0xb5, 0xff, 0xff],
+    [0xff, 0x3d, 0xcb, 0xff],
+    [0x3b, 0xd1, 0x3b, 0xff],
+    [0xff, 0x7a, 0x00, 0xff],
+    [0x9b, 0x6b, 0xff, 0xff],
+    [0xff, 0x30, 0x30, 0xff],
+];
+
+/// Colours for `count` colour classes making the classes in the `adjacent` pairs, as from
+/// [`Colouring::adjacent_colours`], as different as possible.
+///
+/// Every assignment of distinct high-contrast colours is tried, keeping the one with the largest
+/// smallest CIELAB distance over the pairs, then the largest total. Classes past eight reuse the
+/// colours in order.
+///
+/// [`Colouring::adjacent_colours`]: crate::colouring::Colouring::adjacent_colours
+pub fn high_contrast(count: usize, adjacent: &[(usize, usize)]) -> Vec<Rgba> {
+    let k = count.min(HIGH_CONTRAST.len());
+    let lab = HIGH_CONTRAST.map(to_lab);
+    let distance = |i: usize, j: usize| [0, 1, 2].map(|c| (lab[i][c] - lab[j][c]).powi(2)).iter().sum::<f32>().sqrt();
+    let pairs: Vec<(usize, usize)> = adjacent.iter().copied().filter(|&(a, b)| a < k && b < k).collect();
+
+    let (mut best, mut best_score) = ((0..k).collect::<Vec<_>>(), (f32::NEG_INFINITY, f32::NEG_INFINITY));
+    for choice in arrangements(k, HIGH_CONTRAST.len()) {
+        let distances = pairs.iter().map(|&(a, b)| distance(choice[a], choice[b]));
+        let score = distances.fold((f32::INFINITY, 0.), |(min, sum), d| (min.min(d), sum + d));
+        if score.0 > best_score.0 || score.0 == best_score.0 && score.1 > best_score.1 {
+            (best, best_score) = (choice, score);
+        }
+    }
+
+    (0..count).map(|i| HIGH_CONTRAST[best[i % k]]).collect()
+}
+
+/// Every sequence of `k` distinct numbers below `n`, in lexicographic order.
+fn arrangements(k: usize, n: usize) -> Vec<Vec<usize>> {
+    let mut all = vec![vec![]];
+    for _ in 0..k {
+        all = all
+            .into_iter()
+            .flat_map(|prefix: Vec<usize>| {
+                (0..n).filter(|c| !prefix.contains(c)).map(|c| [prefix.as_slice(), &[c]].concat()).collect::<Vec<_>>()
+            })
+            .collect();
+    }
+    all
+}
+
+/// CIELAB coordinates of an sRGB colour, for the D65 white point.
+fn to_lab(c: Rgba) -> [f32; 3] {
+    let [r, g, b] = [0, 1, 2].map(|i| to_linear(c[i]));
+    let xyz = [
+        (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.95047,
+        0.2126 * r + 0.7152 * g + 0.0722 * b,
+        (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.08883,
+    ];
+    let [fx, fy, fz] = xyz.map(|t| if t > 0.008856 { t.cbrt() } else { 7.787 * t + 16. / 116. });
+    [116. * fy - 16., 500. * (fx - fy), 200. * (fy - fz)]
+}
+
+/// Largest number of pixels [`dominant_colours`] looks at; larger images are sampled evenly.
+const MAX_SAMPLES: usize = 16384;
+
+/// Iterations of k-means in [`dominant_colours`].
+const ITERATIONS: usize = 16;
+
+/// The `count` main colours of the straight-alpha `pixels`, found by k-means in CIELAB, with the
+/// share of the pixels closest to each, largest share first.
+///
+/// Mostly transparent pixels are ignored. The clusters start at the pixels farthest from the ones
+/// picked so far, so the result is the same every time; fewer colours are returned when the image
+/// has fewer.
+pub fn dominant_colours(pixels: &[Rgba], count: usize) -> Vec<(Rgba, f32)> {
+    let step = pixels.len().div_ceil(MAX_SAMPLES).max(1);
+    let samples: Vec<([f32; 3], Rgba)> = pixels.iter().step_by(step).filter(|p| p[3] >= 128).map(|&p| (to_lab(p), p)).collect();
+    if samples.is_empty() || count == 0 {
+        return vec![];
+    }
+    let distance = |a: &[f32; 3], b: &[f32; 3]| (0..3).map(|c| (a[c] - b[c]).powi(2)).sum::<f32>();
+
+    let mean = samples.iter().fold([0.; 3], |sum, (lab, _)| [0, 1, 2].map(|c| sum[c] + lab[c] / samples.len() as f32));
+    let mut centres = vec![mean];
+    while centres.len() < count {
+        let (far, d) = samples
+            .iter()
+            .map(|(lab, _)| (*lab, centres.iter().map(|c| distance(c, lab)).fold(f32::INFINITY, f32::min)))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap_or((mean, 0.));
+        if d <= 0. {
+            break;
+        }
+        centres.push(far);
+    }
+
+    let mut nearest = vec![0; samples.len()];
+    for _ in 0..ITERATIONS {
+        for (n, (lab, _)) in nearest.iter_mut().zip(&samples) {
+            *n = (0..centres.len()).min_by(|&i, &j| distance(&centres[i], lab).total_cmp(&distance(&centres[j], lab))).unwrap_or(0);
+        }
+        let mut sums = vec![([0.; 3], 0); centres.len()];
+        for (&n, (lab, _)) in nearest.iter().zip(&samples) {
+            sums[n].0 = [0, 1, 2].map(|c| sums[n].0[c] + lab[c]);
+            sums[n].1 += 1;
+        }
+        for (centre, (sum, k)) in centres.iter_mut().zip(sums) {
+            if k > 0 {
+                *centre = sum.map(|v| v / k as f32);
+            }
+        }
+    }
+
+    // Each cluster is shown by its average colour, averaged in sRGB.
+    let mut clusters = vec![([0.; 3], 0); centres.len()];
+    for (&n, (_, p)) in nearest.iter().zip(&samples) {
+        clusters[n].0 = [0, 1, 2].map(|c| clusters[n].0[c] + p[c] as f32);
+        clusters[n].1 += 1;
+    }
+    let mut colours: Vec<(Rgba, f32)> = clusters
+        .into_iter()
+        .filter(|&(_, k)| k > 0)
+        .map(|(sum, k)| {
+            let [r, g, b] = sum.map(|v| (v / k as f32).round() as u8);
+            ([r, g, b, 0xff], k as f32 / samples.len() as f32)
+        })
+        .collect();
+    colours.sort_by(|a, b| b.1.total_cmp(&a.1));
+    colours
+}
+
+/// A colour vision deficiency to simulate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Deficiency {
+    /// No working red cones.
+    Protanopia,
+    /// No working green cones, the most common.
+    Deuteranopia,
+    /// No working blue cones.
+    Tritanopia,
+}
+
+impl Deficiency {
+    pub const ALL: [Deficiency; 3] = [Deficiency::Protanopia, Deficiency::Deuteranopia, Deficiency::Tritanopia];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Deficiency::Protanopia => "protanopia",
+            Deficiency::Deuteranopia => "deuteranopia",
+            Deficiency::Tritanopia => "tritanopia",
+        }
+    }
+
+    /// How the straight-alpha colour `c` looks with the deficiency. Alpha is kept.
+    pub fn simulate(self, c: Rgba) -> Rgba {
+        let m = match self {
+            Deficiency::Protanopia => [[0.152286, 1.052583, -0.204868], [0.114503, 0.786281, 0.099216], [-0.003882, -0.048116, 1.051998]],
+            Deficiency::Deuteranopia => [[0.367322, 0.860646, -0.227968], [0.280085, 0.672501, 0.047413], [-0.011820, 0.042940, 0.968881]],
+            Deficiency::Tritanopia => [[1.255528, -0.076749, -0.178779], [-0.078411, 0.930809, 0.147602], [0.004733, 0.691367, 0.303900]],
+        };
+        let linear = [0, 1, 2].map(|i| to_linear(c[i]));
+        let [r, g, b] = m.map(|row| from_linear(row[0] * linear[0] + row[1] * linear[1] + row[2] * linear[2]));
+        [r, g, b, c[3]]
+    }
+}
+
+fn to_linear(c: u8) -> f32 {
+    let c = c as f32 / 255.;
+    if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+}
+
+fn from_linear(c: f32) -> u8 {
+    let c = c.clamp(0., 1.);
+    let c = if c <= 0.0031308 { c * 12.92 } else { 1.055 * c.powf(1. / 2.4) - 0.055 };
+    (c * 255.).round() as u8
+}
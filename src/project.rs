@@ -0,0 +1,392 @@
+//! Project files, holding everything needed to restore a design, and edge files holding a single
+//! edge shape.
+//!
+//! Projects are plain text with the extension [`PROJECT_EXTENSION`], one setting per line: a key
+//! followed by its values, separated by spaces. Lines starting with `#` are comments.
+//!
+//! ```text
+//! # egui Isohedral project
+//! type 4
+//! parameters 0.12 0.5
+//! colours 3
+//! edge 0 0 0.3 -0.1 1 0
+//! edge 0 0 1 0
+//! ```
+//!
+//! `type` is the IH number, and there is one `edge` line of flattened `x y` pairs per edge shape.
+//! `theme` holds the twelve colours of the egui theme as `r g b` triples, and each `saved-theme`
+//! line one of the custom themes offered in the themes menu, in the same form; both are optional.
+//!
+//! Each layer over the design (see [`layers`](crate::layers)) follows as a `layer` line with its
+//! blend, `normal` or `add`, its opacity, and `hidden` if it isn't shown; the `type`,
+//! `parameters`, `colours` and `edge` lines after it are the layer's, with a `palette` line of
+//! `r g b` triples for the colours of its colour classes.
+//!
+//! Edge files are JSON arrays of `[x, y]` points in the edge frame.
+//!
+//! A share code is a project without its saved themes, compressed and written in URL-safe
+//! base64, short enough to paste into a chat or an issue.
+use std::fmt::Write;
+
+use eframe::egui;
+use egui_colors::{tokens::ThemeColor, Theme};
+use egui_isohedral::{colouring::MAX_COLOURS, edges, get_tiling_type, render::Blend, tiling::IsohedralTiling, vec2, Vec2, TILING_TYPES};
+
+use crate::app::App;
+use crate::interface;
+use crate::layers::Layer;
+
+
+/// Extension of project files.
+pub const PROJECT_EXTENSION: &str = "isoh";
+
+/// A saved design.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Project {
+    /// The IH number of the tiling type.
+    pub tiling_type: usize,
+    pub params: Vec<f32>,
+    pub colours: usize,
+    pub edges: Vec<Vec<Vec2>>,
+    pub theme: Option<Theme>,
+    pub saved_themes: Vec<Theme>,
+    pub layers: Vec<ProjectLayer>,
+}
+
+/// A layer over the design in a project.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectLayer {
+    /// The IH number of the tiling type.
+    pub tiling_type: usize,
+    pub params: Vec<f32>,
+    pub colours: usize,
+    pub edges: Vec<Vec<Vec2>>,
+    /// Fill colour of each colour class.
+    pub palette: Vec<egui::Color32>,
+    pub opacity: f32,
+    pub blend: Blend,
+    pub visible: bool,
+}
+
+impl ProjectLayer {
+    fn of(layer: &Layer) -> Self {
+        let mut params = [0.; 6];
+        layer.tiling.parameters(&mut params);
+        let colours = layer.tiling.num_colours();
+        Self {
+            tiling_type: TILING_TYPES[layer.tile_type_num],
+            params: params[..layer.tiling.num_params()].to_vec(),
+            colours,
+            edges: layer.edges.clone(),
+            palette: layer.palette[..colours.min(MAX_COLOURS)].to_vec(),
+            opacity: layer.opacity,
+            blend: layer.blend,
+            visible: layer.visible,
+        }
+    }
+
+    /// The layer, with `fallback` for the colours its palette doesn't give.
+    fn build(&self, fallback: [egui::Color32; MAX_COLOURS]) -> Result<Layer, String> {
+        let (tile_type_num, mut tiling, edges) = build(self.tiling_type, &self.params, &self.edges)?;
+        tiling.set_colouring(self.colours.clamp(1, MAX_COLOURS)).map_err(|err| err.to_string())?;
+        let palette = std::array::from_fn(|i| self.palette.get(i).copied().unwrap_or(fallback[i]));
+        Ok(Layer { tile_type_num, tiling, edges, palette, opacity: self.opacity.clamp(0., 1.), blend: self.blend, visible: self.visible })
+    }
+}
+
+/// The tiling of a design, and its edges projected onto the constraints of their edge shapes.
+fn build(tiling_type: usize, params: &[f32], edges: &[Vec<Vec2>]) -> Result<(usize, IsohedralTiling, Vec<Vec<Vec2>>), String> {
+    let type_num = TILING_TYPES.iter().position(|&t| t == tiling_type).ok_or_else(|| format!("IH{tiling_type:02} is not a valid tiling type"))?;
+    let tiling = IsohedralTiling::builder()
+        .tiling_type(get_tiling_type(type_num))
+        .parameters(params)
+        .build()
+        .map_err(|err| err.to_string())?;
+    if edges.len() != tiling.num_edge_shapes() {
+        return Err(format!("{} has {} edge shapes, but the project has {}", tiling.tiling_type(), tiling.num_edge_shapes(), edges.len()));
+    }
+    if edges.iter().any(|edge| edge.len() < 2) {
+        return Err(String::from("every edge needs at least two points"));
+    }
+    let edges = edges.iter().enumerate().map(|(i, edge)| edges::project(edge, tiling.edge_shape(i))).collect();
+    Ok((type_num, tiling, edges))
+}
+
+impl Project {
+    /// The design currently shown by the app.
+    pub fn from_app(app: &App) -> Self {
+        Self {
+            tiling_type: TILING_TYPES[app.tile_type_num],
+            params: app.params[..app.tiling.num_params()].to_vec(),
+            colours: app.colours,
+            edges: app.edges_shapes.clone(),
+            theme: Some(*app.colorix.theme()),
+            saved_themes: app.saved_themes.clone(),
+            layers: app.layers.iter().map(ProjectLayer::of).collect(),
+        }
+    }
+
+    /// Show the design in the app, leaving it unchanged if the project doesn't fit its tiling type.
+    ///
+    /// Edges are projected onto the constraints of their edge shapes, so hand-edited files can't
+    /// break the tiling. The project's layers replace the app's, and its saved themes are added to
+    /// those of the app.
+    pub fn apply(self, app: &mut App, ctx: &egui::Context) -> Result<(), String> {
+        let (type_num, tiling, edges) = build(self.tiling_type, &self.params, &self.edges)?;
+        let fallback = interface::tile_colours(app);
+        let layers = self
+            .layers
+            .iter()
+            .enumerate()
+            .map(|(i, layer)| layer.build(fallback).map_err(|err| format!("layer {}: {err}", i + 1)))
+            .collect::<Result<_, _>>()?;
+
+        app.morph = None;
+        app.tile_type_num = type_num;
+        app.edges_shapes = edges;
+        app.tiling = tiling;
+        app.set_default_params();
+        app.colours = self.colours.clamp(1, MAX_COLOURS);
+        app.apply_colouring();
+        app.layers = layers;
+        if let Some(theme) = self.theme {
+            app.colorix.update_theme(ctx, theme);
+        }
+        for theme in self.saved_themes {
+            if !app.saved_themes.contains(&theme) {
+                app.saved_themes.push(theme);
+            }
+        }
+        Ok(())
+    }
+
+    /// The contents of a project file.
+    pub fn to_text(&self) -> String {
+        let mut out = String::from("# egui Isohedral project\n");
+
+        // Writing into a `String` can't fail.
+        let design = |out: &mut String, tiling_type: usize, params: &[f32], colours: usize, edges: &[Vec<Vec2>]| {
+            let _ = writeln!(out, "type {tiling_type}");
+            let params: Vec<String> = params.iter().map(f32::to_string).collect();
+            let _ = writeln!(out, "parameters {}", params.join(" "));
+            let _ = writeln!(out, "colours {colours}");
+            for edge in edges {
+                let points: Vec<String> = edge.iter().map(|p| format!("{} {}", p.x, p.y)).collect();
+                let _ = writeln!(out, "edge {}", points.join(" "));
+            }
+        };
+        design(&mut out, self.tiling_type, &self.params, self.colours, &self.edges);
+        for (key, theme) in self.theme.iter().map(|theme| ("theme", theme)).chain(self.saved_themes.iter().map(|theme| ("saved-theme", theme))) {
+            let values: Vec<String> = theme.iter().flat_map(ThemeColor::rgb).map(|v| v.to_string()).collect();
+            let _ = writeln!(out, "{key} {}", values.join(" "));
+        }
+        for layer in &self.layers {
+            let blend = match layer.blend {
+                Blend::Normal => "normal",
+                Blend::Add => "add",
+            };
+            let _ = writeln!(out, "layer {blend} {}{}", layer.opacity, if layer.visible { "" } else { " hidden" });
+            design(&mut out, layer.tiling_type, &layer.params, layer.colours, &layer.edges);
+            let values: Vec<String> = layer.palette.iter().flat_map(|c| [c.r(), c.g(), c.b()]).map(|v| v.to_string()).collect();
+            let _ = writeln!(out, "palette {}", values.join(" "));
+        }
+        out
+    }
+
+    /// Read a project file.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let (mut tiling_type, mut params, mut colours, mut edges) = (None, vec![], 3, vec![]);
+        let (mut theme, mut saved_themes) = (None, vec![]);
+        let mut layers: Vec<ProjectLayer> = vec![];
+        for (n, line) in text.lines().enumerate().map(|(n, line)| (n + 1, line.trim())) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, values) = line.split_once(' ').unwrap_or((line, ""));
+            if key == "layer" {
+                layers.push(parse_layer(values).ok_or(format!("line {n}: expected layer normal|add <opacity> [hidden]"))?);
+                continue;
+            }
+            let numbers = values
+                .split_whitespace()
+                .map(|value| value.parse::<f32>().map_err(|_| format!("line {n}: {value:?} is not a number")))
+                .collect::<Result<Vec<f32>, String>>()?;
+            let single = || match numbers[..] {
+                [value] if value >= 0. && value.fract() == 0. => Ok(value as usize),
+                _ => Err(format!("line {n}: {key} takes a single whole number")),
+            };
+            // The design settings after a `layer` line are the layer's.
+            let layer = layers.last_mut();
+            match (key, layer) {
+                ("type", Some(layer)) => layer.tiling_type = single()?,
+                ("type", None) => tiling_type = Some(single()?),
+                ("parameters", Some(layer)) => layer.params = numbers,
+                ("parameters", None) => params = numbers,
+                ("colours", Some(layer)) => layer.colours = single()?,
+                ("colours", None) => colours = single()?,
+                ("edge", layer) if numbers.len().is_multiple_of(2) => {
+                    let edge = numbers.chunks(2).map(|p| vec2(p[0], p[1])).collect();
+                    layer.map_or(&mut edges, |layer| &mut layer.edges).push(edge);
+                }
+                ("edge", _) => return Err(format!("line {n}: an edge needs pairs of coordinates")),
+                ("palette", Some(layer)) => layer.palette = parse_palette(&numbers).ok_or(format!("line {n}: a palette needs r g b colours"))?,
+                ("theme", _) => theme = Some(parse_theme(&numbers).ok_or(format!("line {n}: a theme needs twelve r g b colours"))?),
+                ("saved-theme", _) => saved_themes.push(parse_theme(&numbers).ok_or(format!("line {n}: a theme needs twelve r g b colours"))?),
+                _ => return Err(format!("line {n}: unknown setting {key:?}")),
+            }
+        }
+        let tiling_type = tiling_type.ok_or("the project has no tiling type")?;
+        if let Some(i) = layers.iter().position(|layer| layer.tiling_type == 0) {
+            return Err(format!("layer {} has no tiling type", i + 1));
+        }
+        Ok(Self { tiling_type, params, colours, edges, theme, saved_themes, layers })
+    }
+
+    /// The share code of the design, see the [module docs](self).
+    pub fn share_code(&self) -> String {
+        let design = Self { saved_themes: vec![], ..self.clone() };
+        base64_encode(&miniz_oxide::deflate::compress_to_vec(design.to_text().as_bytes(), 10))
+    }
+
+    /// Read a share code, ignoring any whitespace a chat wrapped it with.
+    pub fn from_share_code(code: &str) -> Result<Self, String> {
+        let invalid = || String::from("not a share code");
+        let compressed = base64_decode(&code.split_whitespace().collect::<String>()).ok_or_else(invalid)?;
+        let text = miniz_oxide::inflate::decompress_to_vec_with_limit(&compressed, MAX_SHARED_TEXT).map_err(|_| invalid())?;
+        Self::parse(&String::from_utf8(text).map_err(|_| invalid())?)
+    }
+}
+
+/// Size limit of the project in a share code, against codes that decompress without end.
+const MAX_SHARED_TEXT: usize = 1 << 20;
+
+/// The URL-safe base64 alphabet.
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// `bytes` in URL-safe base64, without padding.
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0_u32, |n, (i, &b)| n | u32::from(b) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            out.push(BASE64[(n >> (18 - 6 * i) & 63) as usize] as char);
+        }
+    }
+    out
+}
+
+/// The bytes of URL-safe base64 text, padded or not.
+fn base64_decode(text: &str) -> Option<Vec<u8>> {
+    let digits = text.trim_end_matches('=').bytes().map(|c| BASE64.iter().position(|&d| d == c)).collect::<Option<Vec<usize>>>()?;
+    if digits.len() % 4 == 1 {
+        return None;
+    }
+    let mut out = Vec::with_capacity(digits.len() * 3 / 4);
+    for chunk in digits.chunks(4) {
+        let n = chunk.iter().enumerate().fold(0_u32, |n, (i, &d)| n | (d as u32) << (18 - 6 * i));
+        for i in 0..chunk.len() - 1 {
+            out.push((n >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(out)
+}
+
+/// The egui_colors presets, which a theme read back from its colours is matched against.
+const PRESETS: [ThemeColor; 22] = [
+    ThemeColor::Gray,
+    ThemeColor::EguiBlue,
+    ThemeColor::Tomato,
+    ThemeColor::Red,
+    ThemeColor::Ruby,
+    ThemeColor::Crimson,
+    ThemeColor::Pink,
+    ThemeColor::Plum,
+    ThemeColor::Purple,
+    ThemeColor::Violet,
+    ThemeColor::Iris,
+    ThemeColor::Indigo,
+    ThemeColor::Blue,
+    ThemeColor::Cyan,
+    ThemeColor::Teal,
+    ThemeColor::Jade,
+    ThemeColor::Green,
+    ThemeColor::Grass,
+    ThemeColor::Brown,
+    ThemeColor::Bronze,
+    ThemeColor::Gold,
+    ThemeColor::Orange,
+];
+
+/// A theme from 36 numbers, twelve `r g b` triples. Colours of a preset become that preset, so a
+/// theme made of presets reads back unchanged.
+pub fn parse_theme(numbers: &[f32]) -> Option<Theme> {
+    if numbers.len() != 36 || numbers.iter().any(|&v| !(0. ..=255.).contains(&v) || v.fract() != 0.) {
+        return None;
+    }
+    Some(std::array::from_fn(|i| {
+        let rgb = [0, 1, 2].map(|c| numbers[3 * i + c] as u8);
+        PRESETS.into_iter().find(|preset| preset.rgb() == rgb).unwrap_or(ThemeColor::Custom(rgb))
+    }))
+}
+
+/// The settings of a `layer` line, before the layer's design; its tiling type is `0` until read.
+fn parse_layer(values: &str) -> Option<ProjectLayer> {
+    let mut words = values.split_whitespace();
+    let blend = match words.next()? {
+        "normal" => Blend::Normal,
+        "add" => Blend::Add,
+        _ => return None,
+    };
+    let opacity = words.next()?.parse().ok()?;
+    let visible = match words.next() {
+        None => true,
+        Some("hidden") => false,
+        Some(_) => return None,
+    };
+    Some(ProjectLayer { tiling_type: 0, params: vec![], colours: 3, edges: vec![], palette: vec![], opacity, blend, visible })
+}
+
+/// Colours from `r g b` triples.
+fn parse_palette(numbers: &[f32]) -> Option<Vec<egui::Color32>> {
+    if !numbers.len().is_multiple_of(3) || numbers.iter().any(|&v| !(0. ..=255.).contains(&v) || v.fract() != 0.) {
+        return None;
+    }
+    Some(numbers.chunks(3).map(|c| egui::Color32::from_rgb(c[0] as u8, c[1] as u8, c[2] as u8)).collect())
+}
+
+/// Read an edge file: a JSON array of `[x, y]` points.
+pub fn parse_edge(text: &str) -> Result<Vec<Vec2>, String> {
+    let invalid = || String::from("an edge file must be a JSON array of [x, y] points");
+    let inner = text.trim().strip_prefix('[').and_then(|rest| rest.strip_suffix(']')).ok_or_else(invalid)?.trim();
+    if inner.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut points = vec![];
+    let mut rest = inner;
+    loop {
+        let (point, after) = rest.strip_prefix('[').and_then(|rest| rest.split_once(']')).ok_or_else(invalid)?;
+        let coordinates = point.split(',').map(|value| value.trim().parse::<f32>()).collect::<Result<Vec<f32>, _>>().map_err(|_| invalid())?;
+        let [x, y] = coordinates[..] else {
+            return Err(invalid());
+        };
+        points.push(vec2(x, y));
+
+        let after = after.trim_start();
+        if after.is_empty() {
+            break;
+        }
+        rest = after.strip_prefix(',').ok_or_else(invalid)?.trim_start();
+    }
+
+    if points.len() < 2 {
+        return Err(String::from("an edge needs at least two points"));
+    }
+    Ok(points)
+}
+
+/// The contents of an edge file, see [`parse_edge`].
+pub fn edge_json(edge: &[Vec2]) -> String {
+    let points: Vec<String> = edge.iter().map(|p| format!("[{}, {}]", p.x, p.y)).collect();
+    format!("[{}]\n", points.join(", "))
+}
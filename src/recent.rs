@@ -0,0 +1,71 @@
+//! The list of recently opened and saved projects, kept across sessions.
+//!
+//! The list is a plain text file with one path per line, most recent first, in the platform's
+//! configuration directory.
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+
+/// Number of projects the list keeps.
+const MAX_RECENT: usize = 10;
+
+/// Recently opened and saved projects, most recent first.
+#[derive(Debug, Default)]
+pub struct RecentFiles {
+    paths: Vec<PathBuf>,
+}
+
+impl RecentFiles {
+    /// Read the list saved by an earlier session, if any.
+    pub fn load() -> Self {
+        let paths = settings_file()
+            .and_then(|file| fs::read_to_string(file).ok())
+            .map(|text| text.lines().filter(|line| !line.is_empty()).map(PathBuf::from).take(MAX_RECENT).collect())
+            .unwrap_or_default();
+        Self { paths }
+    }
+
+    /// The projects, most recent first.
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    /// Move `path` to the top of the list and save it.
+    pub fn add(&mut self, path: &Path) {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        self.paths.retain(|recent| *recent != path);
+        self.paths.insert(0, path);
+        self.paths.truncate(MAX_RECENT);
+        self.save();
+    }
+
+    /// Empty the list and save it.
+    pub fn clear(&mut self) {
+        self.paths.clear();
+        self.save();
+    }
+
+    /// Write the list for later sessions. It's only a convenience, so failures are ignored.
+    fn save(&self) {
+        let Some(file) = settings_file() else {
+            return;
+        };
+        let text: String = self.paths.iter().map(|path| format!("{}\n", path.display())).collect();
+        if let Some(folder) = file.parent() {
+            let _ = fs::create_dir_all(folder);
+        }
+        let _ = fs::write(file, text);
+    }
+}
+
+/// Where the list is kept, if the platform's configuration directory can be found.
+fn settings_file() -> Option<PathBuf> {
+    let config = if cfg!(windows) {
+        env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        env::var_os("HOME").map(|home| Path::new(&home).join("Library/Application Support"))
+    } else {
+        env::var_os("XDG_CONFIG_HOME").map(PathBuf::from).or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+    };
+    config.map(|config| config.join("egui_isohedral").join("recent_projects.txt"))
+}
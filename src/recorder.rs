@@ -0,0 +1,228 @@
+//! Recording the design and view as they change, and replaying them, for rehearsing demos and
+//! reproducing tutorials.
+//!
+//! A recording keeps the design and view at each frame they changed in, with the time since the
+//! recording started. Replaying shows them again at those times, or, when exporting frames, at
+//! a steady [`FRAME_RATE`] whatever the time drawing takes, saving each frame as a PNG image.
+//!
+//! Session files hold a recording as a series of projects, each after a line with its time in
+//! seconds and the view's pan and scale:
+//!
+//! ```text
+//! # egui Isohedral session
+//! at 0 view 640 360 100
+//! # egui Isohedral project
+//! type 4
+//! ...
+//! ```
+use std::fmt::Write;
+
+use eframe::egui;
+use egui_isohedral::view::SCALE_RANGE;
+
+use crate::app::App;
+use crate::interface;
+use crate::project::Project;
+
+
+/// Extension of session files.
+pub const SESSION_EXTENSION: &str = "isosession";
+
+/// Frames per second of exported replays.
+pub const FRAME_RATE: f32 = 30.;
+
+/// The design and view at one moment.
+#[derive(Debug, Clone, PartialEq)]
+struct Snapshot {
+    project: Project,
+    pan: egui::Vec2,
+    scale: f32,
+}
+
+impl Snapshot {
+    fn of(app: &App) -> Self {
+        Self { project: Project { saved_themes: vec![], ..Project::from_app(app) }, pan: app.view.pan, scale: app.view.scale }
+    }
+
+    /// Show the snapshot in the app, leaving the theme alone while it is the same so its colour
+    /// animation isn't started again.
+    fn apply(&self, app: &mut App, ctx: &egui::Context) -> Result<(), String> {
+        let mut project = self.project.clone();
+        if project.theme.as_ref() == Some(app.colorix.theme()) {
+            project.theme = None;
+        }
+        project.apply(app, ctx)?;
+        app.view.pan = self.pan;
+        app.view.scale = self.scale;
+        Ok(())
+    }
+}
+
+/// State of the recorder.
+#[derive(Default)]
+pub struct Recorder {
+    /// The recording: seconds from its start, and the design and view from then on.
+    events: Vec<(f32, Snapshot)>,
+    /// When the recording running started, in seconds of the app's clock.
+    recording: Option<f64>,
+    replay: Option<Replay>,
+    /// Save every frame of replays as an image.
+    pub export_frames: bool,
+}
+
+/// A running replay.
+struct Replay {
+    /// When it started, in seconds of the app's clock.
+    start: f64,
+    /// The next event to show.
+    next: usize,
+    /// The number of the next frame to save, if frames are exported.
+    frame: Option<usize>,
+}
+
+impl Recorder {
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    pub fn is_replaying(&self) -> bool {
+        self.replay.is_some()
+    }
+
+    /// Length of the recording in seconds.
+    pub fn duration(&self) -> f32 {
+        self.events.last().map_or(0., |&(time, _)| time)
+    }
+
+    /// The contents of a session file, see the [module docs](self).
+    pub fn to_text(&self) -> String {
+        let mut out = String::from("# egui Isohedral session\n");
+        for (time, snapshot) in &self.events {
+            // Writing into a `String` can't fail.
+            let _ = writeln!(out, "at {time} view {} {} {}", snapshot.pan.x, snapshot.pan.y, snapshot.scale);
+            out.push_str(&snapshot.project.to_text());
+        }
+        out
+    }
+
+    /// Read a session file, replacing the recording. Every number must be finite, and a scale
+    /// outside [`SCALE_RANGE`] is clamped to it.
+    pub fn parse(&mut self, text: &str) -> Result<(), String> {
+        let mut events = vec![];
+        let mut chunk: Option<(f32, egui::Vec2, f32, String)> = None;
+        let mut finish = |chunk: Option<(f32, egui::Vec2, f32, String)>| -> Result<(), String> {
+            if let Some((time, pan, scale, text)) = chunk {
+                let project = Project::parse(&text).map_err(|err| format!("at {time} s: {err}"))?;
+                events.push((time, Snapshot { project, pan, scale }));
+            }
+            Ok(())
+        };
+        for (n, line) in text.lines().enumerate().map(|(n, line)| (n + 1, line.trim())) {
+            if let Some(rest) = line.strip_prefix("at ") {
+                let numbers = rest
+                    .split_whitespace()
+                    .filter(|&word| word != "view")
+                    .map(|value| {
+                        value
+                            .parse::<f32>()
+                            .ok()
+                            .filter(|v| v.is_finite())
+                            .ok_or_else(|| format!("line {n}: {value:?} is not a finite number"))
+                    })
+                    .collect::<Result<Vec<f32>, String>>()?;
+                let [time, x, y, scale] = numbers[..] else {
+                    return Err(format!("line {n}: expected at <seconds> view <x> <y> <scale>"));
+                };
+                let scale = scale.clamp(*SCALE_RANGE.start(), *SCALE_RANGE.end());
+                finish(chunk.replace((time, egui::vec2(x, y), scale, String::new())))?;
+            } else if let Some((.., text)) = &mut chunk {
+                text.push_str(line);
+                text.push('\n');
+            } else if !line.is_empty() && !line.starts_with('#') {
+                return Err(format!("line {n}: expected at <seconds> view <x> <y> <scale>"));
+            }
+        }
+        finish(chunk)?;
+        if events.windows(2).any(|pair| pair[1].0 < pair[0].0) {
+            return Err(String::from("the times must not go backwards"));
+        }
+        self.events = events;
+        self.recording = None;
+        self.replay = None;
+        Ok(())
+    }
+}
+
+/// Start a new recording, from the design and view shown now.
+pub fn start_recording(app: &mut App, ctx: &egui::Context) {
+    let snapshot = Snapshot::of(app);
+    let recorder = &mut app.recorder;
+    recorder.replay = None;
+    recorder.recording = Some(ctx.input(|i| i.time));
+    recorder.events = vec![(0., snapshot)];
+}
+
+pub fn stop_recording(app: &mut App) {
+    app.recorder.recording = None;
+}
+
+/// Start replaying the recording from its beginning.
+pub fn start_replay(app: &mut App, ctx: &egui::Context) {
+    let recorder = &mut app.recorder;
+    recorder.recording = None;
+    let frame = recorder.export_frames.then_some(0);
+    recorder.replay = Some(Replay { start: ctx.input(|i| i.time), next: 0, frame });
+}
+
+pub fn stop_replay(app: &mut App) {
+    app.recorder.replay = None;
+}
+
+/// Record what changed since the last frame, or move the replay on. `screen` is the area
+/// exported frames are taken of.
+pub fn advance(app: &mut App, ctx: &egui::Context, screen: egui::Rect) {
+    let now = ctx.input(|i| i.time);
+    if let Some(start) = app.recorder.recording {
+        let snapshot = Snapshot::of(app);
+        if app.recorder.events.last().is_none_or(|(_, last)| *last != snapshot) {
+            app.recorder.events.push(((now - start) as f32, snapshot));
+        }
+    }
+
+    let Some(replay) = &mut app.recorder.replay else {
+        return;
+    };
+    let elapsed = match replay.frame {
+        Some(frame) => frame as f32 / FRAME_RATE,
+        None => (now - replay.start) as f32,
+    };
+    let events = &app.recorder.events;
+    let due = events[replay.next..].iter().take_while(|&&(time, _)| time <= elapsed).count();
+    let shown = (due > 0).then(|| events[replay.next + due - 1].1.clone());
+    replay.next += due;
+    let (frame, finished) = (replay.frame, replay.next >= events.len());
+    if let Some(Err(err)) = shown.map(|snapshot| snapshot.apply(app, ctx)) {
+        app.status = format!("Stopped the replay: {err}");
+        app.recorder.replay = None;
+        return;
+    }
+
+    if let Some(frame) = frame {
+        interface::export_png(app, screen, &format!("isohedral_{frame:05}.png"));
+        if !app.status.starts_with("Saved") {
+            app.recorder.replay = None;
+            return;
+        }
+        if let Some(replay) = &mut app.recorder.replay {
+            replay.frame = Some(frame + 1);
+        }
+    }
+    if finished {
+        app.status = match frame {
+            Some(frame) => format!("Saved {} frames of the replay", frame + 1),
+            None => String::from("Replayed the recording"),
+        };
+        app.recorder.replay = None;
+    }
+    ctx.request_repaint();
+}
@@ -0,0 +1,102 @@
+//! A reference image under the tiling, to match an existing design or trace a motif while
+//! editing the edges.
+//!
+//! The last PNG image opened can be laid under the tiling, dimmed, with the tile fills made
+//! see-through so it shows while the outlines stay. It is placed by its centre and width in world
+//! space, so it stays put under the tiling as the view moves, and it can't be picked or dragged.
+//! It isn't exported or saved with the project.
+use eframe::egui;
+use egui_isohedral::{colouring::MAX_COLOURS, export::Rgba, vec2, Vec2};
+
+use crate::app::App;
+
+
+/// The reference image and how it is shown.
+pub struct Reference {
+    name: String,
+    image: egui::ColorImage,
+    /// Made from `image` when it is first drawn.
+    texture: Option<egui::TextureHandle>,
+    pub shown: bool,
+    /// Where the middle of the image is, in world space.
+    pub centre: Vec2,
+    /// Width of the image in world units.
+    pub width: f32,
+    /// Opacity of the image over the background.
+    pub opacity: f32,
+    /// Opacity of the tile fills while the image is shown.
+    pub fill: f32,
+}
+
+impl Reference {
+    /// The image `pixels` of `size`, fitted into the middle of `screen`, not shown yet.
+    pub fn new(app: &App, name: &str, pixels: &[Rgba], size: [usize; 2], screen: egui::Rect) -> Self {
+        let image = egui::ColorImage::from_rgba_unmultiplied(size, pixels.as_flattened());
+        let fitted = 0.8 * (screen.width() / size[0] as f32).min(screen.height() / size[1] as f32);
+        Self {
+            name: name.to_owned(),
+            image,
+            texture: None,
+            shown: false,
+            centre: app.view.to_world(screen.center()),
+            width: fitted * size[0] as f32 / app.view.scale,
+            opacity: 0.6,
+            fill: 0.4,
+        }
+    }
+
+    /// Height of the image in world units.
+    fn height(&self) -> f32 {
+        self.width * self.image.size[1] as f32 / self.image.size[0].max(1) as f32
+    }
+}
+
+/// The tile fill colours `colours`, see-through while the reference is shown.
+pub fn fill_colours(app: &App, colours: [egui::Color32; MAX_COLOURS]) -> [egui::Color32; MAX_COLOURS] {
+    match &app.reference {
+        Some(reference) if reference.shown => colours.map(|c| c.gamma_multiply(reference.fill)),
+        _ => colours,
+    }
+}
+
+/// Draw the reference image, if it is shown.
+pub fn draw(app: &mut App, ctx: &egui::Context, painter: &egui::Painter) {
+    let view = app.view;
+    let Some(reference) = app.reference.as_mut().filter(|reference| reference.shown) else {
+        return;
+    };
+    let half = vec2(reference.width, reference.height()) * 0.5;
+    let rect = egui::Rect::from_two_pos(view.to_screen(reference.centre - half), view.to_screen(reference.centre + half));
+    let tint = egui::Color32::WHITE.gamma_multiply(reference.opacity);
+    let texture = reference.texture.get_or_insert_with(|| ctx.load_texture("reference image", reference.image.clone(), egui::TextureOptions::LINEAR));
+    let uv = egui::Rect::from_min_max(egui::pos2(0., 0.), egui::pos2(1., 1.));
+    painter.image(texture.id(), rect, uv, tint);
+}
+
+/// The settings of the reference image.
+pub fn section(app: &mut App, ui: &mut egui::Ui, screen: egui::Rect) {
+    let view = app.view;
+    let Some(reference) = &mut app.reference else {
+        ui.weak("Open or drop a PNG image to lay it under the tiling.");
+        return;
+    };
+    ui.checkbox(&mut reference.shown, format!("show {}", reference.name));
+    ui.add(egui::Slider::new(&mut reference.opacity, 0.0..=1.).text("image opacity"));
+    ui.add(egui::Slider::new(&mut reference.fill, 0.0..=1.).text("tile fill opacity"));
+    ui.horizontal(|ui| {
+        ui.label("centre");
+        ui.add(egui::DragValue::new(&mut reference.centre.x).speed(0.01).prefix("x "));
+        ui.add(egui::DragValue::new(&mut reference.centre.y).speed(0.01).prefix("y "));
+        ui.add(egui::DragValue::new(&mut reference.width).range(0.01..=1000.).speed(0.01).prefix("width "));
+    });
+    let mut remove = false;
+    ui.horizontal(|ui| {
+        if ui.button("Centre in view").clicked() {
+            reference.centre = view.to_world(screen.center());
+        }
+        remove = ui.button("Remove").clicked();
+    });
+    if remove {
+        app.reference = None;
+    }
+}
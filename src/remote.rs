@@ -0,0 +1,519 @@
+//! A WebSocket server that lets other programs drive the app live, such as installations,
+//! TouchDesigner patches or custom controllers.
+//!
+//! Each text message is a JSON object naming one of the [console](crate::console) commands and
+//! its arguments, and is answered with a JSON object holding the command's output:
+//!
+//! ```text
+//! {"command": "type", "args": ["IH04"]}   ->  {"ok": true, "output": "switched to IH04"}
+//! {"command": "params", "args": [0.1, 0.5]}
+//! {"command": "colours", "args": [3]}
+//! {"command": "export", "args": ["png"]}
+//! {"command": "bogus"}                    ->  {"ok": false, "error": "unknown command ..."}
+//! ```
+//!
+//! The server listens on [`DEFAULT_ADDRESS`], or on the address given with `--remote <address>`.
+//! Connections are served on their own threads, which hand commands to the app to run in its next
+//! frame.
+//!
+//! Browsers let any page open a WebSocket to a local address, so a handshake with an `Origin`
+//! header, which browsers always send, is refused unless that origin was allowed with
+//! `--remote-origin <origin>`, which can be given several times. Programs other than browsers
+//! send no `Origin` and are let in.
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc;
+use std::thread;
+
+use eframe::egui;
+
+use crate::{app::App, console};
+
+
+/// Where the server listens unless `--remote` says otherwise. Only local programs can connect.
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:9002";
+
+/// Appended to the client's key to compute the handshake's accept key, see RFC 6455.
+const HANDSHAKE_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Longest message the server accepts, in bytes.
+const MAX_MESSAGE: usize = 1 << 16;
+
+/// Deepest nesting of arrays and objects in a message; commands only need two levels.
+const MAX_DEPTH: usize = 8;
+
+/// A command received from a client, and where to send its reply.
+struct Request {
+    command: Result<String, String>,
+    reply: mpsc::Sender<String>,
+}
+
+/// The running server, handing received commands to the app.
+pub struct RemoteServer {
+    requests: mpsc::Receiver<Request>,
+}
+
+impl RemoteServer {
+    /// Start listening on `address`, waking up `ctx` whenever a command arrives. Browsers are only
+    /// let in from the `origins` given, see the [module docs](self).
+    pub fn start(ctx: &egui::Context, address: &str, origins: Vec<String>) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        let (sender, requests) = mpsc::channel();
+        let ctx = ctx.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let (sender, ctx, origins) = (sender.clone(), ctx.clone(), origins.clone());
+                thread::spawn(move || {
+                    // A failed connection only concerns its client.
+                    let _ = serve(stream, &sender, &ctx, &origins);
+                });
+            }
+        });
+        Ok(Self { requests })
+    }
+}
+
+/// The address to listen on given by `--remote <address>` on the command line, if any.
+pub fn address_from_args() -> String {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--remote"
+            && let Some(address) = args.next()
+        {
+            return address;
+        }
+    }
+    String::from(DEFAULT_ADDRESS)
+}
+
+/// The origins browsers may connect from, each given by `--remote-origin <origin>` on the command
+/// line.
+pub fn origins_from_args() -> Vec<String> {
+    let mut origins = vec![];
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--remote-origin"
+            && let Some(origin) = args.next()
+        {
+            origins.push(origin);
+        }
+    }
+    origins
+}
+
+/// Run the commands received since the last frame.
+pub fn poll(app: &mut App, ctx: &egui::Context) {
+    let Some(server) = &app.remote else {
+        return;
+    };
+    let requests: Vec<Request> = server.requests.try_iter().collect();
+    for request in requests {
+        let result = request.command.and_then(|command| console::execute(app, ctx, &command));
+        let reply = match result {
+            Ok(output) => format!("{{\"ok\": true, \"output\": {}}}", json_string(&output)),
+            Err(err) => format!("{{\"ok\": false, \"error\": {}}}", json_string(&err)),
+        };
+        // The client may have gone away in the meantime.
+        let _ = request.reply.send(reply);
+    }
+}
+
+/// Complete the handshake with a client, then pass its messages on until it disconnects.
+fn serve(stream: TcpStream, sender: &mpsc::Sender<Request>, ctx: &egui::Context, origins: &[String]) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    handshake(&mut reader, &mut writer, origins)?;
+
+    let (reply, replies) = mpsc::channel();
+    while let Some(message) = read_message(&mut reader, &mut writer)? {
+        let command = String::from_utf8(message).map_err(|_| String::from("messages must be UTF-8")).and_then(|text| command_line(&text));
+        if sender.send(Request { command, reply: reply.clone() }).is_err() {
+            break;
+        }
+        ctx.request_repaint();
+        let Ok(answer) = replies.recv() else {
+            break;
+        };
+        write_frame(&mut writer, OPCODE_TEXT, answer.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Read the client's HTTP upgrade request and accept it, unless it comes from an origin not in
+/// `origins`.
+fn handshake(reader: &mut impl BufRead, writer: &mut impl Write, origins: &[String]) -> io::Result<()> {
+    let mut key = None;
+    let mut origin = None;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            let name = name.trim();
+            if name.eq_ignore_ascii_case("sec-websocket-key") {
+                key = Some(value.trim().to_string());
+            } else if name.eq_ignore_ascii_case("origin") {
+                origin = Some(value.trim().to_string());
+            }
+        }
+    }
+    if let Some(origin) = origin
+        && !origins.contains(&origin)
+    {
+        writer.write_all(b"HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\n\r\n")?;
+        return Err(io::Error::new(io::ErrorKind::PermissionDenied, format!("origin {origin} not allowed")));
+    }
+    let Some(key) = key else {
+        writer.write_all(b"HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\n\r\n")?;
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "not a WebSocket handshake"));
+    };
+    let accept = base64(&sha1(format!("{key}{HANDSHAKE_GUID}").as_bytes()));
+    write!(writer, "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {accept}\r\n\r\n")
+}
+
+const OPCODE_CONTINUATION: u8 = 0x0;
+const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_BINARY: u8 = 0x2;
+const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+const OPCODE_PONG: u8 = 0xa;
+
+/// Read the next complete data message, answering pings and close frames on the way. Returns
+/// `None` once the client closes the connection.
+fn read_message(reader: &mut impl Read, writer: &mut impl Write) -> io::Result<Option<Vec<u8>>> {
+    let mut message = vec![];
+    loop {
+        let mut header = [0; 2];
+        reader.read_exact(&mut header)?;
+        let fin = header[0] & 0x80 != 0;
+        let opcode = header[0] & 0x0f;
+        let len = match header[1] & 0x7f {
+            126 => {
+                let mut len = [0; 2];
+                reader.read_exact(&mut len)?;
+                u16::from_be_bytes(len) as u64
+            }
+            127 => {
+                let mut len = [0; 8];
+                reader.read_exact(&mut len)?;
+                u64::from_be_bytes(len)
+            }
+            len => len as u64,
+        };
+        if len > MAX_MESSAGE as u64 || message.len() + len as usize > MAX_MESSAGE {
+            write_frame(writer, OPCODE_CLOSE, &1009u16.to_be_bytes())?;
+            return Ok(None);
+        }
+        // Clients always mask their frames.
+        let mut mask = [0; 4];
+        if header[1] & 0x80 != 0 {
+            reader.read_exact(&mut mask)?;
+        }
+        let mut payload = vec![0; len as usize];
+        reader.read_exact(&mut payload)?;
+        for (i, byte) in payload.iter_mut().enumerate() {
+            *byte ^= mask[i % 4];
+        }
+
+        match opcode {
+            OPCODE_CLOSE => {
+                write_frame(writer, OPCODE_CLOSE, &payload[..payload.len().min(2)])?;
+                return Ok(None);
+            }
+            OPCODE_PING => write_frame(writer, OPCODE_PONG, &payload)?,
+            OPCODE_TEXT | OPCODE_BINARY | OPCODE_CONTINUATION => {
+                message.extend(payload);
+                if fin {
+                    return Ok(Some(message));
+                }
+            }
+            // Pongs and unknown control frames.
+            _ => {}
+        }
+    }
+}
+
+/// Send one unmasked frame, as servers do.
+fn write_frame(writer: &mut impl Write, opcode: u8, payload: &[u8]) -> io::Result<()> {
+    let mut frame = vec![0x80 | opcode];
+    match payload.len() {
+        len @ 0..126 => frame.push(len as u8),
+        len @ 126..=0xffff => {
+            frame.push(126);
+            frame.extend((len as u16).to_be_bytes());
+        }
+        len => {
+            frame.push(127);
+            frame.extend((len as u64).to_be_bytes());
+        }
+    }
+    frame.extend(payload);
+    writer.write_all(&frame)
+}
+
+/// Turn a JSON command message into the console command it names.
+fn command_line(text: &str) -> Result<String, String> {
+    let invalid = || String::from(r#"a message must be a JSON object like {"command": "type", "args": [4]}"#);
+    let Json::Object(fields) = Json::parse(text).ok_or_else(invalid)? else {
+        return Err(invalid());
+    };
+    let mut words = vec![];
+    match fields.iter().find(|(key, _)| key == "command") {
+        Some((_, Json::String(command))) => words.push(command.clone()),
+        _ => return Err(invalid()),
+    }
+    match fields.iter().find(|(key, _)| key == "args") {
+        Some((_, Json::Array(args))) => {
+            for arg in args {
+                match arg {
+                    Json::String(arg) if !arg.is_empty() && !arg.contains(char::is_whitespace) => words.push(arg.clone()),
+                    Json::Number(arg) => words.push(arg.to_string()),
+                    _ => return Err(String::from("arguments must be numbers or strings without spaces")),
+                }
+            }
+        }
+        Some(_) => return Err(invalid()),
+        None => {}
+    }
+    Ok(words.join(" "))
+}
+
+/// A parsed JSON value. Only what command messages need is kept.
+#[derive(Debug, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Parse a complete JSON document.
+    fn parse(text: &str) -> Option<Self> {
+        let mut parser = JsonParser { text: text.as_bytes(), pos: 0, depth: 0 };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        (parser.pos == parser.text.len()).then_some(value)
+    }
+}
+
+struct JsonParser<'a> {
+    text: &'a [u8],
+    pos: usize,
+    /// Arrays and objects open around `pos`, at most [`MAX_DEPTH`].
+    depth: usize,
+}
+
+impl JsonParser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.text.get(self.pos).is_some_and(|b| b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    /// Skip whitespace and consume `byte` if it comes next.
+    fn eat(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        let found = self.text.get(self.pos) == Some(&byte);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn literal(&mut self, word: &str, value: Json) -> Option<Json> {
+        self.text[self.pos..].starts_with(word.as_bytes()).then(|| {
+            self.pos += word.len();
+            value
+        })
+    }
+
+    fn value(&mut self) -> Option<Json> {
+        self.skip_whitespace();
+        let open = matches!(self.text.get(self.pos)?, b'{' | b'[');
+        if open {
+            if self.depth == MAX_DEPTH {
+                return None;
+            }
+            self.depth += 1;
+        }
+        let value = self.scalar_or_nested();
+        if open {
+            self.depth -= 1;
+        }
+        value
+    }
+
+    fn scalar_or_nested(&mut self) -> Option<Json> {
+        match self.text.get(self.pos)? {
+            b'{' => {
+                self.pos += 1;
+                let mut fields = vec![];
+                if self.eat(b'}') {
+                    return Some(Json::Object(fields));
+                }
+                loop {
+                    self.skip_whitespace();
+                    let key = self.string()?;
+                    if !self.eat(b':') {
+                        return None;
+                    }
+                    fields.push((key, self.value()?));
+                    if self.eat(b'}') {
+                        return Some(Json::Object(fields));
+                    }
+                    if !self.eat(b',') {
+                        return None;
+                    }
+                }
+            }
+            b'[' => {
+                self.pos += 1;
+                let mut items = vec![];
+                if self.eat(b']') {
+                    return Some(Json::Array(items));
+                }
+                loop {
+                    items.push(self.value()?);
+                    if self.eat(b']') {
+                        return Some(Json::Array(items));
+                    }
+                    if !self.eat(b',') {
+                        return None;
+                    }
+                }
+            }
+            b'"' => self.string().map(Json::String),
+            b't' => self.literal("true", Json::Bool(true)),
+            b'f' => self.literal("false", Json::Bool(false)),
+            b'n' => self.literal("null", Json::Null),
+            _ => {
+                let start = self.pos;
+                while self.text.get(self.pos).is_some_and(|b| b.is_ascii_digit() || b"+-.eE".contains(b)) {
+                    self.pos += 1;
+                }
+                std::str::from_utf8(&self.text[start..self.pos]).ok()?.parse().ok().map(Json::Number)
+            }
+        }
+    }
+
+    fn string(&mut self) -> Option<String> {
+        if self.text.get(self.pos) != Some(&b'"') {
+            return None;
+        }
+        self.pos += 1;
+        let mut out = vec![];
+        loop {
+            let byte = *self.text.get(self.pos)?;
+            self.pos += 1;
+            match byte {
+                b'"' => return String::from_utf8(out).ok(),
+                b'\\' => {
+                    let escaped = *self.text.get(self.pos)?;
+                    self.pos += 1;
+                    let c = match escaped {
+                        b'n' => '\n',
+                        b't' => '\t',
+                        b'r' => '\r',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'u' => {
+                            let hex = std::str::from_utf8(self.text.get(self.pos..self.pos + 4)?).ok()?;
+                            self.pos += 4;
+                            // Surrogate pairs don't occur in commands, so they are replaced.
+                            char::from_u32(u32::from_str_radix(hex, 16).ok()?).unwrap_or(char::REPLACEMENT_CHARACTER)
+                        }
+                        other => other as char,
+                    };
+                    out.extend(c.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                _ => out.push(byte),
+            }
+        }
+    }
+}
+
+/// `text` as a JSON string literal.
+fn json_string(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// The SHA-1 digest of `data`, needed for the handshake only.
+fn sha1(data: &[u8]) -> [u8; 20] {
+    let mut h: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend((data.len() as u64 * 8).to_be_bytes());
+
+    for block in message.chunks(64) {
+        let mut w = [0u32; 80];
+        for (i, word) in block.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e] = h;
+        for (i, &word) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..20 => ((b & c) | (!b & d), 0x5a827999),
+                20..40 => (b ^ c ^ d, 0x6ed9eba1),
+                40..60 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+                _ => (b ^ c ^ d, 0xca62c1d6),
+            };
+            let t = a.rotate_left(5).wrapping_add(f).wrapping_add(e).wrapping_add(k).wrapping_add(word);
+            (e, d, c, b, a) = (d, c, b.rotate_left(30), a, t);
+        }
+        for (h, v) in h.iter_mut().zip([a, b, c, d, e]) {
+            *h = h.wrapping_add(v);
+        }
+    }
+
+    let mut digest = [0; 20];
+    for (bytes, word) in digest.chunks_mut(4).zip(h) {
+        bytes.copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
+
+/// Standard, padded base64 encoding.
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::new();
+    for chunk in data.chunks(3) {
+        let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
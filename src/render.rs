@@ -0,0 +1,543 @@
+//! Headless raster rendering of a tiling, independent of egui.
+//!
+//! Enabled by the `render` feature. Tiles are drawn with [tiny-skia] into an RGBA image, which can
+//! be used for command line tools, thumbnails or server-side rendering, or into a
+//! [`seamless_texture`] repeating one period of the tiling.
+//!
+//! [tiny-skia]: https://docs.rs/tiny-skia
+use std::collections::HashMap;
+
+use tiny_skia::{
+    BlendMode, Color, FillRule, FilterQuality, IntRect, LineCap, LineJoin, Paint, Path, PathBuilder, Pixmap, PixmapPaint, Stroke, Transform,
+};
+
+use crate::error::TilingError;
+use crate::export::contact_sheet::ContactSheet;
+use crate::export::{ExportOptions, Rgba};
+use crate::hatch;
+use crate::tessellate;
+use crate::tiling::IsohedralTiling;
+use crate::utils::{mat2, vec2, Affine2, Vec2};
+
+
+/// A rendered image.
+#[derive(Debug, Clone)]
+pub struct RenderedImage {
+    pixmap: Pixmap,
+    dpi: Option<f32>,
+}
+
+impl RenderedImage {
+    /// Width of the image in pixels.
+    pub fn width(&self) -> u32 {
+        self.pixmap.width()
+    }
+
+    /// Height of the image in pixels.
+    pub fn height(&self) -> u32 {
+        self.pixmap.height()
+    }
+
+    /// The pixels as rows of straight-alpha RGBA bytes, top row first.
+    pub fn to_rgba8(&self) -> Vec<u8> {
+        self.pixmap
+            .pixels()
+            .iter()
+            .flat_map(|p| {
+                let c = p.demultiply();
+                [c.red(), c.green(), c.blue(), c.alpha()]
+            })
+            .collect()
+    }
+
+    /// The resolution the image was rendered for, from [`ExportOptions::physical`].
+    pub fn dpi(&self) -> Option<f32> {
+        self.dpi
+    }
+
+    /// Encode the image as a PNG file, recording its resolution if it has one.
+    pub fn encode_png(&self) -> Result<Vec<u8>, TilingError> {
+        let mut png = vec![];
+        let mut encoder = png::Encoder::new(&mut png, self.width(), self.height());
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        if let Some(dpi) = self.dpi {
+            let per_metre = (dpi / 0.0254).round() as u32;
+            encoder.set_pixel_dims(Some(png::PixelDimensions { xppu: per_metre, yppu: per_metre, unit: png::Unit::Meter }));
+        }
+        let mut writer = encoder.write_header().map_err(|_| TilingError::PngEncoding)?;
+        writer.write_image_data(&self.to_rgba8()).map_err(|_| TilingError::PngEncoding)?;
+        writer.finish().map_err(|_| TilingError::PngEncoding)?;
+        Ok(png)
+    }
+}
+
+/// How [`RenderedImage::composite`] puts an image over another.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Blend {
+    /// Covering the image under it, as far as it is opaque.
+    #[default]
+    Normal,
+    /// Adding its colours to those under it, so overlapping tiles lighten each other.
+    Add,
+}
+
+impl RenderedImage {
+    /// Draw `layer`, an image of the same size, over this one at `opacity`.
+    pub fn composite(&mut self, layer: &RenderedImage, opacity: f32, blend: Blend) {
+        let blend_mode = match blend {
+            Blend::Normal => BlendMode::SourceOver,
+            Blend::Add => BlendMode::Plus,
+        };
+        let paint = PixmapPaint { opacity, blend_mode, quality: FilterQuality::Nearest };
+        self.pixmap.draw_pixmap(0, 0, layer.pixmap.as_ref(), &paint, Transform::identity(), None);
+    }
+}
+
+/// Render the tiling, with the given edge shapes, into an image.
+///
+/// `edges` holds one point sequence per edge shape, as for [`IsohedralTiling::outline`].
+pub fn render(
+    tiling: &IsohedralTiling,
+    edges: &[Vec<Vec2>],
+    options: &ExportOptions,
+) -> Result<RenderedImage, TilingError> {
+    let (width, height) = (options.width, options.height);
+    let mut pixmap = Pixmap::new(width, height).ok_or(TilingError::InvalidImageSize { width, height })?;
+    pixmap.fill(color(options.background));
+
+    let sampled = tiling.sample_edges(edges, options.segments);
+    let outline = tiling.outline(&sampled);
+    let min = options.origin;
+    let to_pixels = |p: Vec2| ((p.x - min.x) * options.scale, (p.y - min.y) * options.scale);
+
+    let mut paint = Paint {
+        anti_alias: true,
+        ..Paint::default()
+    };
+
+    let clip = clip_mask(options, width, height, to_pixels);
+    let clip = clip.as_ref();
+
+    // The tiles are filled from their triangles, the mesh of [`tessellate::fill_tiles`], each as
+    // one path so anti-aliasing leaves no seams between the triangles.
+    let tiles = options.fill(tiling).collect_tiles();
+    let mesh = tessellate::fill_tiles(tiling, &outline, tiles.iter().copied());
+    let (vertices, indices) = (outline.len(), mesh.mesh.indices.len() / tiles.len().max(1));
+    for (i, tile) in tiles.iter().enumerate() {
+        let world = &mesh.mesh.positions[i * vertices..(i + 1) * vertices];
+        let Some(path) = triangles_path(&mesh.mesh.positions, &mesh.mesh.indices[i * indices..(i + 1) * indices], to_pixels) else {
+            continue;
+        };
+
+        let class = tile.colour(tiling);
+        let pattern = options.pattern(class);
+        paint.set_color(color(if pattern.is_marked() { options.background } else { options.colour(class) }));
+        pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), clip);
+        if pattern.is_marked() {
+            paint_marks(&mut pixmap, &mut paint, pattern, world, options, clip, to_pixels);
+        }
+    }
+
+    // Strokes go on top of all the fills, one per edge rather than one per tile outline.
+    if options.stroke_width > 0. {
+        let stroke = Stroke {
+            width: options.stroke_width,
+            line_cap: LineCap::Round,
+            line_join: LineJoin::Round,
+            ..Stroke::default()
+        };
+        paint.set_color(color(options.stroke_colour));
+        for edge in tiling.unique_edges(&sampled, tiles.iter().map(|tile| tile.transform())) {
+            if let Some(path) = edge_path(&edge, to_pixels) {
+                pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), clip);
+            }
+        }
+    }
+
+    Ok(RenderedImage { pixmap, dpi: options.physical.map(|p| p.dpi) })
+}
+
+/// A texture holding exactly one period of a tiling, see [`seamless_texture`].
+#[derive(Debug, Clone)]
+pub struct SeamlessTexture {
+    image: RenderedImage,
+    rectangular: bool,
+    border_difference: u8,
+}
+
+impl SeamlessTexture {
+    /// The texture.
+    pub fn image(&self) -> &RenderedImage {
+        &self.image
+    }
+
+    /// Whether the period is a true rectangle of the tiling. Otherwise it is a parallelogram
+    /// sheared into the image, so the texture still wraps around but the tiles are distorted.
+    pub fn is_rectangular(&self) -> bool {
+        self.rectangular
+    }
+
+    /// The largest difference of a colour channel between the first row and column of the
+    /// texture and the rendering just past the opposite borders. Zero when the texture wraps
+    /// around exactly.
+    pub fn border_difference(&self) -> u8 {
+        self.border_difference
+    }
+}
+
+/// Largest multiple of a translation vector tried when looking for a rectangular period.
+const MAX_PERIOD_MULTIPLE: isize = 8;
+
+/// A translation of the tiling: `index.0 * t1 + index.1 * t2`.
+#[derive(Debug, Clone, Copy)]
+struct LatticeVector {
+    index: (isize, isize),
+    vector: Vec2,
+}
+
+impl LatticeVector {
+    fn new(tiling: &IsohedralTiling, i: isize, j: isize) -> Self {
+        Self { index: (i, j), vector: *tiling.t1() * i as f32 + *tiling.t2() * j as f32 }
+    }
+
+    fn combine(self, m: isize, other: Self, n: isize) -> Self {
+        Self {
+            index: (self.index.0 * m + other.index.0 * n, self.index.1 * m + other.index.1 * n),
+            vector: self.vector * m as f32 + other.vector * n as f32,
+        }
+    }
+}
+
+/// Render exactly one period of the tiling, colours included, so that the image wraps around
+/// seamlessly when repeated, as for textures in games and 3D scenes.
+///
+/// The period is a rectangle whenever the lattice contains one that is not too large, and is
+/// turned so that its sides are as close as possible to the axes, without mirroring.
+/// `options.origin` is the world point at the top left corner and `options.scale` the approximate
+/// number of pixels per world unit; the size of the image follows from the period, so
+/// `options.width` and `options.height` are ignored. The scale is adjusted slightly along each
+/// axis so the period spans a whole number of pixels.
+///
+/// Every tile of the period is rasterised once and copied to whole pixel offsets, so the
+/// wraparound is exact. [`SeamlessTexture::border_difference`] checks this against the pixels
+/// rendered past the opposite borders.
+pub fn seamless_texture(
+    tiling: &IsohedralTiling,
+    edges: &[Vec<Vec2>],
+    options: &ExportOptions,
+) -> Result<SeamlessTexture, TilingError> {
+    let (p1, p2) = tiling.colour_periods();
+    let a = LatticeVector::new(tiling, p1 as isize, 0);
+    let b = LatticeVector::new(tiling, 0, p2 as isize);
+    let (rectangular, (side, across)) = match rectangular_period(a, b) {
+        Some(period) => (true, period),
+        None => (false, (a, b)),
+    };
+    let (side, across) = upright(side, across);
+
+    // Map `side` to the top and `across` to the left edge of the image.
+    let (s, c) = (side.vector, across.vector);
+    let det = s.perp_dot(c);
+    let width = ((s.length() * options.scale).round() as u32).max(1);
+    let height = ((det / s.length() * options.scale).round() as u32).max(1);
+    let inverse = mat2(vec2(c.y / det, -s.y / det), vec2(-c.x / det, s.x / det));
+    let matrix2 = mat2(vec2(width as f32, 0.), vec2(0., height as f32)) * inverse;
+
+    // Render a margin all around the period, which includes the pixels past its right and bottom
+    // borders, and crop it afterwards.
+    let margin = options.stroke_width.ceil() as u32 + 2;
+    let view = Affine2 {
+        matrix2,
+        translation: vec2(margin as f32, margin as f32) - matrix2 * options.origin,
+    };
+    let (full_width, full_height) = (width + 2 * margin + 1, height + 2 * margin + 1);
+    let mut pixmap = Pixmap::new(full_width, full_height)
+        .ok_or(TilingError::InvalidImageSize { width: full_width, height: full_height })?;
+    pixmap.fill(color(options.background));
+
+    let corners = [Vec2::ZERO, s, c, s + c].map(|p| options.origin + p);
+    let (lo, hi) = corners.iter().fold((corners[0], corners[0]), |(lo, hi), p| {
+        (vec2(lo.x.min(p.x), lo.y.min(p.y)), vec2(hi.x.max(p.x), hi.y.max(p.y)))
+    });
+    let extra = tiling.t1().length() + tiling.t2().length() + 2. * margin as f32 / options.scale;
+    let mut tiles = tiling.fill_region(lo.x - extra, lo.y - extra, hi.x + extra, hi.y + extra).collect_tiles();
+    // Lattice order is the same around every copy, so overlaps blend the same way everywhere.
+    tiles.sort_by_key(|tile| (tile.t1(), tile.t2(), tile.aspect()));
+
+    let outline = tiling.outline(&tiling.sample_edges(edges, options.segments));
+    let mut layers = HashMap::new();
+    let span = side.index.0 * across.index.1 - side.index.1 * across.index.0;
+    for tile in tiles {
+        // Split the tile's position into whole periods and a representative inside the period.
+        let (i, j) = (tile.t1(), tile.t2());
+        let k = (i * across.index.1 - j * across.index.0).div_euclid(span);
+        let l = (j * side.index.0 - i * side.index.1).div_euclid(span);
+        let home = tiling.tile(
+            i - k * side.index.0 - l * across.index.0,
+            j - k * side.index.1 - l * across.index.1,
+            tile.aspect(),
+        );
+        let layer = layers
+            .entry((home.t1(), home.t2(), home.aspect()))
+            .or_insert_with(|| tile_layer(&outline, options, view * home.transform(), options.colour(home.colour(tiling))));
+        if let Some((layer, x, y)) = layer {
+            let (x, y) = (*x + k as i32 * width as i32, *y + l as i32 * height as i32);
+            let paint = PixmapPaint { quality: FilterQuality::Nearest, ..PixmapPaint::default() };
+            pixmap.draw_pixmap(x, y, layer.as_ref(), &paint, Transform::identity(), None);
+        }
+    }
+
+    let border_difference = border_difference(&pixmap, margin, width, height);
+    let pixmap = IntRect::from_xywh(margin as i32, margin as i32, width, height)
+        .and_then(|rect| pixmap.clone_rect(rect))
+        .ok_or(TilingError::InvalidImageSize { width, height })?;
+    Ok(SeamlessTexture { image: RenderedImage { pixmap, dpi: options.physical.map(|p| p.dpi) }, rectangular, border_difference })
+}
+
+/// Render a [`ContactSheet`] of every tiling type into one image.
+///
+/// Labels are drawn with a small built-in pixel font, so no font is needed.
+pub fn contact_sheet(sheet: &ContactSheet) -> Result<RenderedImage, TilingError> {
+    let (width, height) = (sheet.width(), sheet.height());
+    let mut pixmap = Pixmap::new(width, height).ok_or(TilingError::InvalidImageSize { width, height })?;
+    pixmap.fill(color(sheet.style.background));
+
+    let pixel = (sheet.label_height / (GLYPH_HEIGHT + 2)).max(1);
+    let mut paint = Paint::default();
+    paint.set_color(color(sheet.style.stroke_colour));
+    for cell in sheet.cells() {
+        let sample = render(&cell.tiling, &cell.edges, &cell.options)?;
+        pixmap.draw_pixmap(cell.x as i32, cell.y as i32, sample.pixmap.as_ref(), &PixmapPaint::default(), Transform::identity(), None);
+
+        let text_width = cell.label.len() as u32 * (GLYPH_WIDTH + 1) * pixel - pixel;
+        let x = cell.x + sheet.cell_size.saturating_sub(text_width) / 2;
+        let y = cell.y + sheet.cell_size + sheet.label_height.saturating_sub(GLYPH_HEIGHT * pixel) / 2;
+        for (k, c) in cell.label.chars().enumerate() {
+            let x = x + k as u32 * (GLYPH_WIDTH + 1) * pixel;
+            for (row, bits) in glyph(c).iter().enumerate() {
+                for column in (0..GLYPH_WIDTH).filter(|column| bits >> (GLYPH_WIDTH - 1 - column) & 1 == 1) {
+                    let Some(rect) = tiny_skia::Rect::from_xywh(
+                        (x + column * pixel) as f32,
+                        (y + row as u32 * pixel) as f32,
+                        pixel as f32,
+                        pixel as f32,
+                    ) else {
+                        continue;
+                    };
+                    pixmap.fill_rect(rect, &paint, Transform::identity(), None);
+                }
+            }
+        }
+    }
+
+    Ok(RenderedImage { pixmap, dpi: None })
+}
+
+/// Size of a glyph of the pixel font used by [`contact_sheet`].
+const GLYPH_WIDTH: u32 = 5;
+const GLYPH_HEIGHT: u32 = 7;
+
+/// The rows of a glyph of the pixel font, top first, with the leftmost pixel in the highest bit.
+/// Only the characters of tiling type names are drawn; anything else is blank.
+fn glyph(c: char) -> [u8; GLYPH_HEIGHT as usize] {
+    match c {
+        '0' => [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110],
+        '1' => [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        '2' => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111],
+        '3' => [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110],
+        '4' => [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010],
+        '5' => [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110],
+        '6' => [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110],
+        '7' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000],
+        '8' => [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110],
+        '9' => [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100],
+        'I' => [0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        'H' => [0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001],
+        _ => [0; GLYPH_HEIGHT as usize],
+    }
+}
+
+/// A lattice vector perpendicular to `a` or to `b`, together with that one, forming the smallest
+/// rectangular period found.
+fn rectangular_period(a: LatticeVector, b: LatticeVector) -> Option<(LatticeVector, LatticeVector)> {
+    let area = |(s, c): (LatticeVector, LatticeVector)| s.vector.length() * c.vector.length();
+    let mut best: Option<(LatticeVector, LatticeVector)> = None;
+    for (side, other) in [(a, b), (b, a)] {
+        let (s, o) = (side.vector, other.vector);
+        for n in 1..=MAX_PERIOD_MULTIPLE {
+            // `m * side + n * other` is perpendicular to `side` for this `m`.
+            let m = (-(n as f32) * o.dot(s) / s.dot(s)).round() as isize;
+            let across = side.combine(m, other, n);
+            if across.vector.dot(s).abs() <= 1e-4 * across.vector.length() * s.length() {
+                if best.is_none_or(|period| area((side, across)) < area(period) - 1e-4) {
+                    best = Some((side, across));
+                }
+                break;
+            }
+        }
+    }
+    best
+}
+
+/// Orient a period so that `side` points as close to the positive x axis as possible and
+/// `side` followed by `across` doesn't mirror the image.
+fn upright(side: LatticeVector, across: LatticeVector) -> (LatticeVector, LatticeVector) {
+    let (mut side, mut across) = if side.vector.x.abs() >= side.vector.y.abs() { (side, across) } else { (across, side) };
+    if side.vector.x < 0. {
+        side = side.combine(-1, side, 0);
+    }
+    if side.vector.perp_dot(across.vector) < 0. {
+        across = across.combine(-1, across, 0);
+    }
+    (side, across)
+}
+
+/// One tile, filled and stroked, in a layer of its own, with the pixel position of the layer.
+fn tile_layer(
+    outline: &[Vec2],
+    options: &ExportOptions,
+    transform: Affine2,
+    colour: Rgba,
+) -> Option<(Pixmap, i32, i32)> {
+    let path = tile_path(outline, |p| {
+        let p = transform.transform_point2(p);
+        (p.x, p.y)
+    })?;
+    let bounds = path.bounds();
+    let pad = options.stroke_width / 2. + 2.;
+    let (x, y) = ((bounds.left() - pad).floor(), (bounds.top() - pad).floor());
+    let (width, height) = ((bounds.right() + pad).ceil() - x, (bounds.bottom() + pad).ceil() - y);
+    let mut layer = Pixmap::new(width as u32, height as u32)?;
+    let path = path.transform(Transform::from_translate(-x, -y))?;
+
+    let mut paint = Paint {
+        anti_alias: true,
+        ..Paint::default()
+    };
+    paint.set_color(color(colour));
+    layer.fill_path(&path, &paint, FillRule::EvenOdd, Transform::identity(), None);
+    if options.stroke_width > 0. {
+        let stroke = Stroke {
+            width: options.stroke_width,
+            line_join: LineJoin::Round,
+            ..Stroke::default()
+        };
+        paint.set_color(color(options.stroke_colour));
+        layer.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+    }
+    Some((layer, x as i32, y as i32))
+}
+
+/// The largest channel difference between the first row and column of the period starting at
+/// `(margin, margin)` in `pixmap` and the ones just past its end.
+fn border_difference(pixmap: &Pixmap, margin: u32, width: u32, height: u32) -> u8 {
+    let pixel = |x: u32, y: u32| {
+        pixmap.pixel(x + margin, y + margin).map_or([0; 4], |p| {
+            let c = p.demultiply();
+            [c.red(), c.green(), c.blue(), c.alpha()]
+        })
+    };
+    let difference = |p: [u8; 4], q: [u8; 4]| (0..4).map(|i| p[i].abs_diff(q[i])).max().unwrap_or(0);
+    let columns = (0..height).map(|y| difference(pixel(0, y), pixel(width, y)));
+    let rows = (0..width).map(|x| difference(pixel(x, 0), pixel(x, height)));
+    columns.chain(rows).max().unwrap_or(0)
+}
+
+/// Draw the lines and dots of `pattern` inside the world-space outline `world` of a tile.
+fn paint_marks(
+    pixmap: &mut Pixmap,
+    paint: &mut Paint,
+    pattern: hatch::Pattern,
+    world: &[Vec2],
+    options: &ExportOptions,
+    clip: Option<&tiny_skia::Mask>,
+    to_pixels: impl Fn(Vec2) -> (f32, f32),
+) {
+    paint.set_color(color(options.stroke_colour));
+    let stroke = Stroke {
+        width: hatch::mark_width(options.stroke_width),
+        ..Stroke::default()
+    };
+    for line in pattern.lines(world) {
+        if let Some(path) = edge_path(&line, &to_pixels) {
+            pixmap.stroke_path(&path, paint, &stroke, Transform::identity(), clip);
+        }
+    }
+    let (dots, radius) = pattern.dots(world);
+    for dot in dots {
+        let (x, y) = to_pixels(dot);
+        if let Some(path) = PathBuilder::from_circle(x, y, radius * options.scale) {
+            pixmap.fill_path(&path, paint, FillRule::Winding, Transform::identity(), clip);
+        }
+    }
+}
+
+/// The pixels inside [`ExportOptions::mask`], or `None` to draw everywhere.
+fn clip_mask(options: &ExportOptions, width: u32, height: u32, to_pixels: impl Fn(Vec2) -> (f32, f32)) -> Option<tiny_skia::Mask> {
+    let mut builder = PathBuilder::new();
+    for shape in options.mask.as_ref()?.loops() {
+        for (i, &p) in shape.iter().enumerate() {
+            let (x, y) = to_pixels(p);
+            if i == 0 {
+                builder.move_to(x, y);
+            } else {
+                builder.line_to(x, y);
+            }
+        }
+        builder.close();
+    }
+    let mut clip = tiny_skia::Mask::new(width, height)?;
+    // A mask outside the image hides everything.
+    if let Some(path) = builder.finish() {
+        clip.fill_path(&path, FillRule::EvenOdd, true, Transform::identity());
+    }
+    Some(clip)
+}
+
+/// The closed path through the outline's points, mapped to pixels by `to_pixel`.
+fn tile_path(outline: &[Vec2], to_pixel: impl Fn(Vec2) -> (f32, f32)) -> Option<Path> {
+    polyline(outline, true, to_pixel)
+}
+
+/// The triangles `indices` into `positions`, as one path mapped to pixels by `to_pixel`.
+fn triangles_path(positions: &[Vec2], indices: &[u32], to_pixel: impl Fn(Vec2) -> (f32, f32)) -> Option<Path> {
+    let mut builder = PathBuilder::new();
+    for triangle in indices.chunks_exact(3) {
+        for (k, &i) in triangle.iter().enumerate() {
+            let (x, y) = to_pixel(positions[i as usize]);
+            if k == 0 {
+                builder.move_to(x, y);
+            } else {
+                builder.line_to(x, y);
+            }
+        }
+        builder.close();
+    }
+    builder.finish()
+}
+
+fn edge_path(edge: &[Vec2], to_pixel: impl Fn(Vec2) -> (f32, f32)) -> Option<Path> {
+    polyline(edge, false, to_pixel)
+}
+
+fn polyline(points: &[Vec2], close: bool, to_pixel: impl Fn(Vec2) -> (f32, f32)) -> Option<Path> {
+    let mut builder = PathBuilder::new();
+    for (i, &p) in points.iter().enumerate() {
+        let (x, y) = to_pixel(p);
+        if i == 0 {
+            builder.move_to(x, y);
+        } else {
+            builder.line_to(x, y);
+        }
+    }
+    if close {
+        builder.close();
+    }
+    builder.finish()
+}
+
+fn color(c: Rgba) -> Color {
+    Color::from_rgba8(c[0], c[1], c[2], c[3])
+}
+
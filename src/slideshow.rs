@@ -0,0 +1,129 @@
+//! A demo mode that cycles through the tiling types on its own, for exhibitions and displays.
+//!
+//! Every few seconds a random tiling type is picked with random parameters and edges, and the
+//! previous tiling fades out over it. The interface is hidden while the slideshow runs.
+//!
+//! As a screensaver the slideshow runs full-screen and never stops: the parameters sway
+//! continuously, every tiling comes with a new colour theme, and any key or button exits.
+use eframe::egui;
+use egui_isohedral::{get_tiling_type, tiling::IsohedralTiling, Vec2, TILING_TYPES};
+use rand::Rng;
+
+use crate::app::{App, Roll};
+
+
+/// Seconds for a parameter of the screensaver to sway back and forth once.
+const SWAY_PERIOD: f64 = 20.;
+
+/// How far the parameters of the screensaver sway from their starting values.
+const SWAY_AMPLITUDE: f32 = 0.15;
+
+/// Frames per second the screensaver is redrawn at while only the parameters sway. They change
+/// slowly, so a full display rate would only cost power.
+const SWAY_FRAME_RATE: f32 = 30.;
+
+/// State of the slideshow.
+pub struct Slideshow {
+    pub active: bool,
+    /// Seconds each tiling is shown for.
+    pub interval: f32,
+    /// Seconds the crossfade between two tilings takes.
+    pub fade: f32,
+    /// Time of the last change of tiling, in seconds, or `None` before the first one.
+    changed: Option<f64>,
+    fading: Option<Fading>,
+}
+
+/// The tiling shown before the last change, while it fades out.
+pub struct Fading {
+    pub tiling: IsohedralTiling,
+    pub edges: Vec<Vec<Vec2>>,
+    /// How much of it is still visible, from `1` down to `0`.
+    pub opacity: f32,
+}
+
+impl Default for Slideshow {
+    fn default() -> Self {
+        Self { active: false, interval: 6., fade: 1.5, changed: None, fading: None }
+    }
+}
+
+impl Slideshow {
+    /// Start the slideshow from the tiling currently shown.
+    pub fn start(&mut self) {
+        self.active = true;
+        self.changed = None;
+        self.fading = None;
+    }
+
+    pub fn stop(&mut self) {
+        self.active = false;
+        self.fading = None;
+    }
+
+    /// The previous tiling, if it is still fading out.
+    pub fn fading(&self) -> Option<&Fading> {
+        self.fading.as_ref()
+    }
+}
+
+/// Move the slideshow on: switch to a new random tiling when the interval is over, and update the
+/// crossfade. Escape stops the slideshow.
+pub fn advance(app: &mut App, ctx: &egui::Context) {
+    if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+        app.slideshow.stop();
+        return;
+    }
+    let now = ctx.input(|i| i.time);
+    let changed = *app.slideshow.changed.get_or_insert(now);
+
+    if now - changed >= app.slideshow.interval as f64 {
+        app.next_seed();
+        app.tile_type_num = app.rng(Roll::TilingType).gen_range(0..TILING_TYPES.len());
+        let previous = std::mem::replace(&mut app.tiling, IsohedralTiling::new(get_tiling_type(app.tile_type_num)));
+        let edges = std::mem::take(&mut app.edges_shapes);
+        app.set_default_params();
+        app.set_random_params();
+        app.set_random_edges();
+        app.apply_colouring();
+        app.slideshow.fading = Some(Fading { tiling: previous, edges, opacity: 1. });
+        app.slideshow.changed = Some(now);
+        if app.screensaver {
+            app.set_random_theme(ctx);
+        }
+    }
+
+    let elapsed = (now - app.slideshow.changed.unwrap_or(now)) as f32;
+    if let Some(fading) = &mut app.slideshow.fading {
+        fading.opacity = 1. - (elapsed / app.slideshow.fade.max(f32::EPSILON)).min(1.);
+    }
+    if app.slideshow.fading.as_ref().is_some_and(|fading| fading.opacity <= 0.) {
+        app.slideshow.fading = None;
+    }
+
+    if app.slideshow.fading.is_some() {
+        ctx.request_repaint();
+    } else {
+        ctx.request_repaint_after_secs((app.slideshow.interval - elapsed).max(0.));
+    }
+}
+
+/// Run the slideshow as a screensaver: close on any key or button, otherwise sway the
+/// parameters of the current tiling around the values it was picked with.
+pub fn screensaver(app: &mut App, ctx: &egui::Context) {
+    let exit = ctx.input(|i| i.events.iter().any(|event| matches!(event, egui::Event::Key { pressed: true, .. } | egui::Event::PointerButton { pressed: true, .. })));
+    if exit {
+        ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+    }
+    app.slideshow.active = true;
+
+    let now = ctx.input(|i| i.time);
+    let elapsed = now - app.slideshow.changed.unwrap_or(now);
+    let mut params = app.params;
+    for (i, p) in params.iter_mut().enumerate().take(app.tiling.num_params()) {
+        let phase = std::f64::consts::TAU * (elapsed / SWAY_PERIOD + i as f64 / 6.);
+        *p = (*p + SWAY_AMPLITUDE * (phase.sin() - (i as f64 / 6. * std::f64::consts::TAU).sin()) as f32).clamp(0., 1.);
+    }
+    app.tiling.set_parameters(&params);
+    ctx.request_repaint_after_secs(1. / SWAY_FRAME_RATE);
+}
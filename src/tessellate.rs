@@ -0,0 +1,185 @@
+//! Tessellation of tile outlines into triangle meshes.
+//!
+//! The meshes are independent of any renderer, so the same geometry can be fed to egui, to an
+//! exporter or to a game engine. Fills use [`triangulate`], so concave outlines are handled, and
+//! strokes are built from one quad per segment plus a join at every vertex.
+//!
+//! The PNG export fills its tiles from [`fill_tiles`], and the app strokes tile outlines on screen
+//! with [`stroke_closed`].
+//!
+//! [`triangulate`]: crate::triangulate
+use crate::iterators::Tile;
+use crate::tiling::IsohedralTiling;
+use crate::utils::{triangulate, vec2, Vec2};
+
+
+/// A triangle mesh: positions and indices into them, three per triangle.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Mesh {
+    pub positions: Vec<Vec2>,
+    pub indices: Vec<u32>,
+}
+
+impl Mesh {
+    /// Remove all the triangles, keeping the allocations.
+    pub fn clear(&mut self) {
+        self.positions.clear();
+        self.indices.clear();
+    }
+
+    fn add_triangle(&mut self, a: Vec2, b: Vec2, c: Vec2) {
+        let base = self.positions.len() as u32;
+        self.positions.extend([a, b, c]);
+        self.indices.extend([base, base + 1, base + 2]);
+    }
+}
+
+/// How two stroke segments are joined at a vertex.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LineJoin {
+    /// Extend both segments until they meet, falling back to [`Bevel`] beyond the miter limit.
+    ///
+    /// [`Bevel`]: LineJoin::Bevel
+    #[default]
+    Miter,
+    /// Round the outer corner with a circular arc.
+    Round,
+    /// Cut the outer corner off with a straight line.
+    Bevel,
+}
+
+/// Parameters for [`stroke_closed`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrokeOptions {
+    /// Width of the stroke, in the same units as the outline.
+    pub width: f32,
+    /// The join between consecutive segments.
+    pub join: LineJoin,
+    /// Maximum ratio between the miter length and half the stroke width.
+    pub miter_limit: f32,
+    /// Maximum deviation of round joins from the true arc.
+    pub tolerance: f32,
+}
+
+impl Default for StrokeOptions {
+    fn default() -> Self {
+        Self {
+            width: 1.,
+            join: LineJoin::Miter,
+            miter_limit: 4.,
+            tolerance: 0.01,
+        }
+    }
+}
+
+/// Append the triangles filling a closed outline to `out`.
+pub fn fill(outline: &[Vec2], out: &mut Mesh) {
+    let base = out.positions.len() as u32;
+    out.positions.extend_from_slice(outline);
+    out.indices.extend(triangulate(outline).into_iter().map(|i| base + i));
+}
+
+/// Many tiles in one mesh, with the colour class of every vertex.
+///
+/// This is what a game engine needs to draw a filled region as a single mesh: for Bevy, the
+/// positions with a zero `z` go into `Mesh::ATTRIBUTE_POSITION`, the indices into
+/// `Indices::U32`, and the classes, looked up in a palette, into `Mesh::ATTRIBUTE_COLOR`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TileMesh {
+    pub mesh: Mesh,
+    /// The colour class of each position, see [`IsohedralTiling::colour`].
+    pub classes: Vec<u32>,
+}
+
+/// Fill every tile of `tiles`, such as those of [`IsohedralTiling::fill_region`], with the
+/// prototile `outline` moved into place. The outline is triangulated once.
+pub fn fill_tiles(tiling: &IsohedralTiling, outline: &[Vec2], tiles: impl IntoIterator<Item = Tile>) -> TileMesh {
+    let triangles = triangulate(outline);
+    let mut out = TileMesh::default();
+    for tile in tiles {
+        let (transform, class) = (tile.transform(), tile.colour(tiling) as u32);
+        let base = out.mesh.positions.len() as u32;
+        out.mesh.positions.extend_from_slice(outline);
+        transform.transform_points(&mut out.mesh.positions[base as usize..]);
+        out.mesh.indices.extend(triangles.iter().map(|i| base + i));
+        out.classes.extend(std::iter::repeat_n(class, outline.len()));
+    }
+    out
+}
+
+/// Append the triangles stroking a closed outline to `out`.
+///
+/// Segments overlap at the inside of every corner, so the mesh should be drawn opaque.
+pub fn stroke_closed(outline: &[Vec2], options: &StrokeOptions, out: &mut Mesh) {
+    let points = dedup(outline);
+    let n = points.len();
+    if n < 2 || options.width <= 0. {
+        return;
+    }
+    let half = options.width / 2.;
+
+    for i in 0..n {
+        let (p0, p1, p2) = (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+        let n1 = normal(p1, p2);
+
+        // One quad per segment.
+        let base = out.positions.len() as u32;
+        out.positions.extend([p1 + n1 * half, p2 + n1 * half, p2 - n1 * half, p1 - n1 * half]);
+        out.indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+
+        // The join between the previous segment and this one, on the outer side of the turn.
+        let n0 = normal(p0, p1);
+        let turn = (p1 - p0).perp_dot(p2 - p1);
+        if turn.abs() <= f32::EPSILON {
+            continue;
+        }
+        let side = if turn > 0. { -1. } else { 1. };
+        let (a, b) = (n0 * (half * side), n1 * (half * side));
+
+        match options.join {
+            LineJoin::Bevel => out.add_triangle(p1, p1 + a, p1 + b),
+            LineJoin::Miter => {
+                let m = a + b;
+                let cos = m.dot(a) / (m.length() * half);
+                let len = half / cos.max(f32::EPSILON);
+                out.add_triangle(p1, p1 + a, p1 + b);
+                if len / half <= options.miter_limit {
+                    out.add_triangle(p1 + a, p1 + m * (len / m.length()), p1 + b);
+                }
+            }
+            LineJoin::Round => {
+                let angle = a.perp_dot(b).atan2(a.dot(b));
+                let step = 2. * (1. - options.tolerance / half).clamp(-1., 1.).acos();
+                let steps = (angle.abs() / step.max(0.01)).ceil().max(1.) as usize;
+                let mut prev = a;
+                for s in 1..=steps {
+                    let (sin, cos) = (angle * s as f32 / steps as f32).sin_cos();
+                    let next = vec2(a.x * cos - a.y * sin, a.x * sin + a.y * cos);
+                    out.add_triangle(p1, p1 + prev, p1 + next);
+                    prev = next;
+                }
+            }
+        }
+    }
+}
+
+/// The outline without consecutive repeated points, including the closing one.
+pub(crate) fn dedup(outline: &[Vec2]) -> Vec<Vec2> {
+    let mut points: Vec<Vec2> = Vec::with_capacity(outline.len());
+    for &p in outline {
+        if points.last() != Some(&p) {
+            points.push(p);
+        }
+    }
+    while points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    points
+}
+
+/// The unit normal to the left of the segment from `p` to `q`.
+pub(crate) fn normal(p: Vec2, q: Vec2) -> Vec2 {
+    let d = q - p;
+    let len = d.length();
+    vec2(-d.y / len, d.x / len)
+}
@@ -0,0 +1,60 @@
+//! Small preview images of saved projects.
+//!
+//! A project's thumbnail is a PNG rendered when it is saved and stored next to it, see
+//! [`thumbnail_path`]. Thumbnails are loaded into textures on first use.
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use eframe::egui;
+
+
+/// Width and height of a thumbnail in pixels.
+pub const THUMBNAIL_SIZE: u32 = 96;
+
+/// Where the thumbnail of the project at `project` is stored: the project's path with `.png`
+/// appended.
+pub fn thumbnail_path(project: &Path) -> PathBuf {
+    let mut path = project.as_os_str().to_owned();
+    path.push(".png");
+    PathBuf::from(path)
+}
+
+/// Thumbnail textures by project path. Projects without a readable thumbnail are remembered too,
+/// so their files aren't read every frame.
+#[derive(Default)]
+pub struct Thumbnails {
+    textures: HashMap<PathBuf, Option<egui::TextureHandle>>,
+}
+
+impl Thumbnails {
+    /// The thumbnail of the project at `project`, loaded on first use.
+    pub fn get(&mut self, ctx: &egui::Context, project: &Path) -> Option<&egui::TextureHandle> {
+        self.textures
+            .entry(project.to_path_buf())
+            .or_insert_with(|| {
+                let image = load(&thumbnail_path(project))?;
+                Some(ctx.load_texture(project.display().to_string(), image, egui::TextureOptions::LINEAR))
+            })
+            .as_ref()
+    }
+
+    /// Drop the cached thumbnail of `project`, after it has been saved again.
+    pub fn forget(&mut self, project: &Path) {
+        // The recent files list holds canonical paths.
+        let project = project.canonicalize().unwrap_or_else(|_| project.to_path_buf());
+        self.textures.remove(&project);
+    }
+}
+
+/// Decode an RGBA PNG file, as written by the headless renderer.
+fn load(path: &Path) -> Option<egui::ColorImage> {
+    let file = std::fs::File::open(path).ok()?;
+    let mut reader = png::Decoder::new(std::io::BufReader::new(file)).read_info().ok()?;
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut pixels).ok()?;
+    if info.color_type != png::ColorType::Rgba || info.bit_depth != png::BitDepth::Eight {
+        return None;
+    }
+    let size = [info.width as usize, info.height as usize];
+    Some(egui::ColorImage::from_rgba_unmultiplied(size, &pixels[..info.buffer_size()]))
+}
@@ -0,0 +1,234 @@
+//! Customisable drawing of the tiles on screen.
+//!
+//! Every frame, the visible tiles are handed to the app's [`TilePainter`] one by one with their
+//! colour classes, and then all together for whatever goes on top of them. [`DefaultPainter`]
+//! fills each tile with the colour of its colour class and strokes every edge once, or every
+//! tile's own outline if there are gaps between the tiles; [`WeavePainter`] draws interlaced
+//! ribbons over the tiles instead of their edges. Other painters can draw anything into the frame's mesh.
+use eframe::egui;
+use eframe::epaint::tessellator::Path;
+use egui_isohedral::{hatch::{self, Pattern}, iterators::Tile, tessellate::{self, LineJoin, StrokeOptions}, tiling::IsohedralTiling, view::ViewState, vec2, weave, Vec2};
+
+
+/// Everything a [`TilePainter`] is given to draw a frame with.
+pub struct PaintContext<'a> {
+    pub tiling: &'a IsohedralTiling,
+    /// The prototile outline, to be transformed by each tile's transform.
+    pub outline: &'a [Vec2],
+    /// Triangle indices into `outline`, filling the prototile.
+    pub triangles: &'a [u32],
+    /// Each edge shape sampled into points, in the edge frame.
+    pub edges: &'a [Vec<Vec2>],
+    /// Fill colour of each colour class.
+    pub colours: &'a [egui::Color32],
+    /// Fill pattern of each colour class, flat for the classes without one.
+    pub patterns: &'a [Pattern],
+    pub stroke: egui::Stroke,
+    /// The colour of the canvas behind the tiles.
+    pub background: egui::Color32,
+    /// The mapping from world space to the screen.
+    pub view: ViewState,
+    /// Width of the anti-aliasing fringe, in screen points.
+    pub feathering: f32,
+    /// Whether the tiles are drawn apart, with `outline` already shrunk or inset, so neighbouring
+    /// tiles no longer share their edges.
+    pub apart: bool,
+    /// The join at the corners of tiles drawn apart.
+    pub join: LineJoin,
+}
+
+impl PaintContext<'_> {
+    /// The screen position of the world-space point `p`.
+    pub fn to_screen(&self, p: Vec2) -> egui::Pos2 {
+        self.view.to_screen(p)
+    }
+
+    /// The fill colour of colour class `class`.
+    pub fn colour(&self, class: usize) -> egui::Color32 {
+        self.colours[class % self.colours.len()]
+    }
+
+    /// The fill pattern of colour class `class`.
+    pub fn pattern(&self, class: usize) -> Pattern {
+        self.patterns.get(class).copied().unwrap_or_default()
+    }
+}
+
+/// Add the stroke around the closed outline `points`, in screen points, to `mesh`, tessellated
+/// into `scratch` by [`tessellate::stroke_closed`].
+pub fn stroke_outline(points: &[Vec2], stroke: egui::Stroke, join: LineJoin, scratch: &mut tessellate::Mesh, mesh: &mut egui::Mesh) {
+    scratch.clear();
+    tessellate::stroke_closed(points, &StrokeOptions { width: stroke.width, join, ..StrokeOptions::default() }, scratch);
+    let base = mesh.vertices.len() as u32;
+    mesh.vertices.extend(scratch.positions.iter().map(|p| egui::epaint::Vertex { pos: egui::pos2(p.x, p.y), uv: egui::epaint::WHITE_UV, color: stroke.color }));
+    mesh.indices.extend(scratch.indices.iter().map(|i| base + i));
+}
+
+/// Draws the tiles of the main view into its mesh.
+pub trait TilePainter {
+    /// Add `tile`, of colour class `class`, to `mesh`.
+    fn paint_tile(&mut self, cx: &PaintContext, tile: &Tile, class: usize, mesh: &mut egui::Mesh);
+
+    /// Add what goes on top of all the tiles to `mesh`, once every tile has been painted.
+    fn paint_overlay(&mut self, _cx: &PaintContext, _tiles: &[Tile], _mesh: &mut egui::Mesh) {}
+}
+
+// `App` derives `Default`, which needs a painter to start with.
+impl Default for Box<dyn TilePainter> {
+    fn default() -> Self {
+        Box::new(DefaultPainter::default())
+    }
+}
+
+/// How the tiles are drawn, picked in the Style section.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Rendering {
+    /// Filled tiles with stroked edges, see [`DefaultPainter`].
+    #[default]
+    Tiles,
+    /// Ribbons of `width` world units crossing the edges at `angle` radians, see [`WeavePainter`].
+    Weave { width: f32, angle: f32 },
+}
+
+impl Rendering {
+    /// The weave a switch to weaving starts with.
+    pub const WEAVE: Self = Rendering::Weave { width: 0.08, angle: std::f32::consts::FRAC_PI_4 };
+
+    /// A painter drawing the tiles this way.
+    pub fn painter(self) -> Box<dyn TilePainter> {
+        match self {
+            Rendering::Tiles => Box::new(DefaultPainter::default()),
+            Rendering::Weave { width, angle } => Box::new(WeavePainter::new(width, angle)),
+        }
+    }
+}
+
+/// Fills every tile from the prototile's triangulation and strokes each edge once, see
+/// [`IsohedralTiling::unique_edges`]. Tiles drawn apart are stroked one by one.
+#[derive(Default)]
+pub struct DefaultPainter {
+    points: Vec<egui::Pos2>,
+    path: Path,
+    /// The outline of a tile drawn apart, in screen points, and its stroke.
+    outline: Vec<Vec2>,
+    stroke: tessellate::Mesh,
+}
+
+impl DefaultPainter {
+    fn paint_pattern(&mut self, cx: &PaintContext, pattern: Pattern, tile: &Tile, mesh: &mut egui::Mesh) {
+        let mut world = cx.outline.to_vec();
+        tile.transform().transform_points(&mut world);
+        let stroke = egui::epaint::PathStroke::new(hatch::mark_width(cx.stroke.width), cx.stroke.color);
+        for line in pattern.lines(&world) {
+            self.path.clear();
+            self.path.add_line_segment(line.map(|p| cx.to_screen(p)));
+            self.path.stroke_open(cx.feathering, &stroke, mesh);
+        }
+        let (dots, radius) = pattern.dots(&world);
+        for dot in dots {
+            self.path.clear();
+            self.path.add_circle(cx.to_screen(dot), radius * cx.view.scale);
+            self.path.fill(cx.feathering, cx.stroke.color, mesh);
+        }
+    }
+}
+
+impl TilePainter for DefaultPainter {
+    fn paint_tile(&mut self, cx: &PaintContext, tile: &Tile, class: usize, mesh: &mut egui::Mesh) {
+        let transform = tile.transform();
+        let pattern = cx.pattern(class);
+        // Patterned tiles are marked in the stroke colour on the background.
+        let color = if pattern.is_marked() { cx.background } else { cx.colour(class) };
+
+        // Tiles may be concave, so fill from the cached triangulation rather than as a convex path.
+        let base = mesh.vertices.len() as u32;
+        mesh.vertices.extend(cx.outline.iter().map(|&p| egui::epaint::Vertex {
+            pos: cx.to_screen(transform.transform_point2(p)),
+            uv: egui::epaint::WHITE_UV,
+            color,
+        }));
+        mesh.indices.extend(cx.triangles.iter().map(|i| base + i));
+        if pattern.is_marked() {
+            self.paint_pattern(cx, pattern, tile, mesh);
+        }
+
+        // A large inset can leave nothing of the tile.
+        if cx.apart && cx.outline.len() >= 2 {
+            self.outline.clear();
+            self.outline.extend(mesh.vertices[base as usize..base as usize + cx.outline.len()].iter().map(|vertex| vec2(vertex.pos.x, vertex.pos.y)));
+            stroke_outline(&self.outline, cx.stroke, cx.join, &mut self.stroke, mesh);
+        }
+    }
+
+    fn paint_overlay(&mut self, cx: &PaintContext, tiles: &[Tile], mesh: &mut egui::Mesh) {
+        if cx.apart {
+            return;
+        }
+        let stroke = cx.stroke;
+        let path_stroke = stroke.into();
+        for edge in cx.tiling.unique_edges(cx.edges, tiles.iter().map(Tile::transform)) {
+            self.points.clear();
+            self.points.extend(edge.iter().map(|&p| cx.to_screen(p)));
+
+            self.path.clear();
+            self.path.add_open_points(&self.points);
+            self.path.stroke_open(cx.feathering, &path_stroke, mesh);
+
+            // Round off the ends, so the edges meeting at a vertex join up.
+            for end in [self.points.first(), self.points.last()].into_iter().flatten().copied() {
+                self.path.clear();
+                self.path.add_circle(end, stroke.width / 2.);
+                self.path.fill(cx.feathering, stroke.color, mesh);
+            }
+        }
+    }
+}
+
+/// Fills the tiles like [`DefaultPainter`], and instead of their edges draws ribbons that pass
+/// alternately over and under each other, see [`weave`].
+pub struct WeavePainter {
+    /// Width of the ribbons in world units.
+    pub width: f32,
+    /// The angle in radians at which the ribbons cross the edges.
+    pub angle: f32,
+    tiles: DefaultPainter,
+    points: Vec<egui::Pos2>,
+    path: Path,
+}
+
+impl WeavePainter {
+    pub fn new(width: f32, angle: f32) -> Self {
+        Self { width, angle, tiles: DefaultPainter::default(), points: vec![], path: Path::default() }
+    }
+}
+
+/// Points each half ribbon segment is drawn with.
+const RIBBON_SEGMENTS: usize = 12;
+
+impl TilePainter for WeavePainter {
+    fn paint_tile(&mut self, cx: &PaintContext, tile: &Tile, class: usize, mesh: &mut egui::Mesh) {
+        self.tiles.paint_tile(cx, tile, class, mesh);
+    }
+
+    fn paint_overlay(&mut self, cx: &PaintContext, tiles: &[Tile], mesh: &mut egui::Mesh) {
+        let segments = weave::segments(cx.tiling, tiles.iter().map(Tile::transform), self.angle);
+        let halves: Vec<_> = segments.iter().map(weave::Segment::split).collect();
+        let width = self.width * cx.view.scale;
+        let border = egui::epaint::PathStroke::new(width + 2. * cx.stroke.width, cx.stroke.color);
+        let ribbon = egui::epaint::PathStroke::new(width, cx.background);
+
+        // All the halves passing under first, then those passing over on top of them. Within each
+        // pass the borders go down before the ribbons, so the pieces of a ribbon join seamlessly.
+        for pass in [halves.iter().map(|(under, _)| under).collect::<Vec<_>>(), halves.iter().map(|(_, over)| over).collect()] {
+            for stroke in [&border, &ribbon] {
+                for half in &pass {
+                    self.points.clear();
+                    self.points.extend(half.sample(RIBBON_SEGMENTS).into_iter().map(|p| cx.to_screen(p)));
+                    self.path.clear();
+                    self.path.add_open_points(&self.points);
+                    self.path.stroke_open(cx.feathering, stroke, mesh);
+                }
+            }
+        }
+    }
+}
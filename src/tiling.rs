@@ -1,12 +1,17 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
 
+use crate::colouring::{perfect_colourings, Colouring};
 use crate::data::{tiling_type_data, TilingTypeData};
-use crate::iterators::{FillAlgorithm, TilingShapeIterator, TilingShapePartIterator};
-use crate::utils::{fill_affine, fill_vector, r_match, Affine2, Vec2, vec2};
+use crate::edges;
+use crate::error::TilingError;
+use crate::iterators::{FillAlgorithm, Tile, TilingShapeIterator, TilingShapePartIterator};
+use crate::utils::{centroid, contains, fill_affine, fill_vector, r_match, Affine2, Vec2, vec2};
 
 
 /// One of the 93 isohedral tiling types. Can be used to initialise or reset an [`IsohedralTiling`]
 /// instance.
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct TilingType(pub(crate) usize);
 
 impl std::fmt::Display for TilingType {
@@ -28,6 +33,53 @@ pub enum EdgeShape {
     I,
 }
 
+/// A problem with an edge shape that breaks the tiling, see [`IsohedralTiling::check_edges`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EdgeProblem {
+    /// The edge crosses itself, another edge of the tile, or an edge of a neighbouring tile.
+    Overlap,
+    /// A U or S edge lost its symmetry, so it doesn't fit against its neighbour.
+    Asymmetric,
+}
+
+/// An inconsistency in the data of a tiling type, found by [`IsohedralTiling::verify`].
+#[derive(Debug, Copy, Clone, PartialEq, thiserror::Error)]
+pub enum Inconsistency {
+    /// The transform of a tile edge doesn't map the edge frame onto the segment from its vertex to
+    /// the next.
+    #[error("edge {0} doesn't run between vertices {0} and the next")]
+    EdgeEndpoints(usize),
+    /// Tile edges sharing an edge shape differ in length, so the shape can't fit all of them.
+    #[error("the edges of edge shape {0} differ in length")]
+    EdgeLengths(usize),
+    /// An aspect transform distorts the prototile.
+    #[error("aspect {0} is not an isometry")]
+    NonIsometricAspect(usize),
+    /// The translation vectors don't span the plane.
+    #[error("the translation vectors are parallel")]
+    DegenerateLattice,
+    /// The aspects don't cover exactly one translational unit.
+    #[error("the aspects cover an area of {tiles}, but a translational unit has an area of {unit}")]
+    AreaMismatch { tiles: f32, unit: f32 },
+    /// No neighbouring tile has an edge of the same shape in the place of this tile edge, so the
+    /// aspects and translations don't fit together.
+    #[error("edge {0} isn't matched by an edge of the same shape on a neighbouring tile")]
+    UnmatchedEdge(usize),
+    /// The colouring gives an aspect a colour other than the three available.
+    #[error("aspect {aspect} has colour {colour}, but there are only 3 colours")]
+    InvalidColour { aspect: usize, colour: u8 },
+    /// The colour permutation applied per translation along `t1` (`1`) or `t2` (`2`) doesn't
+    /// permute the three colours, or doesn't return to the identity after the colouring's period.
+    #[error("the colour permutation along t{0} is invalid")]
+    InvalidColourPermutation(usize),
+}
+
+/// Distance, in world units, below which edges are considered to touch rather than cross.
+const CROSSING_TOLERANCE: f32 = 1e-4;
+
+/// Source of [`IsohedralTiling::revision`] values, shared by all instances.
+static REVISION: AtomicU64 = AtomicU64::new(0);
+
 /// Represents a particular isohedral tiling type.
 #[derive(Debug, Default)]
 pub struct IsohedralTiling {
@@ -41,11 +93,15 @@ pub struct IsohedralTiling {
     pub(crate) t1: Vec2,
     pub(crate) t2: Vec2,
     pub(crate) ttd: &'static TilingTypeData,
+    pub(crate) colouring: Colouring,
+    pub(crate) revision: u64,
 }
 
 impl IsohedralTiling {
     /// Create a new [`IsohedralTiling`] instance for the given tiling type. You can get a valid
     /// tiling type by using the [`get_tiling_type`] function.
+    ///
+    /// [`get_tiling_type`]: crate::get_tiling_type
     pub fn new(ihtype: TilingType) -> Self {
         let mut tiling = Self::default();
         tiling.reset(ihtype);
@@ -53,20 +109,43 @@ impl IsohedralTiling {
         tiling
     }
 
+    /// Start building a new [`IsohedralTiling`], validating the tiling type and parameters at
+    /// once instead of calling [`new`] followed by [`set_parameters`].
+    ///
+    /// ```
+    /// # use egui_isohedral::{get_tiling_type, tiling::IsohedralTiling};
+    /// let tiling = IsohedralTiling::builder()
+    ///     .tiling_type(get_tiling_type(0))
+    ///     .parameters(&[0.1, 0.2, 0.3, 0.4])
+    ///     .build()?;
+    /// # Ok::<(), egui_isohedral::TilingError>(())
+    /// ```
+    ///
+    /// [`new`]: IsohedralTiling::new
+    /// [`set_parameters`]: IsohedralTiling::set_parameters
+    pub fn builder() -> IsohedralTilingBuilder {
+        IsohedralTilingBuilder::default()
+    }
+
     /// Resets the current instance to describe the new given tiling type, and recomputes the
     /// internal state as necessary.
     pub fn reset(&mut self, ihtype: TilingType) {
+        self.set_type(ihtype);
+        self.recompute();
+    }
+
+    fn set_type(&mut self, ihtype: TilingType) {
         self.tiling_type = ihtype;
-        let ttd = &tiling_type_data[ihtype.0];
+        let ttd = &tiling_type_data()[ihtype.0];
 
         self.num_params = ttd.num_params;
         self.ttd = ttd;
 
         self.parameters[..ttd.num_params].copy_from_slice(ttd.default_params);
-        self.recompute();
+        self.colouring = Colouring::from_data(ttd);
     }
 
-    /// # Accessors
+    // # Accessors
 
     /// The tiling type described by this instance.
     pub fn tiling_type(&self) -> TilingType {
@@ -98,6 +177,18 @@ impl IsohedralTiling {
         self.ttd.edge_shapes[idx]
     }
 
+    /// Fallible version of [`edge_shape`].
+    ///
+    /// [`edge_shape`]: IsohedralTiling::edge_shape
+    pub fn try_edge_shape(&self, idx: usize) -> Result<EdgeShape, TilingError> {
+        let count = self.num_edge_shapes();
+        if idx < count {
+            Ok(self.ttd.edge_shapes[idx])
+        } else {
+            Err(TilingError::EdgeShapeOutOfRange { index: idx, count })
+        }
+    }
+
     /// Returns the vertex specified by `idx`.
     ///
     /// See [`num_vertices`] for the valid range of values for `idx`.
@@ -107,6 +198,18 @@ impl IsohedralTiling {
         &self.vertices[idx]
     }
 
+    /// Fallible version of [`vertex`].
+    ///
+    /// [`vertex`]: IsohedralTiling::vertex
+    pub fn try_vertex(&self, idx: usize) -> Result<&Vec2, TilingError> {
+        let count = self.num_vertices();
+        if idx < count {
+            Ok(&self.vertices[idx])
+        } else {
+            Err(TilingError::VertexOutOfRange { index: idx, count })
+        }
+    }
+
     /// The number of aspects that the current tiling type has.
     pub fn num_aspects(&self) -> usize {
         self.ttd.num_aspects
@@ -121,33 +224,118 @@ impl IsohedralTiling {
         &self.aspects[idx]
     }
 
+    /// Fallible version of [`aspect_transform`].
+    ///
+    /// [`aspect_transform`]: IsohedralTiling::aspect_transform
+    pub fn try_aspect_transform(&self, idx: usize) -> Result<&Affine2, TilingError> {
+        let count = self.num_aspects();
+        if idx < count {
+            Ok(&self.aspects[idx])
+        } else {
+            Err(TilingError::AspectOutOfRange { index: idx, count })
+        }
+    }
+
     /// Computes a colour index used for tiling a region.
     ///
-    /// The return value can be 0, 1, 2, representing one of 3 possible colours. The parameters `t1`,
-    /// `t2`, and `aspect` can be obtained while iterating over the tiles of a region. See
-    /// [`iterators::FillRegionIterator`].
+    /// The return value is one of the [`num_colours`] colours, 0, 1 or 2 with the built-in
+    /// colouring. The parameters `t1`, `t2`, and `aspect` can be obtained while iterating over the
+    /// tiles of a region. See [`Tile::colour`].
+    ///
+    /// [`num_colours`]: IsohedralTiling::num_colours
+    /// [`Tile::colour`]: crate::iterators::Tile::colour
     pub fn colour(&self, t1: isize, t2: isize, aspect: usize) -> usize{
-        let nc = self.ttd.colouring[18] as isize;
+        self.colouring.colour(t1, t2, aspect)
+    }
 
-        let mut mt1 = t1 % nc;
-        if mt1 < 0 {
-            mt1 += nc;
-        }
-        let mut mt2 = t2 % nc;
-        if mt2 < 0 {
-            mt2 += nc;
-        }
-        let mut col = self.ttd.colouring[aspect];
+    /// The number of colours of the current colouring, 3 unless changed with [`set_colouring`].
+    ///
+    /// [`set_colouring`]: IsohedralTiling::set_colouring
+    pub fn num_colours(&self) -> usize {
+        self.colouring.num_colours()
+    }
 
-        for _ in 0..mt1 {
-            col = self.ttd.colouring[12 + col as usize];
-        }
+    /// The current colouring.
+    pub fn colouring(&self) -> &Colouring {
+        &self.colouring
+    }
+
+    /// Colour the tiles with `colours` colours: 3 selects the tiling type's built-in colouring,
+    /// other counts the best of the [`perfect_colourings`] with that many colours. Changing the
+    /// tiling type with [`reset`] goes back to the built-in colouring.
+    ///
+    /// Fails with [`TilingError::NoPerfectColouring`] if the tiling type has no perfect colouring
+    /// with that many colours, leaving the colouring unchanged.
+    ///
+    /// [`perfect_colourings`]: crate::colouring::perfect_colourings
+    /// [`reset`]: IsohedralTiling::reset
+    pub fn set_colouring(&mut self, colours: usize) -> Result<(), TilingError> {
+        self.colouring = match colours {
+            3 => Colouring::from_data(self.ttd),
+            _ => perfect_colourings(self, colours).into_iter().next().ok_or(TilingError::NoPerfectColouring(colours))?,
+        };
+        Ok(())
+    }
+
+    /// The smallest number of translations along [`t1`] and along [`t2`] after which the colours
+    /// repeat. The colouring is periodic on the lattice spanned by `periods.0 * t1` and
+    /// `periods.1 * t2`.
+    ///
+    /// [`t1`]: IsohedralTiling::t1
+    /// [`t2`]: IsohedralTiling::t2
+    pub fn colour_periods(&self) -> (usize, usize) {
+        self.colouring.periods()
+    }
+
+    /// The colour of `aspect` in the tile at the origin of the lattice, before any translation
+    /// permutes it. [`colour`] returns it for `t1 == 0` and `t2 == 0`.
+    ///
+    /// See [`num_aspects`] for the valid range of values for `aspect`.
+    ///
+    /// [`colour`]: IsohedralTiling::colour
+    /// [`num_aspects`]: IsohedralTiling::num_aspects
+    pub fn aspect_base_colour(&self, aspect: usize) -> usize {
+        self.colouring.base_colours()[aspect]
+    }
 
-        for _ in 0..mt2 {
-            col = self.ttd.colouring[15 + col as usize];
+    /// Fallible version of [`aspect_base_colour`].
+    ///
+    /// [`aspect_base_colour`]: IsohedralTiling::aspect_base_colour
+    pub fn try_aspect_base_colour(&self, aspect: usize) -> Result<usize, TilingError> {
+        let count = self.num_aspects();
+        if aspect < count {
+            Ok(self.colouring.base_colours()[aspect])
+        } else {
+            Err(TilingError::AspectOutOfRange { index: aspect, count })
         }
+    }
 
-        col as usize
+    /// How a translation by [`t1`] permutes the colours: a tile of colour `c` is moved onto one of
+    /// colour `permutation[c]`.
+    ///
+    /// [`t1`]: IsohedralTiling::t1
+    pub fn colour_permutation_t1(&self) -> &[usize] {
+        self.colouring.permutation_t1()
+    }
+
+    /// How a translation by [`t2`] permutes the colours, see [`colour_permutation_t1`].
+    ///
+    /// [`t2`]: IsohedralTiling::t2
+    /// [`colour_permutation_t1`]: IsohedralTiling::colour_permutation_t1
+    pub fn colour_permutation_t2(&self) -> &[usize] {
+        self.colouring.permutation_t2()
+    }
+
+    /// A counter that changes every time the internal state is recomputed, i.e. on [`reset`] and
+    /// [`set_parameters`].
+    ///
+    /// Revisions increase monotonically and are unique across all instances, so a cache keyed on
+    /// the revision is also invalidated when the tiling is replaced by a new one.
+    ///
+    /// [`reset`]: IsohedralTiling::reset
+    /// [`set_parameters`]: IsohedralTiling::set_parameters
+    pub fn revision(&self) -> u64 {
+        self.revision
     }
 
     /// The first translation vector.
@@ -160,10 +348,10 @@ impl IsohedralTiling {
         &self.t2
     }
 
-    /// # Iterators
+    // # Iterators
 
     /// Iterate over all the edge shapes of the prototile.
-    pub fn shapes(&self) -> TilingShapeIterator {
+    pub fn shapes(&self) -> TilingShapeIterator<'_> {
         TilingShapeIterator {
             idx: 0,
             tiling: self,
@@ -171,7 +359,7 @@ impl IsohedralTiling {
     }
 
     /// Iterate over all the shape parts of the prototile.
-    pub fn parts(&self) -> TilingShapePartIterator {
+    pub fn parts(&self) -> TilingShapePartIterator<'_> {
         TilingShapePartIterator {
             idx: 0,
             tiling: self,
@@ -181,8 +369,10 @@ impl IsohedralTiling {
 
     /// Helper to fill a region of the plan with tiles.
     ///
-    /// The  returned object can be turned into an iteretor where each element gives you the necessary
-    /// transform to apply to the prototile.
+    /// The returned object can be turned into an iterator where each element gives you the necessary
+    /// transform to apply to the prototile. It implements [`IntoIterator`], so it can be used
+    /// directly in a `for` loop or an iterator chain. The tiles come in a fixed order, see
+    /// [`FillAlgorithm`].
     pub fn fill_region(&self, xmin: f32, ymin: f32, xmax: f32, ymax: f32) -> FillAlgorithm<'_> {
         FillAlgorithm::new(
             self,
@@ -193,6 +383,396 @@ impl IsohedralTiling {
         )
     }
 
+    /// The tile at the given lattice coordinates: the prototile placed by the given aspect, then
+    /// translated `t1` times along the first and `t2` times along the second translation vector.
+    ///
+    /// See [`num_aspects`] for the valid range of values for `aspect`.
+    ///
+    /// [`num_aspects`]: IsohedralTiling::num_aspects
+    pub fn tile(&self, t1: isize, t2: isize, aspect: usize) -> Tile {
+        let mut transform = *self.aspect_transform(aspect);
+        let (x, y) = (t1 as f32, t2 as f32);
+        transform.translation.x += x * self.t1.x + y * self.t2.x;
+        transform.translation.y += x * self.t1.y + y * self.t2.y;
+
+        Tile { t1, t2, aspect, transform }
+    }
+
+    /// Express a point in lattice coordinates, i.e. as a combination `x * t1 + y * t2` of the
+    /// translation vectors. The integer parts locate the translational unit containing the point.
+    pub fn lattice_coords(&self, p: Vec2) -> Vec2 {
+        let (t1, t2) = (self.t1, self.t2);
+        let det = t1.x * t2.y - t2.x * t1.y;
+        vec2((p.x * t2.y - p.y * t2.x) / det, (p.y * t1.x - p.x * t1.y) / det)
+    }
+
+    /// The tile containing the point `p`, given the prototile `outline` (see [`outline`]).
+    ///
+    /// Only the translational units around `p` are searched, so outlines that stray far from the
+    /// prototile's polygon may not be found.
+    ///
+    /// [`outline`]: IsohedralTiling::outline
+    pub fn tile_at(&self, outline: &[Vec2], p: Vec2) -> Option<Tile> {
+        let coords = self.lattice_coords(p);
+        let (t1, t2) = (coords.x.floor() as isize, coords.y.floor() as isize);
+        (t1 - 2..=t1 + 2)
+            .flat_map(|i| (t2 - 2..=t2 + 2).map(move |j| (i, j)))
+            .flat_map(|(i, j)| (0..self.num_aspects()).map(move |aspect| (i, j, aspect)))
+            .map(|(i, j, aspect)| self.tile(i, j, aspect))
+            .find(|tile| contains(outline, tile.transform().inverse().transform_point2(p)))
+    }
+
+    /// The tiles of one translational unit: one tile of each aspect at lattice position `(0, 0)`.
+    ///
+    /// Translating the unit by every combination of [`t1`] and [`t2`] covers the plane.
+    ///
+    /// [`t1`]: IsohedralTiling::t1
+    /// [`t2`]: IsohedralTiling::t2
+    pub fn translational_unit(&self) -> impl Iterator<Item = Tile> + '_ {
+        (0..self.num_aspects()).map(|aspect| self.tile(0, 0, aspect))
+    }
+
+    /// Copy the edge shape `from` over the edge shape `to` in `edges`, projected onto the
+    /// constraint of the target (see [`edges::project`]), so a motif can be reused on every edge.
+    ///
+    /// [`edges::project`]: crate::edges::project
+    pub fn copy_edge_shape(&self, edges: &mut [Vec<Vec2>], from: usize, to: usize) -> Result<(), TilingError> {
+        let count = edges.len().min(self.num_edge_shapes());
+        for index in [from, to] {
+            if index >= count {
+                return Err(TilingError::EdgeShapeOutOfRange { index, count });
+            }
+        }
+        edges[to] = edges::project(&edges[from], self.edge_shape(to));
+        Ok(())
+    }
+
+    /// Check the internal consistency of the tiling type's data at the current parameters: that
+    /// every edge transform runs between consecutive vertices, that the aspects are isometries
+    /// covering one translational unit and meeting edge to edge, and that the colouring is valid.
+    ///
+    /// Returns every inconsistency found, so an empty report means the data is consistent.
+    pub fn verify(&self) -> Vec<Inconsistency> {
+        let mut report = vec![];
+        let n = self.num_vertices();
+        let scale = self.t1.length().max(self.t2.length()).max(f32::EPSILON);
+        let close = |a: Vec2, b: Vec2| (a - b).length() < 1e-3 * scale;
+
+        // Edges and their lengths.
+        let mut lengths: Vec<Option<f32>> = vec![None; self.num_edge_shapes()];
+        for (i, e) in self.shapes().enumerate() {
+            let (a, b) = (self.vertices[i], self.vertices[(i + 1) % n]);
+            let (p, q) = (e.transform().transform_point2(Vec2::ZERO), e.transform().transform_point2(Vec2::X));
+            if !(close(p, a) && close(q, b) || close(p, b) && close(q, a)) {
+                report.push(Inconsistency::EdgeEndpoints(i));
+            }
+            let length = (b - a).length();
+            match lengths[e.id()] {
+                Some(l) if (l - length).abs() > 1e-3 * scale => report.push(Inconsistency::EdgeLengths(e.id())),
+                _ => lengths[e.id()] = Some(length),
+            }
+        }
+        report.dedup();
+
+        // Aspects and the lattice.
+        for (i, aspect) in self.aspects[..self.num_aspects()].iter().enumerate() {
+            let (x, y) = (aspect.matrix2.x_axis, aspect.matrix2.y_axis);
+            if (x.length() - 1.).abs() > 1e-3 || (y.length() - 1.).abs() > 1e-3 || x.dot(y).abs() > 1e-3 {
+                report.push(Inconsistency::NonIsometricAspect(i));
+            }
+        }
+        let unit = self.t1.perp_dot(self.t2).abs();
+        if unit < 1e-6 * scale * scale {
+            report.push(Inconsistency::DegenerateLattice);
+        }
+        let prototile = (0..n).map(|i| self.vertices[i].perp_dot(self.vertices[(i + 1) % n])).sum::<f32>().abs() / 2.;
+        let tiles = prototile * self.num_aspects() as f32;
+        if (tiles - unit).abs() > 1e-3 * unit.max(f32::EPSILON) {
+            report.push(Inconsistency::AreaMismatch { tiles, unit });
+        }
+
+        // Every edge of the central tile must lie on an edge of the same shape of a neighbour.
+        let edges_of = |tile: &Tile| -> Vec<(Vec2, Vec2, usize)> {
+            let t = tile.transform();
+            self.shapes()
+                .enumerate()
+                .map(|(i, e)| (t.transform_point2(self.vertices[i]), t.transform_point2(self.vertices[(i + 1) % n]), e.id()))
+                .collect()
+        };
+        let centre = self.tile(0, 0, 0);
+        let neighbours: Vec<(Vec2, Vec2, usize)> = (-2..=2)
+            .flat_map(|t1| (-2..=2).flat_map(move |t2| (0..self.num_aspects()).map(move |aspect| (t1, t2, aspect))))
+            .filter(|&key| key != (0, 0, 0))
+            .flat_map(|(t1, t2, aspect)| edges_of(&self.tile(t1, t2, aspect)))
+            .collect();
+        for (i, (a, b, id)) in edges_of(&centre).into_iter().enumerate() {
+            let matched = neighbours.iter().any(|&(p, q, other)| other == id && (close(p, b) && close(q, a) || close(p, a) && close(q, b)));
+            if !matched {
+                report.push(Inconsistency::UnmatchedEdge(i));
+            }
+        }
+
+        // The colouring: a colour per aspect, two permutations and the period, see `colour`.
+        let colouring = self.ttd.colouring;
+        if colouring.len() < 19 {
+            report.extend([Inconsistency::InvalidColourPermutation(1), Inconsistency::InvalidColourPermutation(2)]);
+            return report;
+        }
+        for (aspect, &colour) in colouring[..self.num_aspects()].iter().enumerate() {
+            if colour > 2 {
+                report.push(Inconsistency::InvalidColour { aspect, colour });
+            }
+        }
+        let period = colouring[18] as usize;
+        for (axis, offset) in [(1, 12), (2, 15)] {
+            let permutation = &colouring[offset..offset + 3];
+            let mut seen = [false; 3];
+            for &c in permutation.iter().filter(|&&c| c < 3) {
+                seen[c as usize] = true;
+            }
+            let returns = (0..3u8).all(|c| (0..period).try_fold(c, |c, _| permutation.get(c as usize).copied()) == Some(c));
+            if period == 0 || !seen.iter().all(|&s| s) || !returns {
+                report.push(Inconsistency::InvalidColourPermutation(axis));
+            }
+        }
+
+        report
+    }
+
+    /// Find the edge shapes that break the tiling: those crossing the tile itself or its
+    /// neighbours, and U or S edges that are not symmetric. Each edge shape is reported at most
+    /// once per kind of problem.
+    ///
+    /// `edges` is as for [`outline`]; pass the sampled edges (see [`sample_edges`]) to check the
+    /// curves as drawn.
+    ///
+    /// [`outline`]: IsohedralTiling::outline
+    /// [`sample_edges`]: IsohedralTiling::sample_edges
+    ///
+    /// # Panics
+    ///
+    /// If there are fewer edges than edge shapes; see [`validate_edges`].
+    ///
+    /// [`validate_edges`]: IsohedralTiling::validate_edges
+    pub fn check_edges(&self, edges: &[Vec<Vec2>]) -> Vec<(usize, EdgeProblem)> {
+        let mut problems = vec![];
+        for (id, edge) in edges.iter().enumerate().take(self.num_edge_shapes()) {
+            if edges::partner(self.edge_shape(id), Vec2::ZERO).is_some() && !is_symmetric(edge, self.edge_shape(id)) {
+                problems.push((id, EdgeProblem::Asymmetric));
+            }
+        }
+
+        // The segments of a tile, each labelled with its edge shape.
+        let segments = |transform: Affine2| -> Vec<(Vec2, Vec2, usize)> {
+            self.shapes()
+                .flat_map(|e| {
+                    let t = transform * e.transform();
+                    edges[e.id()].windows(2).map(move |w| (t.transform_point2(w[0]), t.transform_point2(w[1]), e.id()))
+                })
+                .collect()
+        };
+        let bounds = |segments: &[(Vec2, Vec2, usize)]| {
+            segments.iter().fold((vec2(f32::MAX, f32::MAX), vec2(f32::MIN, f32::MIN)), |(lo, hi), &(a, b, _)| {
+                (vec2(lo.x.min(a.x).min(b.x), lo.y.min(a.y).min(b.y)), vec2(hi.x.max(a.x).max(b.x), hi.y.max(a.y).max(b.y)))
+            })
+        };
+
+        let centre = self.tile(0, 0, 0);
+        let own = segments(centre.transform());
+        let mut overlapping = vec![false; edges.len()];
+        for (i, a) in own.iter().enumerate() {
+            for b in &own[i + 1..] {
+                if crosses(a.0, a.1, b.0, b.1) {
+                    overlapping[a.2] = true;
+                    overlapping[b.2] = true;
+                }
+            }
+        }
+
+        let (lo, hi) = bounds(&own);
+        let margin = self.t1.length() + self.t2.length();
+        for tile in self.fill_region(lo.x - margin, lo.y - margin, hi.x + margin, hi.y + margin) {
+            if (tile.t1, tile.t2, tile.aspect) == (centre.t1, centre.t2, centre.aspect) {
+                continue;
+            }
+            let other = segments(tile.transform());
+            let (other_lo, other_hi) = bounds(&other);
+            if other_lo.x > hi.x || other_hi.x < lo.x || other_lo.y > hi.y || other_hi.y < lo.y {
+                continue;
+            }
+            for a in &own {
+                for b in &other {
+                    if crosses(a.0, a.1, b.0, b.1) {
+                        overlapping[a.2] = true;
+                        overlapping[b.2] = true;
+                    }
+                }
+            }
+        }
+
+        problems.extend((0..edges.len()).filter(|&id| overlapping[id]).map(|id| (id, EdgeProblem::Overlap)));
+        problems
+    }
+
+    /// Build the closed outline of the prototile.
+    ///
+    /// `edges` holds one point sequence per edge shape (see [`num_edge_shapes`]), each running from
+    /// `(0, 0)` to `(1, 0)` in the edge's own frame. Reversed edges are walked backwards, so the
+    /// outline visits the vertices in order with every edge's interior points in between. The
+    /// closing vertex is not repeated.
+    ///
+    /// # Panics
+    ///
+    /// If there are fewer edges than edge shapes; see [`validate_edges`].
+    ///
+    /// [`num_edge_shapes`]: IsohedralTiling::num_edge_shapes
+    /// [`validate_edges`]: IsohedralTiling::validate_edges
+    pub fn outline(&self, edges: &[Vec<Vec2>]) -> Vec<Vec2> {
+        let mut points = vec![];
+
+        for e in self.shapes() {
+            let t = e.transform();
+            let edge = edges[e.id()].iter().map(|&p| t.transform_point2(p));
+            // Each edge starts where the previous one ended.
+            let skip = usize::from(!points.is_empty());
+
+            if e.reversed() {
+                points.extend(edge.rev().skip(skip));
+            } else {
+                points.extend(edge.skip(skip));
+            }
+        }
+        points.pop();
+
+        points
+    }
+
+    /// Fallible version of [`outline`], for edges from untrusted input.
+    ///
+    /// [`outline`]: IsohedralTiling::outline
+    pub fn try_outline(&self, edges: &[Vec<Vec2>]) -> Result<Vec<Vec2>, TilingError> {
+        self.validate_edges(edges)?;
+        Ok(self.outline(edges))
+    }
+
+    /// Check that `edges` has one point sequence per edge shape, as [`outline`],
+    /// [`sample_edges`], [`check_edges`] and [`unique_edges`] expect, and that every point is
+    /// finite.
+    ///
+    /// [`outline`]: IsohedralTiling::outline
+    /// [`sample_edges`]: IsohedralTiling::sample_edges
+    /// [`check_edges`]: IsohedralTiling::check_edges
+    /// [`unique_edges`]: IsohedralTiling::unique_edges
+    pub fn validate_edges(&self, edges: &[Vec<Vec2>]) -> Result<(), TilingError> {
+        if edges.len() != self.num_edge_shapes() {
+            return Err(TilingError::WrongEdgeCount { expected: self.num_edge_shapes(), got: edges.len() });
+        }
+        match edges.iter().position(|edge| !edge.iter().all(|p| p.x.is_finite() && p.y.is_finite())) {
+            Some(idx) => Err(TilingError::NonFiniteEdgePoint(idx)),
+            None => Ok(()),
+        }
+    }
+
+    /// Sample every edge shape into a point sequence with about `segments` segments per edge.
+    ///
+    /// `I` edges are necessarily straight and are returned unchanged. See [`edges::sample`].
+    ///
+    /// # Panics
+    ///
+    /// If there are more edges than edge shapes; see [`validate_edges`].
+    ///
+    /// [`validate_edges`]: IsohedralTiling::validate_edges
+    pub fn sample_edges(&self, edges: &[Vec<Vec2>], segments: usize) -> Vec<Vec<Vec2>> {
+        edges
+            .iter()
+            .enumerate()
+            .map(|(idx, edge)| match self.edge_shape(idx) {
+                EdgeShape::I => edge.clone(),
+                _ => edges::sample(edge, segments),
+            })
+            .collect()
+    }
+
+    /// Fallible version of [`sample_edges`], for edges from untrusted input.
+    ///
+    /// [`sample_edges`]: IsohedralTiling::sample_edges
+    pub fn try_sample_edges(&self, edges: &[Vec<Vec2>], segments: usize) -> Result<Vec<Vec<Vec2>>, TilingError> {
+        self.validate_edges(edges)?;
+        Ok(self.sample_edges(edges, segments))
+    }
+
+    /// Every edge of the given tiles exactly once, as a point sequence in world space.
+    ///
+    /// `edges` holds one sampled point sequence per edge shape, as for [`outline`], and `tiles`
+    /// the tile transforms. Neighbouring tiles share their edges, so stroking each tile's outline
+    /// draws every interior edge twice; stroking these instead draws it once, which keeps
+    /// translucent and dashed strokes even. An edge shared by two of the tiles is kept in the
+    /// direction of the first tile that has it.
+    ///
+    /// # Panics
+    ///
+    /// If there are fewer edges than edge shapes; see [`validate_edges`].
+    ///
+    /// [`outline`]: IsohedralTiling::outline
+    /// [`validate_edges`]: IsohedralTiling::validate_edges
+    pub fn unique_edges(&self, edges: &[Vec<Vec2>], tiles: impl IntoIterator<Item = Affine2>) -> Vec<Vec<Vec2>> {
+        // Shared edges match up to rounding, so they are compared by their ends and their middle
+        // quantised to a small fraction of the lattice.
+        let quantum = 1e-4 * (self.t1.length() + self.t2.length());
+        let key = |p: Vec2| ((p.x / quantum).round() as i64, (p.y / quantum).round() as i64);
+        let mut seen = HashSet::new();
+        let mut unique = vec![];
+
+        for transform in tiles {
+            for e in self.shapes() {
+                let t = transform * e.transform();
+                let points: Vec<Vec2> = edges[e.id()].iter().map(|&p| t.transform_point2(p)).collect();
+                let n = points.len();
+                if n < 2 {
+                    continue;
+                }
+                let (a, b) = (key(points[0]), key(points[n - 1]));
+                let middle = (points[(n - 1) / 2] + points[n / 2]) * 0.5;
+                if seen.insert((a.min(b), a.max(b), key(middle))) {
+                    unique.push(points);
+                }
+            }
+        }
+
+        unique
+    }
+
+    /// The edges of the dual tiling of the tiles with the given transforms: a segment between the
+    /// centres of every two of them that share an edge of the prototile.
+    ///
+    /// A tile's centre is the centroid of its polygon, so the segments cross the shared edges
+    /// whatever their shapes. Edges on the border of the tiles, with no neighbour among them, have
+    /// no dual edge.
+    pub fn dual_edges(&self, tiles: impl IntoIterator<Item = Affine2>) -> Vec<[Vec2; 2]> {
+        // Shared edges are matched by their midpoints, quantised as in `unique_edges`.
+        let quantum = 1e-4 * (self.t1.length() + self.t2.length());
+        let key = |p: Vec2| ((p.x / quantum).round() as i64, (p.y / quantum).round() as i64);
+        let vertices = self.vertices();
+        let centre = centroid(vertices);
+        let n = vertices.len();
+        let mut open = HashMap::new();
+        let mut dual = vec![];
+
+        for transform in tiles {
+            let c = transform.transform_point2(centre);
+            for i in 0..n {
+                let middle = transform.transform_point2((vertices[i] + vertices[(i + 1) % n]) * 0.5);
+                if let Some(other) = open.remove(&key(middle)) {
+                    dual.push([other, c]);
+                } else {
+                    open.insert(key(middle), c);
+                }
+            }
+        }
+
+        dual
+    }
+
     /// Return all the vertex parameters.
     ///
     /// Note: not all tiling types have the same number of parameters. Only the first `n` values of the
@@ -203,12 +783,29 @@ impl IsohedralTiling {
 
     /// Set the vertex parameters.
     ///
+    /// Parameters that aren't finite make the vertices NaN; [`try_set_parameters`] refuses them.
+    ///
     /// See also: [`#parameters`]
+    ///
+    /// [`try_set_parameters`]: IsohedralTiling::try_set_parameters
     pub fn set_parameters(&mut self, params: &[f32; 6]) {
         self.parameters.copy_from_slice(params);
         self.recompute();
     }
 
+    /// Fallible version of [`set_parameters`], for parameters from untrusted input: the tiling is
+    /// left unchanged if one of its [`num_params`] parameters is not finite.
+    ///
+    /// [`set_parameters`]: IsohedralTiling::set_parameters
+    /// [`num_params`]: IsohedralTiling::num_params
+    pub fn try_set_parameters(&mut self, params: &[f32; 6]) -> Result<(), TilingError> {
+        if let Some(idx) = params[..self.num_params].iter().position(|p| !p.is_finite()) {
+            return Err(TilingError::NonFiniteParameter(idx));
+        }
+        self.set_parameters(params);
+        Ok(())
+    }
+
     /// Return the vertices for this prototile.
     ///
     /// See also: [`#parameters`]
@@ -217,6 +814,7 @@ impl IsohedralTiling {
     }
 
     fn recompute(&mut self) {
+        self.revision = REVISION.fetch_add(1, Ordering::Relaxed) + 1;
         let ntv = self.ttd.num_vertices;
 
         // Recompute tiling vertex locations
@@ -264,3 +862,80 @@ impl IsohedralTiling {
         );
     }
 }
+
+/// Builder for an [`IsohedralTiling`], see [`IsohedralTiling::builder`].
+#[derive(Debug, Default, Clone)]
+pub struct IsohedralTilingBuilder {
+    tiling_type: Option<TilingType>,
+    parameters: Option<Vec<f32>>,
+}
+
+impl IsohedralTilingBuilder {
+    /// The tiling type to build. This is required.
+    pub fn tiling_type(mut self, ihtype: TilingType) -> Self {
+        self.tiling_type = Some(ihtype);
+        self
+    }
+
+    /// The vertex parameters. There must be exactly [`num_params`] of them for the chosen tiling
+    /// type. When omitted, the default parameters of the tiling type are used.
+    ///
+    /// [`num_params`]: IsohedralTiling::num_params
+    pub fn parameters(mut self, params: &[f32]) -> Self {
+        self.parameters = Some(params.to_vec());
+        self
+    }
+
+    /// Validate the inputs and compute the tiling.
+    pub fn build(self) -> Result<IsohedralTiling, TilingError> {
+        let ihtype = self.tiling_type.ok_or(TilingError::MissingTilingType)?;
+        let mut tiling = IsohedralTiling::default();
+        tiling.set_type(ihtype);
+
+        if let Some(params) = self.parameters {
+            if params.len() != tiling.num_params {
+                return Err(TilingError::WrongParameterCount {
+                    expected: tiling.num_params,
+                    got: params.len(),
+                });
+            }
+            if let Some(idx) = params.iter().position(|p| !p.is_finite()) {
+                return Err(TilingError::NonFiniteParameter(idx));
+            }
+            tiling.parameters[..params.len()].copy_from_slice(&params);
+        }
+        tiling.recompute();
+
+        Ok(tiling)
+    }
+}
+
+/// Whether the segments `a`–`b` and `c`–`d` cross at a point inside both, further than
+/// [`CROSSING_TOLERANCE`] from either segment's endpoints' lines. Shared and touching segments
+/// don't cross.
+fn crosses(a: Vec2, b: Vec2, c: Vec2, d: Vec2) -> bool {
+    let (ab, cd) = (b - a, d - c);
+    let (len_ab, len_cd) = (ab.length(), cd.length());
+    if len_ab <= 0. || len_cd <= 0. {
+        return false;
+    }
+    // Signed distances of each segment's endpoints from the other segment's line.
+    let (da, db) = (cd.perp_dot(a - c) / len_cd, cd.perp_dot(b - c) / len_cd);
+    let (dc, dd) = (ab.perp_dot(c - a) / len_ab, ab.perp_dot(d - a) / len_ab);
+    let opposite = |p: f32, q: f32| (p > CROSSING_TOLERANCE && q < -CROSSING_TOLERANCE) || (p < -CROSSING_TOLERANCE && q > CROSSING_TOLERANCE);
+    opposite(da, db) && opposite(dc, dd)
+}
+
+/// Whether the partner of every point of `edge` lies on the edge again.
+fn is_symmetric(edge: &[Vec2], shape: EdgeShape) -> bool {
+    let distance = |p: Vec2| {
+        edge.windows(2)
+            .map(|w| {
+                let d = w[1] - w[0];
+                let t = ((p - w[0]).dot(d) / d.dot(d).max(f32::EPSILON)).clamp(0., 1.);
+                (w[0] + d * t - p).length()
+            })
+            .fold(f32::MAX, f32::min)
+    };
+    edge.iter().all(|&p| edges::partner(shape, p).is_none_or(|q| distance(q) < 1e-3))
+}
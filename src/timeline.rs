@@ -0,0 +1,286 @@
+//! A timeline window for animating the parameters, colours and view through keyframes.
+//!
+//! A keyframe holds the parameters, the colours (the background, then the colour classes) and the
+//! pan and zoom of the view at its time. Between two keyframes every value moves from the first
+//! to the second along the easing curve of the first. The scrub bar shows the keyframes and the
+//! playhead: click or drag it to move to a time, click a keyframe to select it.
+use eframe::egui;
+
+use crate::app::App;
+use crate::interface;
+
+
+/// The curve a value follows from one keyframe to the next.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Easing {
+    Linear,
+    /// Starting slowly.
+    In,
+    /// Stopping slowly.
+    Out,
+    #[default]
+    InOut,
+    /// Holding the keyframe's values until the next keyframe.
+    Step,
+}
+
+impl Easing {
+    pub const ALL: [Self; 5] = [Self::Linear, Self::In, Self::Out, Self::InOut, Self::Step];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Linear => "linear",
+            Self::In => "ease in",
+            Self::Out => "ease out",
+            Self::InOut => "ease in and out",
+            Self::Step => "step",
+        }
+    }
+
+    /// How far the values have come at `t`, both from `0` to `1`.
+    pub fn apply(self, t: f32) -> f32 {
+        match self {
+            Self::Linear => t,
+            Self::In => t * t,
+            Self::Out => t * (2. - t),
+            Self::InOut => t * t * (3. - 2. * t),
+            Self::Step => 0.,
+        }
+    }
+}
+
+/// The animated values at one time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframe {
+    /// Seconds from the start of the timeline.
+    pub time: f32,
+    pub params: [f32; 6],
+    /// The background, then the colour classes.
+    pub colours: Vec<egui::Color32>,
+    pub pan: egui::Vec2,
+    pub scale: f32,
+    /// The curve towards the next keyframe.
+    pub easing: Easing,
+}
+
+impl Keyframe {
+    /// The app's values, as a keyframe at `time`.
+    fn capture(app: &App, time: f32) -> Self {
+        let classes = interface::tile_colours(app);
+        let colours = std::iter::once(interface::canvas_background(app)).chain(classes.into_iter().take(app.tiling.num_colours().max(1))).collect();
+        Self { time, params: app.params, colours, pan: app.view.pan, scale: app.view.scale, easing: Easing::default() }
+    }
+
+    /// The values a fraction `t` of the way to `next`.
+    fn towards(&self, next: &Self, t: f32) -> Self {
+        let t = self.easing.apply(t);
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        let count = self.colours.len().max(next.colours.len());
+        Self {
+            time: mix(self.time, next.time),
+            params: std::array::from_fn(|i| mix(self.params[i], next.params[i])),
+            colours: (0..count).map(|i| self.colours[i % self.colours.len()].lerp_to_gamma(next.colours[i % next.colours.len()], t)).collect(),
+            pan: self.pan + (next.pan - self.pan) * t,
+            // Zooming at a steady rate looks even, so the scale is interpolated geometrically.
+            scale: self.scale * (next.scale / self.scale).powf(t),
+            easing: self.easing,
+        }
+    }
+}
+
+/// State of the timeline window.
+pub struct Timeline {
+    pub open: bool,
+    /// Sorted by time.
+    keyframes: Vec<Keyframe>,
+    selected: Option<usize>,
+    /// The playhead, in seconds.
+    time: f32,
+    /// Length of the timeline in seconds.
+    length: f32,
+    playing: bool,
+    looping: bool,
+    /// Time of the last frame played, in seconds of the app's clock.
+    played: Option<f64>,
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self { open: false, keyframes: vec![], selected: None, time: 0., length: 10., playing: false, looping: true, played: None }
+    }
+}
+
+impl Timeline {
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// The values at `time`: those of the keyframes around it, mixed, or of the nearest keyframe
+    /// before the first or after the last.
+    fn at(&self, time: f32) -> Option<Keyframe> {
+        let next = self.keyframes.iter().position(|key| key.time > time);
+        match next {
+            None => self.keyframes.last().cloned(),
+            Some(0) => self.keyframes.first().cloned(),
+            Some(i) => {
+                let (a, b) = (&self.keyframes[i - 1], &self.keyframes[i]);
+                Some(a.towards(b, (time - a.time) / (b.time - a.time).max(f32::EPSILON)))
+            }
+        }
+    }
+
+    /// Add a keyframe, replacing any at the same time.
+    fn insert(&mut self, key: Keyframe) {
+        self.keyframes.retain(|other| other.time != key.time);
+        let i = self.keyframes.partition_point(|other| other.time < key.time);
+        self.keyframes.insert(i, key);
+        self.selected = Some(i);
+    }
+}
+
+/// Show the app at the playhead.
+fn apply(app: &mut App) {
+    let Some(key) = app.timeline.at(app.timeline.time) else {
+        return;
+    };
+    let count = app.tiling.num_params();
+    app.params[..count].copy_from_slice(&key.params[..count]);
+    app.tiling.set_parameters(&app.params);
+    app.image_colours = Some(key.colours);
+    app.view.pan = key.pan;
+    app.view.scale = key.scale;
+}
+
+/// Move the playhead on while the timeline plays, or stop at its end.
+pub fn advance(app: &mut App, ctx: &egui::Context) {
+    let timeline = &mut app.timeline;
+    if !timeline.playing {
+        timeline.played = None;
+        return;
+    }
+    let now = ctx.input(|i| i.time);
+    timeline.time += (now - timeline.played.unwrap_or(now)) as f32;
+    timeline.played = Some(now);
+    if timeline.time > timeline.length {
+        if timeline.looping {
+            timeline.time %= timeline.length.max(f32::EPSILON);
+        } else {
+            timeline.time = timeline.length;
+            timeline.playing = false;
+        }
+    }
+    apply(app);
+    ctx.request_repaint();
+}
+
+/// Show the timeline window, if it is open.
+pub fn show(app: &mut App, ctx: &egui::Context) {
+    let mut open = app.timeline.open;
+    egui::Window::new("Timeline").open(&mut open).default_width(520.).show(ctx, |ui| {
+        controls(app, ui);
+        if scrub_bar(&mut app.timeline, ui) {
+            apply(app);
+        }
+        keyframe_list(app, ui);
+    });
+    app.timeline.open = open;
+}
+
+fn controls(app: &mut App, ui: &mut egui::Ui) {
+    ui.horizontal(|ui| {
+        let timeline = &mut app.timeline;
+        let play = if timeline.playing { "⏸ Pause" } else { "▶ Play" };
+        if ui.add_enabled(!timeline.keyframes.is_empty(), egui::Button::new(play)).clicked() {
+            timeline.playing = !timeline.playing;
+            if timeline.playing && timeline.time >= timeline.length {
+                timeline.time = 0.;
+            }
+        }
+        ui.checkbox(&mut timeline.looping, "loop");
+        ui.add(egui::DragValue::new(&mut timeline.length).range(0.5..=600.).speed(0.1).prefix("length ").suffix(" s"));
+        timeline.time = timeline.time.min(timeline.length);
+        ui.label(format!("{:.2} s", timeline.time));
+        if ui.button("Add keyframe").on_hover_text("Keep the parameters, colours and view at the playhead").clicked() {
+            let key = Keyframe::capture(app, app.timeline.time);
+            app.timeline.insert(key);
+        }
+    });
+}
+
+/// Height of the scrub bar in points.
+const SCRUB_HEIGHT: f32 = 28.;
+
+/// Draw the scrub bar, moving the playhead and selecting keyframes with the pointer. Returns
+/// whether the playhead was moved.
+fn scrub_bar(timeline: &mut Timeline, ui: &mut egui::Ui) -> bool {
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(ui.available_width(), SCRUB_HEIGHT), egui::Sense::click_and_drag());
+    let x = |time: f32| egui::lerp(rect.x_range(), time / timeline.length.max(f32::EPSILON));
+    let visuals = ui.visuals();
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 4., visuals.extreme_bg_color);
+    for (i, key) in timeline.keyframes.iter().enumerate() {
+        let centre = egui::pos2(x(key.time), rect.center().y);
+        let r = SCRUB_HEIGHT / 4.;
+        let diamond = vec![centre - egui::vec2(0., r), centre + egui::vec2(r, 0.), centre + egui::vec2(0., r), centre - egui::vec2(r, 0.)];
+        let fill = if timeline.selected == Some(i) { visuals.selection.bg_fill } else { visuals.widgets.inactive.fg_stroke.color };
+        painter.add(egui::Shape::convex_polygon(diamond, fill, egui::Stroke::NONE));
+    }
+    let playhead = x(timeline.time);
+    painter.line_segment([egui::pos2(playhead, rect.top()), egui::pos2(playhead, rect.bottom())], egui::Stroke::new(2., visuals.warn_fg_color));
+
+    let Some(pos) = response.interact_pointer_pos() else {
+        return false;
+    };
+    let near = timeline.keyframes.iter().position(|key| (x(key.time) - pos.x).abs() <= SCRUB_HEIGHT / 4.);
+    if response.clicked() && near.is_some() {
+        timeline.selected = near;
+        timeline.time = timeline.keyframes[near.unwrap_or_default()].time;
+    } else {
+        timeline.time = ((pos.x - rect.left()) / rect.width() * timeline.length).clamp(0., timeline.length);
+    }
+    timeline.playing = false;
+    true
+}
+
+/// The keyframes, with the time and easing of each and buttons to go to or remove it.
+fn keyframe_list(app: &mut App, ui: &mut egui::Ui) {
+    let timeline = &mut app.timeline;
+    let mut remove = None;
+    let mut moved = false;
+    let mut go_to = None;
+    for (i, key) in timeline.keyframes.iter_mut().enumerate() {
+        ui.horizontal(|ui| {
+            if ui.selectable_label(timeline.selected == Some(i), format!("key {}", i + 1)).clicked() {
+                timeline.selected = Some(i);
+                go_to = Some(key.time);
+            }
+            moved |= ui.add(egui::DragValue::new(&mut key.time).range(0.0..=timeline.length).speed(0.05).suffix(" s")).changed();
+            egui::ComboBox::from_id_salt(("easing", i)).selected_text(key.easing.name()).show_ui(ui, |ui| {
+                for easing in Easing::ALL {
+                    ui.selectable_value(&mut key.easing, easing, easing.name());
+                }
+            });
+            if ui.button("Remove").clicked() {
+                remove = Some(i);
+            }
+        });
+    }
+    if let Some(i) = remove {
+        timeline.keyframes.remove(i);
+        timeline.selected = None;
+    }
+    if moved {
+        let selected = timeline.selected.map(|i| timeline.keyframes[i].clone());
+        timeline.keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+        timeline.selected = selected.and_then(|key| timeline.keyframes.iter().position(|other| *other == key));
+    }
+    if let Some(time) = go_to {
+        timeline.time = time;
+        timeline.playing = false;
+        apply(app);
+    }
+    if app.timeline.keyframes.is_empty() {
+        ui.weak("Move the playhead and add keyframes to animate the tiling.");
+    }
+}
+
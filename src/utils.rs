@@ -1,8 +1,8 @@
 use core::ops::Mul;
-use std::ops::Add;
+use std::ops::{Add, Sub};
 
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct Vec2 {
     pub x: f32,
     pub y: f32,
@@ -26,6 +26,16 @@ impl Vec2 {
     pub const fn splat(v: f32) -> Self {
         Self { x: v, y: v }
     }
+    pub fn length(self) -> f32 {
+        self.x.hypot(self.y)
+    }
+    pub fn dot(self, rhs: Self) -> f32 {
+        self.x * rhs.x + self.y * rhs.y
+    }
+    /// The z component of the cross product of `self` and `rhs`.
+    pub fn perp_dot(self, rhs: Self) -> f32 {
+        self.x * rhs.y - self.y * rhs.x
+    }
     pub const ZERO: Self = Self::splat(0.0);
     pub const X: Self = Self::new(1.0, 0.0);
     pub const Y: Self = Self::new(0.0, 1.0);
@@ -42,6 +52,28 @@ impl Add<Vec2> for Vec2 {
     }
 }
 
+impl Sub<Vec2> for Vec2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.sub(rhs.x),
+            y: self.y.sub(rhs.y),
+        }
+    }
+}
+
+impl Mul<f32> for Vec2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        Self {
+            x: self.x.mul(rhs),
+            y: self.y.mul(rhs),
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Mat2 {
     pub x_axis: Vec2,
@@ -100,6 +132,27 @@ impl Affine2 {
     pub fn transform_point2(&self, rhs: Vec2) -> Vec2 {
         self.matrix2 * rhs + self.translation
     }
+    /// Transform `points` in place, as [`transform_point2`] would one by one.
+    ///
+    /// This is deliberately the plain loop: the compiler already vectorises it, and a
+    /// structure-of-arrays version working on chunks of four measured slower.
+    ///
+    /// [`transform_point2`]: Affine2::transform_point2
+    pub fn transform_points(&self, points: &mut [Vec2]) {
+        for p in points {
+            *p = self.transform_point2(*p);
+        }
+    }
+    /// The inverse transform. The matrix must be invertible, which holds for all tile transforms.
+    pub fn inverse(&self) -> Self {
+        let (x, y) = (self.matrix2.x_axis, self.matrix2.y_axis);
+        let det = x.perp_dot(y);
+        let matrix2 = Mat2::from_cols(Vec2::new(y.y / det, -x.y / det), Vec2::new(-y.x / det, x.x / det));
+        Self {
+            matrix2,
+            translation: Vec2::ZERO - matrix2 * self.translation,
+        }
+    }
 }
 
 impl Mul<Mat2> for Mat2 {
@@ -146,6 +199,97 @@ pub(crate) fn r_match(p: &Vec2, q: &Vec2) -> Affine2 {
     Affine2::from_cols_array(&[q.x - p.x, q.y - p.y, p.y - q.y, q.x - p.x,p.x, p.y])
 }
 
+/// Triangulate a simple polygon by ear clipping, returning indices into `points`.
+///
+/// Works for concave polygons of either winding. Collinear and repeated points are skipped; if no
+/// ear can be found (a self-intersecting outline) the remaining points are fanned.
+pub fn triangulate(points: &[Vec2]) -> Vec<u32> {
+    let mut indices = vec![];
+    let mut v: Vec<usize> = (0..points.len()).collect();
+    if v.len() < 3 {
+        return indices;
+    }
+
+    let area: f32 = (0..v.len()).map(|i| points[i].perp_dot(points[(i + 1) % v.len()])).sum();
+    let sign = if area < 0. { -1. } else { 1. };
+    let corner = |a: usize, b: usize, c: usize| (points[b] - points[a]).perp_dot(points[c] - points[b]) * sign;
+
+    let mut i = 0;
+    let mut misses = 0;
+    while v.len() > 3 && misses < v.len() {
+        let m = v.len();
+        let (a, b, c) = (v[(i + m - 1) % m], v[i % m], v[(i + 1) % m]);
+        let turn = corner(a, b, c);
+
+        if turn.abs() <= f32::EPSILON {
+            v.remove(i % m);
+            misses = 0;
+            continue;
+        }
+        let is_ear = turn > 0. && v.iter().all(|&p| {
+            let q = points[p];
+            [a, b, c].iter().any(|&k| points[k] == q)
+                || corner(a, b, p) < 0. || corner(b, c, p) < 0. || corner(c, a, p) < 0.
+        });
+        if is_ear {
+            indices.extend([a as u32, b as u32, c as u32]);
+            v.remove(i % m);
+            misses = 0;
+        } else {
+            i += 1;
+            misses += 1;
+        }
+    }
+
+    for k in 1..v.len() - 1 {
+        indices.extend([v[0] as u32, v[k] as u32, v[k + 1] as u32]);
+    }
+
+    indices
+}
+
+/// Whether `p` lies inside the polygon, by the even-odd rule.
+pub fn contains(polygon: &[Vec2], p: Vec2) -> bool {
+    let n = polygon.len();
+    let mut inside = false;
+    for i in 0..n {
+        let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+        if (a.y > p.y) != (b.y > p.y) && p.x < a.x + (p.y - a.y) / (b.y - a.y) * (b.x - a.x) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// The centroid of the area enclosed by a simple polygon, of either winding.
+///
+/// Falls back to the average of the points when the polygon has no area.
+pub fn centroid(points: &[Vec2]) -> Vec2 {
+    let n = points.len();
+    let (mut area, mut sum) = (0., Vec2::ZERO);
+    for i in 0..n {
+        let (p, q) = (points[i], points[(i + 1) % n]);
+        let cross = p.perp_dot(q);
+        area += cross;
+        sum = sum + (p + q) * cross;
+    }
+    if area.abs() <= f32::EPSILON {
+        let total = points.iter().fold(Vec2::ZERO, |total, &p| total + p);
+        return total * (1. / n.max(1) as f32);
+    }
+    sum * (1. / (3. * area))
+}
+
+/// The part of the polygon inside the rectangle from `min` to `max`, empty if none is.
+///
+/// The polygon may be concave: where it leaves the rectangle and comes back, the pieces inside
+/// stay joined by edges running along the border, which fill and stroke like the border itself.
+pub fn clip_to_rect(polygon: &[Vec2], min: Vec2, max: Vec2) -> Vec<Vec2> {
+    let corners = [min, vec2(max.x, min.y), max, vec2(min.x, max.y)];
+    let clipped = (0..4).fold(polygon.to_vec(), |piece, i| crate::mask::clip_half_plane(&piece, corners[i], corners[(i + 1) % 4]));
+    if clipped.len() >= 3 { clipped } else { vec![] }
+}
+
 pub(crate) const M_ORIENTS: [Affine2; 4] = [
     Affine2::from_cols_array(&[1.0, 0.0, 0.0, 1.0, 0.0, 0.0]), // IDENTITY
     Affine2::from_cols_array(&[-1.0, 0.0, 0.0, -1.0, 1.0, 0.0]), // ROT
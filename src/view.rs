@@ -0,0 +1,222 @@
+//! An [egui](https://docs.rs/egui) widget showing a tiling, which can be panned by dragging and
+//! zoomed with the scroll wheel or a pinch.
+//!
+//! The pan and zoom are kept in a [`ViewState`] owned by the caller, which also tells which tile
+//! is under the pointer:
+//!
+//! ```
+//! use egui_isohedral::{get_tiling_type, tiling::IsohedralTiling, view::{TilingView, ViewState}, vec2};
+//!
+//! struct Demo {
+//!     tiling: IsohedralTiling,
+//!     edges: Vec<Vec<egui_isohedral::Vec2>>,
+//!     view: ViewState,
+//! }
+//!
+//! impl Demo {
+//!     fn new() -> Self {
+//!         let tiling = IsohedralTiling::new(get_tiling_type(0));
+//!         let edges = vec![vec![vec2(0., 0.), vec2(1., 0.)]; tiling.num_edge_shapes()];
+//!         Self { tiling, edges, view: ViewState::default() }
+//!     }
+//!
+//!     fn ui(&mut self, ui: &mut egui::Ui) {
+//!         ui.add(TilingView::new(&self.tiling, &self.edges, &mut self.view));
+//!         if let Some(tile) = self.view.hovered {
+//!             ui.label(format!("tile {}, {}", tile.t1(), tile.t2()));
+//!         }
+//!     }
+//! }
+//! ```
+use crate::{edges::SCREEN_SEGMENTS, iterators::Tile, tiling::IsohedralTiling, triangulate, Vec2};
+
+
+/// Screen points per world unit of a new view.
+pub const DEFAULT_SCALE: f32 = 100.;
+
+/// The range the scale can be zoomed in, in screen points per world unit.
+pub const SCALE_RANGE: std::ops::RangeInclusive<f32> = 10.0..=1000.0;
+
+/// Colours of the colour classes, unless [`TilingView::colours`] sets others.
+pub const DEFAULT_COLOURS: [egui::Color32; 4] = [
+    egui::Color32::from_rgb(0xe6, 0x9f, 0x00),
+    egui::Color32::from_rgb(0x56, 0xb4, 0xe9),
+    egui::Color32::from_rgb(0x00, 0x9e, 0x73),
+    egui::Color32::from_rgb(0xf0, 0xe4, 0x42),
+];
+
+/// The side of a square with the area of one tile, in world units.
+pub fn tile_size(tiling: &IsohedralTiling) -> f32 {
+    (tiling.t1().perp_dot(*tiling.t2()).abs() / tiling.num_aspects().max(1) as f32).sqrt()
+}
+
+/// Pan and zoom of a view of a tiling, mapping world space to the screen.
+#[derive(Debug, Clone, Copy)]
+pub struct ViewState {
+    /// Screen position of the world origin.
+    pub pan: egui::Vec2,
+    /// Screen points per world unit.
+    pub scale: f32,
+    /// The tile under the pointer, as of the last frame a [`TilingView`] was shown.
+    pub hovered: Option<Tile>,
+}
+
+impl Default for ViewState {
+    fn default() -> Self {
+        Self { pan: egui::Vec2::ZERO, scale: DEFAULT_SCALE, hovered: None }
+    }
+}
+
+impl ViewState {
+    /// The screen position of the world-space point `p`.
+    pub fn to_screen(&self, p: Vec2) -> egui::Pos2 {
+        egui::pos2(p.x, p.y) * self.scale + self.pan
+    }
+
+    /// The world-space point at the screen position `pos`.
+    pub fn to_world(&self, pos: egui::Pos2) -> Vec2 {
+        let p = (pos - self.pan) / self.scale;
+        Vec2::new(p.x, p.y)
+    }
+
+    /// The world-space region to fill for the screen area `rect`, with a margin of one lattice
+    /// cell so tiles reaching into view from outside are not missed.
+    pub fn world_region(&self, tiling: &IsohedralTiling, rect: egui::Rect) -> egui::Rect {
+        let margin = tiling.t1().length() + tiling.t2().length();
+        egui::Rect::from_min_max((rect.min - self.pan) / self.scale, (rect.max - self.pan) / self.scale).expand(margin)
+    }
+
+    /// Pan by `drag` screen points, and zoom about `pointer` by the frame's scroll and pinch input
+    /// if the pointer is over the view. Returns whether the view changed.
+    pub fn navigate(&mut self, ctx: &egui::Context, drag: egui::Vec2, pointer: Option<egui::Pos2>) -> bool {
+        let before = (self.pan, self.scale);
+        self.pan += drag;
+        if let Some(pos) = pointer {
+            let factor = ctx.input(|i| i.zoom_delta() * (i.smooth_scroll_delta.y / 200.).exp());
+            self.zoom_about(pos, factor);
+        }
+        (self.pan, self.scale) != before
+    }
+
+    /// How many tiles fit across a view `width` points wide, measuring tiles by [`tile_size`].
+    pub fn tiles_across(&self, tiling: &IsohedralTiling, width: f32) -> f32 {
+        width / (self.scale * tile_size(tiling))
+    }
+
+    /// Zoom about the middle of `rect` so that `tiles` tiles fit across it, as far as the
+    /// [`SCALE_RANGE`] allows.
+    pub fn set_tiles_across(&mut self, tiling: &IsohedralTiling, rect: egui::Rect, tiles: f32) {
+        let scale = rect.width() / (tiles.max(f32::EPSILON) * tile_size(tiling));
+        self.zoom_about(rect.center(), scale / self.scale);
+    }
+
+    /// Multiply the scale by `factor`, keeping the world point under `pos` in place.
+    pub fn zoom_about(&mut self, pos: egui::Pos2, factor: f32) {
+        let world = self.to_world(pos);
+        self.scale = (self.scale * factor).clamp(*SCALE_RANGE.start(), *SCALE_RANGE.end());
+        self.pan = pos.to_vec2() - egui::vec2(world.x, world.y) * self.scale;
+    }
+
+    /// The tile at the screen position `pos`, given the prototile `outline`.
+    pub fn tile_at(&self, tiling: &IsohedralTiling, outline: &[Vec2], pos: egui::Pos2) -> Option<Tile> {
+        tiling.tile_at(outline, self.to_world(pos))
+    }
+}
+
+/// A widget filling its area with a tiling, see the [module docs](self).
+#[must_use = "add it to a `Ui` with `ui.add(view)`"]
+pub struct TilingView<'a> {
+    tiling: &'a IsohedralTiling,
+    edges: &'a [Vec<Vec2>],
+    state: &'a mut ViewState,
+    colours: &'a [egui::Color32],
+    stroke: Option<egui::Stroke>,
+    segments: usize,
+    size: Option<egui::Vec2>,
+}
+
+impl<'a> TilingView<'a> {
+    /// A view of `tiling` with its edge shapes `edges`, panned and zoomed by `state`. The view
+    /// stays empty if `edges` doesn't fit the tiling, see [`IsohedralTiling::validate_edges`].
+    pub fn new(tiling: &'a IsohedralTiling, edges: &'a [Vec<Vec2>], state: &'a mut ViewState) -> Self {
+        Self { tiling, edges, state, colours: &DEFAULT_COLOURS, stroke: None, segments: SCREEN_SEGMENTS, size: None }
+    }
+
+    /// Fill colours of the colour classes, used in turn if there are fewer than colour classes.
+    pub fn colours(mut self, colours: &'a [egui::Color32]) -> Self {
+        if !colours.is_empty() {
+            self.colours = colours;
+        }
+        self
+    }
+
+    /// The stroke of the tile edges, by default the style's foreground stroke.
+    pub fn stroke(mut self, stroke: impl Into<egui::Stroke>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    /// Number of segments each curved edge is sampled with.
+    pub fn segments(mut self, segments: usize) -> Self {
+        self.segments = segments.max(1);
+        self
+    }
+
+    /// The size of the view, by default all the available space.
+    pub fn desired_size(mut self, size: egui::Vec2) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+impl egui::Widget for TilingView<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let size = self.size.unwrap_or_else(|| ui.available_size());
+        let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click_and_drag());
+        let state = self.state;
+        state.navigate(ui.ctx(), response.drag_delta(), response.hover_pos());
+
+        let tiling = self.tiling;
+        if tiling.validate_edges(self.edges).is_err() {
+            state.hovered = None;
+            return response;
+        }
+        let sampled = tiling.sample_edges(self.edges, self.segments);
+        let outline = tiling.outline(&sampled);
+        state.hovered = response.hover_pos().and_then(|pos| state.tile_at(tiling, &outline, pos));
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        let region = state.world_region(tiling, rect);
+        let tiles = tiling.fill_region(region.min.x, region.min.y, region.max.x, region.max.y).collect_tiles();
+        let triangles = triangulate(&outline);
+        let mut mesh = egui::Mesh::default();
+        for tile in &tiles {
+            let transform = tile.transform();
+            let color = self.colours[tile.colour(tiling) % self.colours.len()];
+
+            // Tiles may be concave, so fill from the triangulation rather than as a convex path.
+            let base = mesh.vertices.len() as u32;
+            mesh.vertices.extend(outline.iter().map(|&p| egui::epaint::Vertex {
+                pos: state.to_screen(transform.transform_point2(p)),
+                uv: egui::epaint::WHITE_UV,
+                color,
+            }));
+            mesh.indices.extend(triangles.iter().map(|i| base + i));
+        }
+
+        let stroke = self.stroke.unwrap_or(ui.visuals().widgets.noninteractive.fg_stroke);
+        let painter = ui.painter_at(rect);
+        painter.add(egui::Shape::mesh(mesh));
+        // Shared edges are stroked once, with round ends so they join up at the vertices.
+        for edge in tiling.unique_edges(&sampled, tiles.iter().map(Tile::transform)) {
+            let points: Vec<egui::Pos2> = edge.iter().map(|&p| state.to_screen(p)).collect();
+            for end in [points.first(), points.last()].into_iter().flatten().copied() {
+                painter.circle_filled(end, stroke.width / 2., stroke.color);
+            }
+            painter.add(egui::Shape::line(points, stroke));
+        }
+        response
+    }
+}
@@ -0,0 +1,125 @@
+//! Following a configuration file: with `--watch <file>` the app shows the tiling type,
+//! parameters and number of colours in the file, and again whenever the file changes, so they
+//! can be set from scripts or in a text editor.
+//!
+//! The file takes a few TOML settings, all optional:
+//!
+//! ```toml
+//! type = 4              # the IH number, or "IH04"
+//! params = [0.12, 0.5]
+//! colours = 3
+//! ```
+//!
+//! The file is checked for changes twice a second. Settings left out keep their current values,
+//! and a file that can't be read or parsed leaves the tiling as it is.
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use eframe::egui;
+use egui_isohedral::TILING_TYPES;
+
+use crate::app::App;
+use crate::link;
+
+
+/// Seconds between two checks of the watched file.
+const POLL_INTERVAL: f32 = 0.5;
+
+/// The watched file.
+pub struct Watch {
+    path: PathBuf,
+    /// When the file was last modified, as of the last time it was read.
+    modified: Option<SystemTime>,
+    /// Time of the last check, in seconds of the app's clock.
+    checked: Option<f64>,
+}
+
+impl Watch {
+    /// The file given on the command line after `--watch`.
+    pub fn from_args() -> Option<Self> {
+        let path = std::env::args().skip_while(|arg| arg != "--watch").nth(1)?;
+        Some(Self { path: PathBuf::from(path), modified: None, checked: None })
+    }
+}
+
+/// The settings of a configuration file.
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Config {
+    /// The IH number of the tiling type.
+    tiling_type: Option<usize>,
+    params: Option<Vec<f32>>,
+    colours: Option<usize>,
+}
+
+impl Config {
+    /// Read the `key = value` lines of a configuration file, see the [module docs](self).
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+        for (n, line) in text.lines().enumerate().map(|(n, line)| (n + 1, line.split('#').next().unwrap_or_default().trim())) {
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = line.split_once('=').map(|(key, value)| (key.trim(), value.trim())).ok_or_else(|| format!("line {n}: expected key = value"))?;
+            let whole = || value.parse::<usize>().map_err(|_| format!("line {n}: {key} takes a whole number"));
+            match key {
+                "type" => {
+                    let name = value.trim_matches('"');
+                    config.tiling_type = Some(match name.strip_prefix("IH") {
+                        Some(number) => number.parse().map_err(|_| format!("line {n}: {value} is not a tiling type"))?,
+                        None => whole()?,
+                    });
+                }
+                "params" => {
+                    let list = value.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')).ok_or_else(|| format!("line {n}: params takes a list like [0.1, 0.5]"))?;
+                    let params = list
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|p| !p.is_empty())
+                        .map(|p| p.parse::<f32>().map_err(|_| format!("line {n}: {p:?} is not a number")))
+                        .collect::<Result<_, _>>()?;
+                    config.params = Some(params);
+                }
+                "colours" | "colors" => config.colours = Some(whole()?),
+                _ => return Err(format!("line {n}: unknown setting {key:?}")),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Show the settings in the app, keeping the current values of those left out.
+    fn apply(self, app: &mut App) -> Result<(), String> {
+        let current = TILING_TYPES[app.tile_type_num];
+        let ih = self.tiling_type.unwrap_or(current);
+        let params = match self.params {
+            Some(params) => params,
+            None if ih == current => app.params[..app.tiling.num_params()].to_vec(),
+            None => vec![],
+        };
+        link::show(app, ih, &params, self.colours.unwrap_or(app.colours))
+    }
+}
+
+/// Read the watched file again if it changed since it was last read.
+pub fn poll(app: &mut App, ctx: &egui::Context) {
+    let now = ctx.input(|i| i.time);
+    let Some(watch) = &mut app.watch else {
+        return;
+    };
+    ctx.request_repaint_after_secs(POLL_INTERVAL);
+    if watch.checked.is_some_and(|checked| now - checked < POLL_INTERVAL as f64) {
+        return;
+    }
+    watch.checked = Some(now);
+    let modified = std::fs::metadata(&watch.path).and_then(|metadata| metadata.modified()).ok();
+    if modified.is_none() || modified == watch.modified {
+        return;
+    }
+    watch.modified = modified;
+
+    let path = watch.path.clone();
+    let result = std::fs::read_to_string(&path).map_err(|err| err.to_string()).and_then(|text| Config::parse(&text)).and_then(|config| config.apply(app));
+    app.status = match result {
+        Ok(()) => format!("Reloaded {}", path.display()),
+        Err(err) => format!("Couldn't reload {}: {err}", path.display()),
+    };
+}
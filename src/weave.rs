@@ -0,0 +1,89 @@
+//! Interlaced ribbons over a tiling, in the style of Celtic knotwork.
+//!
+//! Every tile contributes one ribbon segment per corner, a curve from the midpoint of one of its
+//! edges to the midpoint of the next, which crosses both edges at the contact angle. At every edge
+//! midpoint the segments of the two tiles sharing the edge meet, and two ribbons cross.
+//!
+//! The ribbons follow the tile polygon through the tiling's vertices, not the edge shapes.
+//!
+//! Going round each tile counter-clockwise, every segment passes under the ribbon it crosses at
+//! its start and over the one it crosses at its end. The two tiles at a crossing agree on which
+//! ribbon is on top, so the ribbons alternate over and under along their whole length. To draw
+//! the weave, draw the first half of every [`Segment`], then the second half of every one.
+use crate::tiling::IsohedralTiling;
+use crate::utils::{Affine2, Vec2};
+
+
+/// One ribbon segment as a cubic Bézier curve, under the ribbon it crosses at its start and over
+/// the one at its end.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment {
+    pub points: [Vec2; 4],
+}
+
+impl Segment {
+    /// The point at `t` from `0` to `1` along the curve.
+    pub fn point(&self, t: f32) -> Vec2 {
+        let [p0, p1, p2, p3] = self.points;
+        let u = 1. - t;
+        p0 * (u * u * u) + p1 * (3. * u * u * t) + p2 * (3. * u * t * t) + p3 * (t * t * t)
+    }
+
+    /// `segments + 1` points evenly spaced in `t` along the curve.
+    pub fn sample(&self, segments: usize) -> Vec<Vec2> {
+        let segments = segments.max(1);
+        (0..=segments).map(|i| self.point(i as f32 / segments as f32)).collect()
+    }
+
+    /// The curve cut in two at its middle: the half passing under, then the half passing over.
+    pub fn split(&self) -> (Segment, Segment) {
+        let [p0, p1, p2, p3] = self.points;
+        let mid = |a: Vec2, b: Vec2| (a + b) * 0.5;
+        let (a, b, c) = (mid(p0, p1), mid(p1, p2), mid(p2, p3));
+        let (d, e) = (mid(a, b), mid(b, c));
+        let m = mid(d, e);
+        (Segment { points: [p0, a, d, m] }, Segment { points: [m, e, c, p3] })
+    }
+}
+
+/// The ribbon segments of the given tiles, in world space.
+///
+/// `tiles` holds the tile transforms. `contact_angle` is the angle in radians at which the ribbons
+/// cross the edges: small angles lay them along the edges, `π / 2` makes them cross square on.
+pub fn segments(tiling: &IsohedralTiling, tiles: impl IntoIterator<Item = Affine2>, contact_angle: f32) -> Vec<Segment> {
+    let (sin, cos) = contact_angle.sin_cos();
+    let mut segments = vec![];
+    for transform in tiles {
+        let mut corners: Vec<Vec2> = tiling.vertices().iter().map(|&v| transform.transform_point2(v)).collect();
+        // Reflected tiles wind the other way.
+        if signed_area(&corners) < 0. {
+            corners.reverse();
+        }
+        let n = corners.len();
+        // The midpoint, direction and inward normal of every edge.
+        let edges: Vec<(Vec2, Vec2, Vec2)> = (0..n)
+            .map(|i| {
+                let (a, b) = (corners[i], corners[(i + 1) % n]);
+                let d = (b - a) * (1. / (b - a).length());
+                ((a + b) * 0.5, d, Vec2::new(-d.y, d.x))
+            })
+            .collect();
+
+        for i in 0..n {
+            let (start, d0, n0) = edges[i];
+            let (end, d1, n1) = edges[(i + 1) % n];
+            // Leave the first edge heading for the corner, and reach the second coming from it.
+            let handle = (end - start).length() * 0.5;
+            segments.push(Segment {
+                points: [start, start + (d0 * cos + n0 * sin) * handle, end + (n1 * sin - d1 * cos) * handle, end],
+            });
+        }
+    }
+    segments
+}
+
+/// Twice the signed area of a polygon, positive if it winds counter-clockwise.
+fn signed_area(points: &[Vec2]) -> f32 {
+    let n = points.len();
+    (0..n).map(|i| points[i].perp_dot(points[(i + 1) % n])).sum()
+}